@@ -0,0 +1,24 @@
+package kgen.db
+
+/** Opt-in role of a column managed by the generated code rather than by callers */
+enum class ColumnRole {
+    /** Ordinary data, supplied by callers */
+    Data,
+
+    /** Time the row was inserted, set to `now()` on insert */
+    CreatedAt,
+
+    /** Time the row was last written, set to `now()` on insert, update and upsert */
+    UpdatedAt,
+
+    /** Time the row was soft deleted, `NULL` while live. Deletes set it rather than removing the row
+     * and selects skip rows with it set.
+     */
+    DeletedAt;
+
+    /** Returns true if the column is set by the server rather than from the row */
+    val isServerManaged get() = this != Data
+
+    /** Returns true if the column is set to `now()` when the row is inserted */
+    val isSetOnInsert get() = this == CreatedAt || this == UpdatedAt
+}
@@ -2,35 +2,79 @@ package kgen.db
 
 import kgen.asId
 import org.jetbrains.exposed.sql.Column
+import org.jetbrains.exposed.sql.ULongColumnType
 
 /** Models a database column
  * @property nameId Snake case name for the column
  * @property doc Comment for the sql column
  * @property type The column type
  * @property columnName The name of the column, defaulted to [nameId]
+ * @property role The role of the column, [ColumnRole.Data] unless managed by the generated code
+ * @property jsonPayload Optional rust type of the payload of a `json`/`jsonb` column, as a path in the
+ * generated crate (e.g. `crate::payload::Payload`). The row field is then the payload rather than a
+ * `serde_json::Value`, bound through `tokio_postgres::types::Json`, so the type must implement
+ * `serde::Serialize`, `serde::de::DeserializeOwned`, `Debug`, `Clone`, `Default` and `PartialEq`.
  */
 data class DbColumn(
     val nameId: String,
     val doc: String? = null,
     val type: DbType,
-    val columnName: String = nameId
+    val columnName: String = nameId,
+    val role: ColumnRole = ColumnRole.Data,
+    val jsonPayload: String? = null,
 ) {
     val id get() = nameId.asId
 
+    val isServerManaged get() = role.isServerManaged
+
     val isAutoIncrement get() = type == DbType.IntegerAutoInc || type == DbType.UlongAutoInc || type == DbType.LongAutoInc
 
+    init {
+        if (jsonPayload != null && !type.isJson) {
+            throw Exception("Json payload `$jsonPayload` of column `$nameId` requires a json column, not $type")
+        }
+    }
+
     companion object {
         private val varcharRegex = """VARCHAR\((\d+)\)""".toRegex(option = RegexOption.IGNORE_CASE)
+        private val numericRegex = """(?:DECIMAL|NUMERIC)\((\d+),\s*(\d+)\)""".toRegex(option = RegexOption.IGNORE_CASE)
+        private val arrayRegex = """(.+?)\s*(?:ARRAY|\[])$""".toRegex(option = RegexOption.IGNORE_CASE)
 
         fun <T> getDbType(column: Column<T>): DbType {
 
             val sqlType = column.columnType.sqlType()
             val isNullable = column.columnType.nullable
-            val varCharSize = varcharRegex.find(sqlType)?.groupValues?.get(1)?.length ?: 0
+            val pgEnum = column.columnType as? PgEnumColumnType
+            val arrayElementSqlType = arrayRegex.find(sqlType)?.groupValues?.get(1)
+
+            return if (column.columnType is ULongColumnType) {
+                // Stored as a `bigint`, so the sql type alone loses the unsigned meaning
+                if (isNullable) DbType.NullableUnsignedBigInteger else DbType.UnsignedBigInteger
+            } else if (pgEnum != null) {
+                if (isNullable) {
+                    DbType.NullablePgEnum(pgEnum.typeName, pgEnum.values)
+                } else {
+                    DbType.PgEnum(pgEnum.typeName, pgEnum.values)
+                }
+            } else if (arrayElementSqlType != null) {
+                val elementType = getScalarDbType(arrayElementSqlType, false)
+                if (isNullable) DbType.NullableArray(elementType) else DbType.Array(elementType)
+            } else {
+                getScalarDbType(sqlType, isNullable)
+            }
+        }
+
+        /** Map the [sqlType] of a column that is neither an enum nor an array to its [DbType] */
+        private fun getScalarDbType(sqlType: String, isNullable: Boolean): DbType {
+            val varCharSize = varcharRegex.find(sqlType)?.groupValues?.get(1)?.toInt() ?: 0
+            val numeric = numericRegex.find(sqlType)?.groupValues?.let { Pair(it[1].toInt(), it[2].toInt()) }
 
             return if (varCharSize > 0) {
-                DbType.VarChar(varCharSize)
-            } else when (column.columnType.sqlType().uppercase()) {
+                if (isNullable) DbType.NullableVarChar(varCharSize) else DbType.VarChar(varCharSize)
+            } else if (numeric != null) {
+                val (precision, scale) = numeric
+                if (isNullable) DbType.NullableNumeric(precision, scale) else DbType.Numeric(precision, scale)
+            } else when (sqlType.uppercase()) {
                 "BYTE" -> if (isNullable) DbType.NullableByte else DbType.Byte
                 "DOUBLE", "DOUBLE PRECISION" -> if (isNullable) DbType.NullableDouble else DbType.Double
                 "INT" -> if (isNullable) DbType.NullableInteger else DbType.Integer
@@ -39,6 +83,8 @@ data class DbColumn(
                 "TEXT" -> if (isNullable) DbType.NullableText else DbType.Text
                 "DATE" -> if (isNullable) DbType.NullableDate else DbType.Date
                 "DATETIME", "TIMESTAMP" -> if (isNullable) DbType.NullableDateTime else DbType.DateTime
+                "TIMESTAMP WITH TIME ZONE", "TIMESTAMPTZ" -> if (isNullable) DbType.NullableTimestamp else DbType.Timestamp
+                "TIME" -> if (isNullable) DbType.NullableTime else DbType.Time
                 "INTERVAL" -> if (isNullable) DbType.NullableInterval else DbType.Interval
                 "SERIAL" -> DbType.IntegerAutoInc
                 "BIGSERIAL" -> DbType.LongAutoInc
@@ -48,14 +94,15 @@ data class DbColumn(
                 "BOOLEAN" -> if (isNullable) DbType.NullableBool else DbType.Bool
                 //"BINARYSIZED" -> DbType.Binary
                 //"BLOB" -> DbType.Blob
-                "JSONBINARY" -> if (isNullable) DbType.NullableJsonBinary else DbType.JsonBinary
+                "JSONB", "JSONBINARY" -> if (isNullable) DbType.NullableJsonBinary else DbType.JsonBinary
                 "JSON" -> if (isNullable) DbType.NullableJson else DbType.Json
                 else -> DbType.Json
             }
         }
 
         /** Convert from exposed column to [DbColumn] */
-        fun <T> fromColumn(column: Column<T>) = DbColumn(column.name.asId.snake, type = getDbType(column))
+        fun <T> fromColumn(column: Column<T>, role: ColumnRole = ColumnRole.Data, jsonPayload: String? = null) =
+            DbColumn(column.name.asId.snake, type = getDbType(column), role = role, jsonPayload = jsonPayload)
     }
 
 }
\ No newline at end of file
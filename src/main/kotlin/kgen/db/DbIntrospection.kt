@@ -0,0 +1,195 @@
+package kgen.db
+
+import kgen.asId
+import java.sql.Connection
+
+/** A row of `information_schema.columns` describing a column of a table
+ * @property tableName The name of the table
+ * @property columnName The name of the column
+ * @property udtName The postgres type name, `_` prefixed for arrays, e.g. `int4`, `_text`
+ * @property isNullable True if the column admits `NULL`
+ * @property characterMaximumLength The declared length of a `varchar`, if any
+ * @property numericPrecision The declared precision of a `numeric`, if any
+ * @property numericScale The declared scale of a `numeric`, if any
+ * @property isAutoIncrement True if an identity or defaulted from a sequence (`serial`)
+ */
+data class CatalogColumn(
+    val tableName: String,
+    val columnName: String,
+    val udtName: String,
+    val isNullable: Boolean,
+    val characterMaximumLength: Int? = null,
+    val numericPrecision: Int? = null,
+    val numericScale: Int? = null,
+    val isAutoIncrement: Boolean = false,
+)
+
+/** A unique index of a table from `pg_index`, including that of the primary key
+ * @property tableName The name of the table
+ * @property indexName The name of the index
+ * @property isPrimary True if the index is that of the primary key
+ * @property columnNames The names of the indexed columns, in index order
+ */
+data class CatalogIndex(
+    val tableName: String,
+    val indexName: String,
+    val isPrimary: Boolean,
+    val columnNames: List<String>,
+)
+
+/** The catalog rows of a schema sufficient to model its tables
+ * @property columns The columns of every table, in table then ordinal order
+ * @property indices The unique indices of every table
+ * @property enums Labels of each enum type, in declaration order, by type name
+ */
+data class Catalog(
+    val columns: List<CatalogColumn>,
+    val indices: List<CatalogIndex> = emptyList(),
+    val enums: Map<String, List<String>> = emptyMap(),
+) {
+
+    /** Models each table of the catalog, in order of first column */
+    val asDbTables
+        get() = columns.groupBy { it.tableName }.map { (tableName, tableColumns) ->
+            val dbColumns = tableColumns.map { column ->
+                DbColumn(column.columnName.asId.snake, type = dbType(column), columnName = column.columnName)
+            }
+
+            fun columnsNamed(names: List<String>) = names.map { name -> dbColumns.first { it.columnName == name } }
+
+            val tableIndices = indices.filter { it.tableName == tableName }
+            DbTable(
+                tableName.asId.snake,
+                columns = dbColumns,
+                primaryKeyColumns = tableIndices.firstOrNull { it.isPrimary }?.let { columnsNamed(it.columnNames) }
+                    ?: emptyList(),
+                uniqueIndices = tableIndices.filter { !it.isPrimary }
+                    .associate { it.indexName to columnsNamed(it.columnNames) },
+                tableName = tableName
+            )
+        }
+
+    /** Map the catalog column to its [DbType], throwing if the type is not supported */
+    fun dbType(column: CatalogColumn): DbType = when {
+        column.isAutoIncrement && column.udtName == "int4" -> DbType.IntegerAutoInc
+        column.isAutoIncrement && column.udtName == "int8" -> DbType.LongAutoInc
+        column.udtName.startsWith("_") -> {
+            val elementType = scalarDbType(column.udtName.drop(1), column, null)
+            if (column.isNullable) DbType.NullableArray(elementType) else DbType.Array(elementType)
+        }
+
+        else -> scalarDbType(column.udtName, column, column.characterMaximumLength).let {
+            if (column.isNullable) it.asNullable else it
+        }
+    }
+
+    /** Map the postgres type [udtName] to its non-null [DbType].
+     * A `varchar` without [length] is modeled with length `0`, as is any `varchar` array element.
+     */
+    private fun scalarDbType(udtName: String, column: CatalogColumn, length: Int?): DbType = when (udtName) {
+        "char" -> DbType.Byte
+        "int2" -> DbType.SmallInteger
+        "int4" -> DbType.Integer
+        "int8" -> DbType.BigInteger
+        "float8" -> DbType.Double
+        "bool" -> DbType.Bool
+        "text" -> DbType.Text
+        "varchar" -> DbType.VarChar(length ?: 0)
+        "bytea" -> DbType.Binary
+        "numeric" -> DbType.Numeric(
+            column.numericPrecision
+                ?: throw Exception("Unsupported numeric without precision `${column.tableName}.${column.columnName}`"),
+            column.numericScale ?: 0
+        )
+
+        "date" -> DbType.Date
+        "timestamp" -> DbType.DateTime
+        "timestamptz" -> DbType.Timestamp
+        "time" -> DbType.Time
+        "interval" -> DbType.Interval
+        "uuid" -> DbType.Uuid
+        "json" -> DbType.Json
+        "jsonb" -> DbType.JsonBinary
+        in enums -> DbType.PgEnum(udtName, enums.getValue(udtName))
+        else -> throw Exception("Unsupported type `$udtName` of `${column.tableName}.${column.columnName}`")
+    }
+
+    companion object {
+
+        private val columnsQuery = """
+            SELECT c.table_name, c.column_name, c.udt_name, c.is_nullable = 'YES' AS is_nullable,
+                c.character_maximum_length, c.numeric_precision, c.numeric_scale,
+                c.is_identity = 'YES' OR coalesce(c.column_default LIKE 'nextval(%', FALSE) AS is_auto_increment
+            FROM information_schema.columns c
+            JOIN information_schema.tables t ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+            WHERE c.table_schema = ? AND t.table_type = 'BASE TABLE'
+            ORDER BY c.table_name, c.ordinal_position
+        """.trimIndent()
+
+        private val indicesQuery = """
+            SELECT t.relname AS table_name, i.relname AS index_name, ix.indisprimary AS is_primary,
+                array_agg(a.attname::text ORDER BY k.ord) AS column_names
+            FROM pg_index ix
+            JOIN pg_class t ON t.oid = ix.indrelid
+            JOIN pg_class i ON i.oid = ix.indexrelid
+            JOIN pg_namespace n ON n.oid = t.relnamespace
+            CROSS JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord)
+            JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+            WHERE n.nspname = ? AND ix.indisunique AND ix.indexprs IS NULL AND ix.indpred IS NULL
+            GROUP BY t.relname, i.relname, ix.indisprimary
+            ORDER BY t.relname, i.relname
+        """.trimIndent()
+
+        private val enumsQuery = """
+            SELECT t.typname AS type_name, e.enumlabel AS label
+            FROM pg_type t
+            JOIN pg_enum e ON e.enumtypid = t.oid
+            JOIN pg_namespace n ON n.oid = t.typnamespace
+            WHERE n.nspname = ?
+            ORDER BY t.typname, e.enumsortorder
+        """.trimIndent()
+
+        /** Read the catalog of the tables in [schema] through [connection] */
+        fun read(connection: Connection, schema: String = "public"): Catalog {
+
+            fun <T> query(sql: String, row: (java.sql.ResultSet) -> T): List<T> =
+                connection.prepareStatement(sql).use { statement ->
+                    statement.setString(1, schema)
+                    statement.executeQuery().use { resultSet ->
+                        generateSequence { if (resultSet.next()) row(resultSet) else null }.toList()
+                    }
+                }
+
+            fun java.sql.ResultSet.getIntOrNull(name: String) = getInt(name).takeIf { !wasNull() }
+
+            val columns = query(columnsQuery) {
+                CatalogColumn(
+                    it.getString("table_name"),
+                    it.getString("column_name"),
+                    it.getString("udt_name"),
+                    it.getBoolean("is_nullable"),
+                    it.getIntOrNull("character_maximum_length"),
+                    it.getIntOrNull("numeric_precision"),
+                    it.getIntOrNull("numeric_scale"),
+                    it.getBoolean("is_auto_increment")
+                )
+            }
+            val indices = query(indicesQuery) {
+                @Suppress("UNCHECKED_CAST")
+                CatalogIndex(
+                    it.getString("table_name"),
+                    it.getString("index_name"),
+                    it.getBoolean("is_primary"),
+                    (it.getArray("column_names").array as Array<String>).toList()
+                )
+            }
+            val enums = query(enumsQuery) { it.getString("type_name") to it.getString("label") }
+                .groupBy({ it.first }, { it.second })
+
+            return Catalog(columns, indices, enums)
+        }
+    }
+}
+
+/** Model the tables of [schema] of the database reached through [connection] */
+fun introspectDbTables(connection: Connection, schema: String = "public") = Catalog.read(connection, schema).asDbTables
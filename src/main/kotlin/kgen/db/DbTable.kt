@@ -1,6 +1,7 @@
 package kgen.db
 
 import kgen.asId
+import org.jetbrains.exposed.sql.Column
 import org.jetbrains.exposed.sql.Table
 
 /** Models a database table with its columns, primary key and unique indices
@@ -10,6 +11,8 @@ import org.jetbrains.exposed.sql.Table
  * @property primaryKeyColumns The list of columns in the primary key
  * @property uniqueIndices Maps the name of the index to the columns identifying it
  * @property tableName Optional user supplied name of the table
+ * @property versionColumn Optional column for optimistic concurrency - an integer version
+ * incremented, or an `updated_at` timestamp set, on each versioned update
  */
 data class DbTable(
     val nameId: String,
@@ -18,14 +21,67 @@ data class DbTable(
     val primaryKeyColumns: List<DbColumn> = emptyList(),
     val uniqueIndices: Map<String, List<DbColumn>> = emptyMap(),
     val tableName: String = nameId,
+    val versionColumn: DbColumn? = null,
 ) {
     val id get() = nameId.asId
     val valueColumns get() = columns.filter { it !in primaryKeyColumns && !it.isAutoIncrement }
     val autoIncColumn get() = columns.firstOrNull { it.isAutoIncrement }
     val nonAutoIncColumns get() = columns.filter { it != autoIncColumn }
     val hasAutoInc: Boolean get() = autoIncColumn != null
+
+    /** True if any column is unsigned, modeled by the `U64` newtype */
+    val hasUnsigned get() = columns.any { it.type.isUnsigned }
+
+    /** True if any column is an interval, modeled by the `Interval` newtype */
+    val hasInterval get() = columns.any { it.type.isInterval }
+
+    /** Columns with a typed json payload */
+    val jsonPayloadColumns get() = columns.filter { it.jsonPayload != null }
     val hasPrimaryKey get() = primaryKeyColumns.isNotEmpty()
 
+    /** Columns supplied by callers on insert - all but any _auto inc_ and server managed columns */
+    val insertColumns get() = nonAutoIncColumns.filter { !it.isServerManaged }
+
+    /** Server managed columns set to `now()` on insert */
+    val setOnInsertColumns get() = columns.filter { it.role.isSetOnInsert }
+
+    /** Column with the time the row was last written, if any */
+    val updatedAtColumn get() = columns.firstOrNull { it.role == ColumnRole.UpdatedAt }
+
+    /** Column with the time the row was soft deleted, if any */
+    val deletedAtColumn get() = columns.firstOrNull { it.role == ColumnRole.DeletedAt }
+
+    /** Returns true if deletes of the table are soft, setting [deletedAtColumn] */
+    val isSoftDelete get() = deletedAtColumn != null
+
+    init {
+        ColumnRole.entries.filter { it.isServerManaged }.forEach { role ->
+            val roleColumns = columns.filter { it.role == role }
+            if (roleColumns.size > 1) {
+                throw Exception("Table `$nameId` has more than one $role column ${roleColumns.map { it.nameId }}")
+            }
+            roleColumns.firstOrNull()?.let { column ->
+                if (column in primaryKeyColumns || column.type !in timestampTypes) {
+                    throw Exception("$role column `${column.nameId}` of `$nameId` must be a timestamp outside the key")
+                }
+                if (role == ColumnRole.DeletedAt && !column.type.isNullable) {
+                    throw Exception("$role column `${column.nameId}` of `$nameId` must be nullable")
+                }
+            }
+        }
+        if (versionColumn != null) {
+            if (versionColumn !in columns) {
+                throw Exception("Version column `${versionColumn.nameId}` is not a column of `$nameId`")
+            }
+            if (versionColumn in primaryKeyColumns || versionColumn.isAutoIncrement || versionColumn.isServerManaged) {
+                throw Exception("Version column `${versionColumn.nameId}` of `$nameId` must not be a key or server managed")
+            }
+            if (versionColumn.type !in versionTypes) {
+                throw Exception("Version column `${versionColumn.nameId}` of `$nameId` must be a non null integer or timestamp")
+            }
+        }
+    }
+
 //    init {
 //        println("AUTO INC ($nameId) -> ${autoIncColumn?.nameId}");
 //        println("PRIMARY KEY($nameId) -> ${primaryKeyColumns.map {it.nameId}}");
@@ -44,19 +100,38 @@ data class DbTable(
 
     companion object {
 
+        /** Types supported for a [versionColumn] */
+        val versionTypes = setOf(
+            DbType.SmallInteger, DbType.Integer, DbType.BigInteger, DbType.DateTime, DbType.Timestamp
+        )
+
+        /** Types supported for server managed columns */
+        val timestampTypes = setOf(
+            DbType.DateTime, DbType.Timestamp, DbType.NullableDateTime, DbType.NullableTimestamp
+        )
+
         private fun findByName(allColumns: List<DbColumn>, names: List<String>) = names.flatMap {
             val id = it.asId
             allColumns.filter { it.id == id }
         }
 
-        fun fromTable(table: Table, doc: String? = null): DbTable {
-            val allColumns = table.columns.map { DbColumn.fromColumn(it) }
+        fun fromTable(
+            table: Table,
+            doc: String? = null,
+            versionColumn: Column<*>? = null,
+            columnRoles: Map<Column<*>, ColumnRole> = emptyMap(),
+            jsonPayloads: Map<Column<*>, String> = emptyMap()
+        ): DbTable {
+            val allColumns = table.columns.map {
+                DbColumn.fromColumn(it, columnRoles[it] ?: ColumnRole.Data, jsonPayloads[it])
+            }
             val primaryKeyColumns = findByName(allColumns, table.primaryKey?.columns?.map { it.name } ?: emptyList())
-            val uniqueIndices = table.indices.map { index ->
+            val uniqueIndices = table.indices.filter { it.unique }.map { index ->
                 index.indexName to findByName(allColumns, index.columns.map { it.name })
             }.toMap()
             return DbTable(
-                table.tableName.asId.snake, doc, allColumns, primaryKeyColumns, uniqueIndices
+                table.tableName.asId.snake, doc, allColumns, primaryKeyColumns, uniqueIndices,
+                versionColumn = versionColumn?.let { findByName(allColumns, listOf(it.name)).single() }
             )
         }
     }
@@ -66,6 +141,13 @@ data class DbTable(
  */
 val Table.asDbTable get() = DbTable.fromTable(this)
 
-/** Convert exposed table to modeled [DbTable], including a table description, to support code generation
+/** Convert exposed table to modeled [DbTable], including a table description, any
+ * [versionColumn] for optimistic concurrency, the roles of any server managed columns
+ * and the rust payload types of any typed json columns, to support code generation
  */
-fun Table.intoDbTable(doc: String) = DbTable.fromTable(this, doc)
\ No newline at end of file
+fun Table.intoDbTable(
+    doc: String,
+    versionColumn: Column<*>? = null,
+    columnRoles: Map<Column<*>, ColumnRole> = emptyMap(),
+    jsonPayloads: Map<Column<*>, String> = emptyMap()
+) = DbTable.fromTable(this, doc, versionColumn, columnRoles, jsonPayloads)
\ No newline at end of file
@@ -1,8 +1,12 @@
 package kgen.db
 
+import kgen.asId
 import kgen.rust.*
 import java.time.LocalDate
+import java.math.BigDecimal
+import java.math.RoundingMode
 import java.time.LocalDateTime
+import java.time.LocalTime
 import java.util.*
 import kotlin.random.Random
 import kotlin.Byte as KotlinByte
@@ -18,6 +22,9 @@ sealed class DbType {
 
     data object BigInteger : DbType()
 
+    /** An unsigned `u64` stored in a `bigint`, modeled by the checked `U64` newtype of the generated crate */
+    data object UnsignedBigInteger : DbType()
+
     data object Text : DbType()
 
     data object Date : DbType()
@@ -28,6 +35,10 @@ sealed class DbType {
 
     data object Interval : DbType()
 
+    data object Time : DbType()
+
+    data class Numeric(val precision: Int, val scale: Int) : DbType()
+
     data object IntegerAutoInc : DbType()
 
     data object LongAutoInc : DbType()
@@ -48,8 +59,18 @@ sealed class DbType {
 
     data object Json : DbType()
 
+    /** A `varchar` of [length], unbounded if `0` */
     data class VarChar(val length: Int) : DbType()
 
+    /** A postgres enum type with its labels in declaration order */
+    data class PgEnum(val typeName: String, val values: List<String>) : DbType()
+
+    /** A one dimensional postgres array (e.g. `text[]`, `int[]`, `uuid[]`) of non-null elements.
+     * Elements are limited to scalar types whose rust type implements `Display`, as `bulk_insert`
+     * passes each array as its postgres text literal.
+     */
+    data class Array(val elementType: DbType) : DbType()
+
     // Nullables
 
     data object NullableByte : DbType()
@@ -62,6 +83,8 @@ sealed class DbType {
 
     data object NullableBigInteger : DbType()
 
+    data object NullableUnsignedBigInteger : DbType()
+
     data object NullableText : DbType()
 
     data object NullableDate : DbType()
@@ -72,6 +95,10 @@ sealed class DbType {
 
     data object NullableInterval : DbType()
 
+    data object NullableTime : DbType()
+
+    data class NullableNumeric(val precision: Int, val scale: Int) : DbType()
+
     data object NullableUuid : DbType()
 
     data object NullableBool : DbType()
@@ -88,45 +115,135 @@ sealed class DbType {
 
     data class NullableVarChar(val length: Int) : DbType()
 
+    data class NullablePgEnum(val typeName: String, val values: List<String>) : DbType()
+
+    data class NullableArray(val elementType: DbType) : DbType()
+
+    /** True if the column type admits `NULL`, modeled as `Option` in rust */
+    val isNullable
+        get() = when (this) {
+            is NullableByte, is NullableDouble, is NullableInteger, is NullableSmallInteger,
+            is NullableBigInteger, is NullableUnsignedBigInteger, is NullableText, is NullableDate, is NullableDateTime,
+            is NullableTimestamp, is NullableInterval, is NullableTime, is NullableNumeric,
+            is NullableUuid, is NullableBool,
+            is NullableBinary, is NullableBinarySized, is NullableBlob, is NullableJsonBinary,
+            is NullableJson, is NullableVarChar, is NullablePgEnum, is NullableArray -> true
+
+            else -> false
+        }
+
+    /** The nullable counterpart of the type, the type itself if already nullable or _auto inc_ */
+    val asNullable: DbType
+        get() = when (this) {
+            is DbType.Byte -> NullableByte
+            is Double -> NullableDouble
+            is Integer -> NullableInteger
+            is SmallInteger -> NullableSmallInteger
+            is BigInteger -> NullableBigInteger
+            is UnsignedBigInteger -> NullableUnsignedBigInteger
+            is Text -> NullableText
+            is Date -> NullableDate
+            is DateTime -> NullableDateTime
+            is Timestamp -> NullableTimestamp
+            is Interval -> NullableInterval
+            is Time -> NullableTime
+            is Numeric -> NullableNumeric(precision, scale)
+            is Uuid -> NullableUuid
+            is Binary -> NullableBinary
+            is Bool -> NullableBool
+            is BinarySized -> NullableBinarySized(length)
+            is Blob -> NullableBlob
+            is JsonBinary -> NullableJsonBinary
+            is Json -> NullableJson
+            is VarChar -> NullableVarChar(length)
+            is PgEnum -> NullablePgEnum(typeName, values)
+            is Array -> NullableArray(elementType)
+            else -> this
+        }
+
+    /** True if postgres orders the type with `min`/`max` aggregates decoding to its rust type */
+    val isOrderable
+        get() = when (this) {
+            is Double, is Integer, is SmallInteger, is BigInteger, is UnsignedBigInteger, is Text, is Date,
+            is DateTime, is Timestamp, is Interval, is Time, is Numeric, is IntegerAutoInc, is LongAutoInc,
+            is UlongAutoInc, is VarChar, is NullableDouble, is NullableInteger, is NullableSmallInteger,
+            is NullableBigInteger, is NullableUnsignedBigInteger,
+            is NullableText, is NullableDate, is NullableDateTime, is NullableTimestamp, is NullableInterval,
+            is NullableTime, is NullableNumeric, is NullableVarChar -> true
+
+            else -> false
+        }
+
+    /** True if the column type is unsigned, stored in a `bigint` and modeled by the `U64` newtype */
+    val isUnsigned get() = this is UnsignedBigInteger || this is NullableUnsignedBigInteger || this is UlongAutoInc
+
+    /** True if the column type is `interval`, modeled by the `Interval` newtype */
+    val isInterval get() = this is Interval || this is NullableInterval
+
+    /** True if the column type is `json` or `jsonb` */
+    val isJson get() = this is Json || this is NullableJson || this is JsonBinary || this is NullableJsonBinary
+
+    /** True if the column type is a postgres array, modeled as `Vec` in rust */
+    val isArray get() = this is Array || this is NullableArray
 
     val asRustType
         get() = when (this) {
-            is DbType.Byte -> U8
+            is DbType.Byte -> I8
             is Double -> F64
             is Integer -> I32
             is SmallInteger -> I16
             is BigInteger -> I64
+            is UnsignedBigInteger -> unsignedRustType
             is Text -> RustString
             is Date -> "chrono::NaiveDate".asType
-            is DateTime, is Timestamp -> "chrono::NaiveDateTime".asType
+            is DateTime -> "chrono::NaiveDateTime".asType
+            is Timestamp -> "chrono::DateTime<chrono::Utc>".asType
+            is Time -> "chrono::NaiveTime".asType
+            is Interval -> intervalRustType
+            is Numeric -> "rust_decimal::Decimal".asType
             is IntegerAutoInc -> I32
             is LongAutoInc -> I64
-            is UlongAutoInc -> U64
-            is Binary, is BinarySized -> I64
+            is UlongAutoInc -> unsignedRustType
+            is Binary, is BinarySized, is Blob -> "Vec<u8>".asType
             is Bool -> RustBoolean
             is Uuid -> "uuid::Uuid".asType
             is VarChar -> RustString
             is Json, is JsonBinary -> "serde_json::Value".asType
+            is PgEnum -> typeName.asId.capCamel.asType
+            is Array -> "Vec<${elementType.asRustType.asRustName}>".asType
 
-
-            is NullableByte -> "Option<u8>".asType
+            is NullableByte -> "Option<i8>".asType
             is NullableDouble -> "Option<f64>".asType
             is NullableInteger -> "Option<i32>".asType
             is NullableSmallInteger -> "Option<i16>".asType
             is NullableBigInteger -> "Option<i64>".asType
+            is NullableUnsignedBigInteger -> "Option<${unsignedRustType.asRustName}>".asType
             is NullableText -> "Option<String>".asType
             is NullableDate -> "Option<chrono::NaiveDate>".asType
-            is NullableDateTime, is NullableTimestamp -> "Option<chrono::NaiveDateTime>".asType
-            is NullableBinary, is NullableBinarySized -> "Option<i64>".asType
+            is NullableDateTime -> "Option<chrono::NaiveDateTime>".asType
+            is NullableTimestamp -> "Option<chrono::DateTime<chrono::Utc>>".asType
+            is NullableTime -> "Option<chrono::NaiveTime>".asType
+            is NullableInterval -> "Option<${intervalRustType.asRustName}>".asType
+            is NullableNumeric -> "Option<rust_decimal::Decimal>".asType
+            is NullableBinary, is NullableBinarySized, is NullableBlob -> "Option<Vec<u8>>".asType
             is NullableUuid -> "Option<uuid::Uuid>".asType
             is NullableBool -> "Option<bool>".asType
             is NullableVarChar -> "Option<String>".asType
             is NullableJson, is NullableJsonBinary -> "Option<serde_json::Value>".asType
-            else -> throw (Exception("Unsupported rust type for $this"))
+            is NullablePgEnum -> "Option<${typeName.asId.capCamel}>".asType
+            is NullableArray -> "Option<Vec<${elementType.asRustType.asRustName}>>".asType
         }
 
 
-    fun getSampleIterator() = when (this) {
+    companion object {
+        /** Rust type of unsigned columns, the checked newtype of the `unsigned` module */
+        val unsignedRustType = "U64".asType
+
+        /** Rust type of interval columns, the newtype of the `interval` module */
+        val intervalRustType = "Interval".asType
+    }
+
+    fun getSampleIterator(): Iterator<Any> = when (this) {
         is DbType.Byte, is NullableByte -> generateSequence(KotlinByte.MIN_VALUE) {
             it.plus(1).toByte()
         }.iterator()
@@ -135,24 +252,39 @@ sealed class DbType {
         is Integer, is NullableInteger -> generateSequence(Int.MIN_VALUE) { it.plus(1) }.iterator()
         is SmallInteger, is NullableSmallInteger -> generateSequence(Short.MIN_VALUE) { (it + 1).toShort() }.iterator()
         is BigInteger, is NullableBigInteger -> generateSequence(Int.MIN_VALUE) { it.plus(1) }.iterator()
+        is UnsignedBigInteger, is NullableUnsignedBigInteger ->
+            generateSequence(Long.MAX_VALUE.toULong() - 1000UL) { it.plus(1UL) }.iterator()
         is Bool, is NullableBool -> generateSequence(false) { it.not() }.iterator()
         is Text, is NullableText -> generateSequence("a") { incrementString(it) }.iterator()
         is Date, is NullableDate -> generateSequence(LocalDate.of(2000, 1, 1)) {
             it.plusDays(1).plusMonths(1).plusDays(1)
         }.iterator()
 
-        is DateTime, is NullableDateTime -> generateSequence(LocalDateTime.of(2000, 1, 1, 1, 1)) {
-            it.plusDays(1).plusMonths(1).plusDays(1)
-        }.iterator()
+        is DateTime, is NullableDateTime, is Timestamp, is NullableTimestamp ->
+            generateSequence(LocalDateTime.of(2000, 1, 1, 1, 1)) {
+                it.plusDays(1).plusMonths(1).plusDays(1)
+            }.iterator()
+
+        is Time, is NullableTime -> generateSequence(LocalTime.of(1, 1)) { it.plusHours(1).plusMinutes(1) }.iterator()
+        is Interval, is NullableInterval -> generateSequence(1) { it.plus(1) }.iterator()
+        is Numeric -> generateNumericSequence(scale).iterator()
+        is NullableNumeric -> generateNumericSequence(scale).iterator()
 
         is IntegerAutoInc -> generateSequence(Int.MIN_VALUE) { it.plus(1) }.iterator()
         is LongAutoInc -> generateSequence(Long.MIN_VALUE) { it.plus(1) }.iterator()
-        is UlongAutoInc -> generateSequence(ULong.MIN_VALUE) { it.plus(1.toULong()) }.iterator()
+        is UlongAutoInc -> generateSequence(1UL) { it.plus(1UL) }.iterator()
         //is DbType.Binary -> generateSequence(Long.MIN_VALUE) { it.plus(1) }.iterator()
-        is Blob, is NullableBlob -> generateBlobSequence().iterator()
+        is Binary, is NullableBinary, is BinarySized, is NullableBinarySized, is Blob, is NullableBlob ->
+            generateBlobSequence().map { bytes -> bytes.joinToString(", ") { (it.toInt() and 0xff).toString() } }
+                .iterator()
+
         is Uuid, is NullableUuid -> generateDeterministicUuidSequence().iterator()
-        is Json, is NullableJson -> generateDeterministicJsonSequence().iterator()
+        is Json, is NullableJson, is JsonBinary, is NullableJsonBinary -> generateDeterministicJsonSequence().iterator()
         is VarChar, is NullableVarChar -> generateSequence("a") { incrementString(it) }.iterator()
+        is PgEnum -> generateSequence(0) { it + 1 }.map { values[it % values.size] }.iterator()
+        is NullablePgEnum -> generateSequence(0) { it + 1 }.map { values[it % values.size] }.iterator()
+        is Array -> generateArraySequence(elementType).iterator()
+        is NullableArray -> generateArraySequence(elementType).iterator()
         else -> throw (Exception("Unsupported rust type for $this"))
     }
 }
@@ -196,6 +328,16 @@ fun generateDeterministicBlobSequence(): Sequence<String> {
     }
 }
 
+// Generates a deterministic sequence of decimals with the given scale
+fun generateNumericSequence(scale: Int): Sequence<BigDecimal> =
+    generateSequence(BigDecimal("1.25")) { it.add(BigDecimal("1.25")) }.map { it.setScale(scale, RoundingMode.HALF_UP) }
+
+// Generates deterministic arrays of 0, 1 or 2 sample elements, as lists of the element samples
+fun generateArraySequence(elementType: DbType): Sequence<List<Any>> {
+    val elements = elementType.getSampleIterator()
+    return generateSequence(0) { it + 1 }.map { length -> List(length % 3) { elements.next() } }
+}
+
 // Generates deterministic binary data (e.g., for BYTEA)
 fun generateBlobSequence(): Sequence<ByteArray> {
     val random = Random(42) // Seeded for reproducibility
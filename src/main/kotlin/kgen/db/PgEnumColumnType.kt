@@ -0,0 +1,36 @@
+package kgen.db
+
+import org.jetbrains.exposed.sql.ColumnType
+import org.jetbrains.exposed.sql.Table
+import org.postgresql.util.PGobject
+
+/** Exposed column type for a postgres enum created with `CREATE TYPE ... AS ENUM`.
+ * Values are carried as their string labels. Recognized by [DbColumn.getDbType] as [DbType.PgEnum].
+ *
+ * @property typeName The postgres name of the enum type
+ * @property values The labels of the enum, in declaration order
+ */
+class PgEnumColumnType(val typeName: String, val values: List<String>) : ColumnType<String>() {
+    override fun sqlType(): String = typeName
+
+    override fun valueFromDB(value: Any): String = when (value) {
+        is PGobject -> value.value!!
+        else -> value.toString()
+    }
+
+    override fun notNullValueToDB(value: String): Any = PGobject().also {
+        it.type = typeName
+        it.value = value
+    }
+
+    /** Statement creating the enum type */
+    val createTypeStatement
+        get() = "CREATE TYPE $typeName AS ENUM (${values.joinToString(", ") { "'$it'" }})"
+
+    /** Statement dropping the enum type */
+    val dropTypeStatement get() = "DROP TYPE IF EXISTS $typeName"
+}
+
+/** Register a column of postgres enum type [typeName] with labels [values] */
+fun Table.pgEnum(name: String, typeName: String, values: List<String>) =
+    registerColumn(name, PgEnumColumnType(typeName, values))
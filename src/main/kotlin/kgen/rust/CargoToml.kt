@@ -12,7 +12,8 @@ class CargoToml(
     val dependencies: List<String> = emptyList(),
     val buildDependencies: List<String> = emptyList(),
     val edition: String = "2021",
-    val keywords: List<String> = emptyList()
+    val keywords: List<String> = emptyList(),
+    val benches: List<String> = emptyList()
 ) : Identifier(nameId) {
 
     val toml
@@ -52,6 +53,8 @@ class CargoToml(
                 "\n[features]",
                 emptyOpenDelimitedBlock("features", scriptDelimiter),
             ).joinToString("\n"),
+            benches.joinToString("\n") { "\n[[bench]]\nname = ${doubleQuote(it)}\nharness = false" }
+                .ifEmpty { null },
             emptyOpenDelimitedBlock("additional", scriptDelimiter),
         ).joinToString("\n")
 
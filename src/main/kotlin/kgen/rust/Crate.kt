@@ -16,6 +16,7 @@ import java.nio.file.Path
  * @property binaries List of binaries, models as clap binaries, in the crate
  * @property includeTypeSizes If set will include sizes for structs and enums
  * @property integrationTestModules Modules for integration testing
+ * @property benchModules Modules for benchmarks, each a bench target in `benches`
  * @property handCodedSet Set of paths that were hand coded and should not be considered
  *                        orphaned code and hence not cleaned up
  */
@@ -28,6 +29,7 @@ data class Crate(
     val binaries: List<ClapBinary> = emptyList(),
     val includeTypeSizes: Boolean = false,
     val integrationTestModules: List<Module> = emptyList(),
+    val benchModules: List<Module> = emptyList(),
     val handCodedSet: Set<Path> = emptySet(),
 ) : Identifier(nameId) {
 
@@ -16,7 +16,7 @@ data class AutoIdDetails(
 
     val collectResult = """
 $autoId.into_iter()
-       .zip(rows.into_iter())
+       .zip(rows)
        .map(|($autoId, data)| $rowEntryStructName { $autoId, data })
        .collect()
     """.trimIndent()
@@ -53,6 +53,6 @@ insert_result
     val insertReturnDoc = "Entries with corresponding _auto_id_"
 
     /** The transform of an insertion returning entries to the data vector */
-    val insertedDataTransform = "&inserted.into_iter().map(|r| r.data).collect()"
+    val insertedDataTransform = "&inserted.into_iter().map(|r| r.data).collect::<Vec<_>>()"
 
 }
\ No newline at end of file
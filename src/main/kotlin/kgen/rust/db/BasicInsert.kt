@@ -1,6 +1,7 @@
 package kgen.rust.db
 
 import kgen.rust.*
+import kgen.rust.db.select.QueryColumn
 import kgen.rustQuote
 
 /** Responsible for generating `basic_insert` method for the table.
@@ -53,10 +54,40 @@ data class BasicInsert(
         }
     }
 
+    /** The query columns supplied by callers, excluding any server managed */
+    val insertQueryColumns = tableGateway.dataQueryColumns.queryColumns.filter { it.dbColumn?.isServerManaged != true }
+
+    /** The inserted columns with a json payload. Their `Json` wrappers are collected ahead of
+     * the parameters, which can only borrow values outliving them.
+     */
+    private val jsonPayloadColumns = insertQueryColumns.mapNotNull { it.dbColumn }.filter { it.jsonPayload != null }
+
+    /** Statements collecting the `Json` wrappers of each of the [jsonPayloadColumns] */
+    private val jsonParamDecls = jsonPayloadColumns.joinToString("\n") {
+        "let ${it.nameId}_json = rows.iter().map(|row| ${it.jsonParam("row")}).collect::<Vec<_>>();"
+    }
+
+    /** The closure mapping each row to its value parameters, indexing any json wrappers */
+    private val rowMapping = if (jsonPayloadColumns.isEmpty()) ".map(|row| {" else ".enumerate().map(|(i, row)| {"
+
+    /** Statement pushing the parameter of [queryColumn] of the row */
+    private fun pushParam(queryColumn: QueryColumn) = if (queryColumn.dbColumn?.jsonPayload != null) {
+        "params.push(&${queryColumn.dbColumn!!.nameId}_json[i]);"
+    } else {
+        "params.push(&row.${queryColumn.id});"
+    }
+
+    /** Source of one parameter per inserted field - the field names unless some are server managed */
+    private val rowParamsSource = if (insertQueryColumns.size == tableGateway.dataQueryColumns.queryColumns.size) {
+        "${tableGateway.rowDataStructName}::FIELD_NAMES"
+    } else {
+        "(0..${insertQueryColumns.size})"
+    }
+
     /** The sql insert statement */
     val basicInsertStatement = rustQuote(
         """insert into $tableName 
-${tableGateway.nonAutoIncColumnSetLiteralValue}
+${tableGateway.insertColumnSetLiteralValue}
 VALUES
 {value_params}$returningId
 """
@@ -73,19 +104,18 @@ VALUES
     val insertBody = """
         use itertools::Itertools;
         let mut param_id = 0;
+        $jsonParamDecls
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::with_capacity(rows.len() * ${tableGateway.rowDataStructName}::NUM_FIELDS);;
         let value_params = rows
             .iter()
-            .map(|row| {
-                let row_params = ${tableGateway.rowDataStructName}::FIELD_NAMES.map(|_| {
+            $rowMapping
+                let row_params = $rowParamsSource.map(|_| {
                     param_id += 1;
                     format!("${'$'}{param_id}")
-                }).join(", ");
+                }).join(", ")${if (table.setOnInsertColumns.isEmpty()) "" else " + \"${table.setOnInsertValues}\""};
                 
 ${
-        tableGateway.dataQueryColumns.queryColumns.joinToString("\n") {
-            "params.push(&row.${it.id});"
-        }
+        insertQueryColumns.joinToString("\n") { pushParam(it) }
     }                
                 
                 format!("({row_params})")
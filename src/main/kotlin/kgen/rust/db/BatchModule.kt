@@ -0,0 +1,98 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** How the chunks of a transactional batch are committed */
+val batchModeEnum = Enum(
+    "batch_mode",
+    "How the chunks of a transactional batch are committed",
+    listOf(
+        Variant.UnitStruct("all_or_nothing", "All chunks commit together, or none do if any chunk fails"),
+        Variant.UnitStruct(
+            "chunk_savepoints",
+            "Each chunk runs under its own savepoint, so a failed chunk is rolled back alone"
+        ),
+    ),
+    attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq")
+)
+
+/** Range of rows of a chunk */
+private val chunkRowsField = Field("rows", "Range of the rows of the chunk in the batch", "std::ops::Range<usize>".asType)
+
+/** A committed chunk with the result of its statement */
+val chunkSuccessStruct = Struct(
+    "chunk_success",
+    "A chunk of a batch that was committed",
+    listOf(
+        Field("chunk", "Index of the chunk", USize),
+        chunkRowsField,
+        Field("result", "Result of the chunk statement", "T".asType),
+    ),
+    genericParamSet = GenericParamSet("t"),
+    attrs = derive("Debug", "Clone", "PartialEq")
+)
+
+/** A chunk not committed, with the error if it was the chunk failing */
+val chunkFailureStruct = Struct(
+    "chunk_failure",
+    "A chunk of a batch that was not committed",
+    listOf(
+        Field("chunk", "Index of the chunk", USize),
+        chunkRowsField,
+        Field(
+            "error",
+            "The error of the chunk, `None` if rolled back for the failure of another chunk",
+            "Option<tokio_postgres::Error>".asType
+        ),
+    ),
+    attrs = derive("Debug")
+)
+
+/** Report of the chunks of a transactional batch */
+val batchReportStruct = Struct(
+    "batch_report",
+    "The committed and failed chunks of a transactional batch, in chunk order",
+    listOf(
+        Field("succeeded", "The chunks committed", "Vec<ChunkSuccess<T>>".asType),
+        Field("failed", "The chunks not committed", "Vec<ChunkFailure>".asType),
+    ),
+    genericParamSet = GenericParamSet("t"),
+    attrs = derive("Debug"),
+    typeImpl = TypeImpl(
+        "BatchReport<T>".asType,
+        Fn(
+            "is_complete",
+            "True if every chunk was committed",
+            refSelf,
+            returnType = RustBoolean,
+            returnDoc = "True if no chunk failed",
+            inlineDecl = InlineDecl.Inline,
+            hasUnitTest = false,
+            body = FnBody("self.failed.is_empty()")
+        ),
+        genericParamSet = GenericParamSet("t"),
+    )
+)
+
+/** Summary of the changes applied by a table synchronization */
+val syncSummaryStruct = Struct(
+    "sync_summary",
+    "The changes applied to make a table match the rows it was synchronized to",
+    listOf(
+        Field("inserted", "Number of rows inserted, their key absent from the table", U64),
+        Field("updated", "Number of rows updated, their key present with other values", U64),
+        Field("deleted", "Number of rows deleted, their key absent from the rows", U64),
+        Field("unchanged", "Number of rows already matching", U64),
+    ),
+    attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq", "Default")
+)
+
+/** Module shared by all table gateways supporting transactional batches.
+ * Include in the crate alongside the table gateway modules.
+ */
+val batchModule = Module(
+    "batch",
+    "Modes and reports of the transactional batch and synchronization functions of the table gateways",
+    structs = listOf(chunkSuccessStruct, chunkFailureStruct, batchReportStruct, syncSummaryStruct),
+    enums = listOf(batchModeEnum)
+)
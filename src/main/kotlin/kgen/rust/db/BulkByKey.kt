@@ -0,0 +1,190 @@
+package kgen.rust.db
+
+import kgen.db.DbColumn
+import kgen.rust.*
+import kgen.rustQuote
+
+/** Responsible for generating the bulk functions by key of the table, `bulk_delete_by_<key>s`
+ * and `bulk_update`. Like [BulkInsert] the keys or rows are bound in chunks as `UNNEST` arrays,
+ * one statement per chunk, and the number of rows affected is returned.
+ *
+ * Rows are matched on the key of [keyLookup]. If the key includes the _auto id_ the update takes
+ * the entries, else the row data.
+ *
+ * @property keyLookup The key lookup providing the key columns and the columns updated
+ */
+data class BulkByKey(
+    val keyLookup: KeyLookup,
+) {
+
+    /** The table gateway */
+    val tableGateway get() = keyLookup.tableGateway
+
+    /** Underlying table */
+    val table get() = keyLookup.table
+
+    /** Table name */
+    val tableName get() = table.tableName
+
+    /** The key columns */
+    val keyColumns get() = keyLookup.keyColumns
+
+    /** The columns set by the update */
+    val updateColumns get() = keyLookup.updateColumns
+
+    /** True if rows are identified by the entry, the key including the _auto id_ */
+    private val keyInEntry = keyColumns.any { it.isAutoIncrement }
+
+    /** The type of the updated rows */
+    private val rowType = if (keyInEntry) tableGateway.rowEntryStructName!! else tableGateway.rowDataStructName
+
+    /** The name of the bulk delete function */
+    private val bulkDeleteNameId = "bulk_delete_by_${keyLookup.keyId.snake}s"
+
+    /** Statement declaring the column vector of each of [columns] passed to `UNNEST` */
+    private fun vectorDecls(columns: List<DbColumn>) =
+        columns.joinToString("\n") { "let mut ${it.nameId} = Vec::with_capacity(chunk_size);" }
+
+    /** Statement clearing the column vector of each of [columns] for the next chunk */
+    private fun clearStatements(columns: List<DbColumn>) =
+        columns.joinToString("\n") { "${it.nameId}.clear();" }
+
+    /** The column vectors of [columns] as sql parameters */
+    private fun vectorParams(columns: List<DbColumn>) = columns.joinToString(", ") { "&${it.nameId}" }
+
+    /** Body running [statement] for each chunk of [items] bound to [columns], summing the rows affected */
+    private fun chunkedBody(
+        fnNameId: String,
+        operation: String,
+        items: String,
+        statement: RustSqlStatement,
+        columns: List<DbColumn>,
+        pushValues: String
+    ) = """
+${vectorDecls(columns)}
+let mut affected = 0;
+${statement.letStatement}
+for (chunk, chunk_$items) in $items.chunks(chunk_size).enumerate() {
+    for item in chunk_$items.iter() {
+$pushValues
+    }
+
+    let chunk_result = client.execute(
+        ${statement.asStr},
+        &[${vectorParams(columns)}]
+    ).await;
+
+    match &chunk_result {
+        Err(err) => {
+            tracing::error!("Failed $fnNameId `${table.nameId}` chunk({chunk}) -> {err}");
+            chunk_result?;
+        }
+        Ok(chunk_result) => {
+            tracing::debug!("Finished bulk $operation of size({}) in `${table.nameId}`", chunk_result);
+            affected += chunk_result;
+        }
+    }
+    ${clearStatements(columns)}
+}
+
+Ok(affected)""".trimIndent()
+
+    /** Delete, or soft delete, of the rows with keys in the unnested key arrays */
+    val rustBulkDeleteStatement = RustSqlStatement(
+        "bulk_delete_statement",
+        rustQuote(
+            table.deletedAtColumn?.let {
+                "UPDATE $tableName SET ${it.asSqlLiteral} = now() WHERE ${
+                    listOfNotNull(table.notDeletedPredicate, keyLookup.keyInUnnestPredicate).joinToString(" AND ")
+                }"
+            } ?: "DELETE FROM $tableName WHERE ${keyLookup.keyInUnnestPredicate}"
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    val bulkDeleteByKeysFn
+        get() = Fn(
+            bulkDeleteNameId,
+            if (table.isSoftDelete) {
+                "Soft delete large batch of rows of `$tableName` identified by [${keyLookup.keyStructName}], setting `${table.deletedAtColumn!!.nameId}`"
+            } else {
+                "Delete large batch of rows of `$tableName` identified by [${keyLookup.keyStructName}]"
+            },
+            clientFnParam,
+            FnParam("keys", "&[${keyLookup.keyStructName}]".asType, "The keys identifying the rows"),
+            FnParam("chunk_size", USize, "How to chunk the deletes"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<u64, tokio_postgres::Error>".asType,
+            returnDoc = "Number of rows deleted",
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                chunkedBody(
+                    bulkDeleteNameId,
+                    "delete",
+                    "keys",
+                    rustBulkDeleteStatement,
+                    keyColumns,
+                    keyColumns.joinToString("\n") { it.pushValue("item") }
+                )
+            )
+        )
+
+    /** The columns bound for the update, the key then those updated */
+    private val boundColumns = keyColumns + updateColumns
+
+    /** Update of the rows matching the key of the unnested rows, setting the columns updated */
+    val rustBulkUpdateStatement = RustSqlStatement(
+        "bulk_update_statement",
+        rustQuote(
+            """UPDATE $tableName AS t SET
+    ${
+                (updateColumns.map { column ->
+                    "${column.asSqlLiteral} = u.${column.nameId}${if (column.type.isArray) column.sqlCast else ""}"
+                } + listOfNotNull(table.updatedAtAssignment)).joinToString(",\n\t")
+            }
+FROM UNNEST(${
+                boundColumns.withIndex().joinToString(", ") { (i, column) ->
+                    "${'$'}${i + 1}${column.unnestCast}[]"
+                }
+            }) AS u(${boundColumns.joinToString(", ") { it.nameId }})
+WHERE ${
+                (keyColumns.map { "t.${it.asSqlLiteral} = u.${it.nameId}" } +
+                        listOfNotNull(table.deletedAtColumn?.let { "t.${it.asSqlLiteral} IS NULL" }))
+                    .joinToString(" AND ")
+            }"""
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    /** Update function, only available if there are columns outside the key to update */
+    val bulkUpdateFn
+        get() = if (updateColumns.isNotEmpty()) {
+            Fn(
+                "bulk_update",
+                "Update large batch of rows of `$tableName` identified by [${keyLookup.keyStructName}] with their non-key fields",
+                clientFnParam,
+                FnParam("rows", "&[$rowType]".asType, "The rows with the new values"),
+                FnParam("chunk_size", USize, "How to chunk the updates"),
+                genericParamSet = genericClientParamSet,
+                returnType = "Result<u64, tokio_postgres::Error>".asType,
+                returnDoc = "Number of rows updated",
+                isAsync = true,
+                hasUnitTest = false,
+                body = FnBody(
+                    chunkedBody(
+                        "bulk_update",
+                        "update",
+                        "rows",
+                        rustBulkUpdateStatement,
+                        boundColumns,
+                        boundColumns.joinToString("\n") {
+                            it.pushValue(if (keyInEntry && !it.isAutoIncrement) "item.data" else "item")
+                        }
+                    )
+                )
+            )
+        } else {
+            null
+        }
+}
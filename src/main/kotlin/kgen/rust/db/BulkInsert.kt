@@ -52,9 +52,8 @@ data class BulkInsert(
 
     val insertStatement = rustQuote(
         """insert into $tableName
-${tableGateway.nonAutoIncColumnSetLiteralValue}
-SELECT * FROM UNNEST
-${tableGateway.unnestedColumnExpressionValue}$returningId
+${tableGateway.insertColumnSetLiteralValue}
+${tableGateway.unnestSelectValue}$returningId
 """
     )
 
@@ -71,30 +70,26 @@ ${tableGateway.unnestedColumnExpressionValue}$returningId
         "Row data to insert"
     )
 
-    val bulkInsertFn
-        get() = Fn(
-            "bulk_insert",
-            "Insert large batch of [${id.capCamel}] rows.",
-            clientFnParam,
-            inputFnParam,
-            tableGateway.bulkInsertChunkSizeFnParam,
-            genericParamSet = genericClientParamSet,
-            returnType = "Result<${autoIdDetails?.outputType ?: "()"}, tokio_postgres::Error>".asType,
-            returnDoc = autoIdDetails?.insertReturnDoc ?: "Success or tokio_postgres::Error",
-            body = FnBody(
-                """
+    /** Return type of _bulk insert_ */
+    val returnType = "Result<${autoIdDetails?.outputType ?: "()"}, tokio_postgres::Error>".asType
+
+    /** Doc of the return of _bulk insert_ */
+    val returnDoc = autoIdDetails?.insertReturnDoc ?: "Success or tokio_postgres::Error"
+
+    /** Body of _bulk insert_ running the statement bound by [statementLet] as [statementArg] */
+    fun bulkInsertBody(statementLet: String, statementArg: String) = """
 ${autoIdDetails?.autoIdVecLet ?: ""}
 ${table.unnestColumnVectorDecls}
 
-${rustInsertStatement.letStatement}
+$statementLet
 for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
     for row in chunk_rows.iter() {
 ${table.bulkUpdateUnnestAssignments}
     }
     
     let chunk_result = client.$queryOrExecute(
-        ${rustInsertStatement.asStr},
-        &[${table.nonAutoIncColumns.joinToString(", ") { "&${it.nameId}" }}]
+        $statementArg,
+        &[${table.insertColumns.joinToString(", ") { "&${it.nameId}" }}]
     ).await;
     
     match &chunk_result {
@@ -111,7 +106,18 @@ ${table.bulkUpdateUnnestAssignments}
 }
 
 Ok(${autoIdDetails?.collectResult ?: "()"})""".trimIndent()
-            ),
+
+    val bulkInsertFn
+        get() = Fn(
+            "bulk_insert",
+            "Insert large batch of [${id.capCamel}] rows.",
+            clientFnParam,
+            inputFnParam,
+            tableGateway.bulkInsertChunkSizeFnParam,
+            genericParamSet = genericClientParamSet,
+            returnType = returnType,
+            returnDoc = returnDoc,
+            body = FnBody(bulkInsertBody(rustInsertStatement.letStatement, rustInsertStatement.asStr)),
             isAsync = true,
             hasUnitTest = false,
             testFnAttrs = attrSerializeTest.asAttrList
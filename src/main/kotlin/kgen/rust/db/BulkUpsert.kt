@@ -1,6 +1,7 @@
 package kgen.rust.db
 
 import kgen.asId
+import kgen.db.DbColumn
 import kgen.rust.*
 import kgen.rustQuote
 
@@ -10,12 +11,23 @@ import kgen.rustQuote
  * the rows, gets the _auto id_ and wraps both in the _Entry_ struct.
  * If no _auto id_ it borrows the rows to upsert.
  *
+ * Updated rows keep their existing _auto id_ and `RETURNING` has no defined order, so with an
+ * _auto id_ the rows are unnested `WITH ORDINALITY` and the upserted rows are joined back to
+ * them on the conflict key, selecting the ids in the order of the rows. The join uses sql
+ * equality, as does the conflict, so every row finds its id. Rows with a `NULL` in the conflict
+ * key never conflict nor join, so nullable conflict columns are rejected with an _auto id_.
+ *
  *  @property tableGateway Data associated with the table
  *  @property autoIdDetails Details if the table has an _auto id_
+ *  @property conflictColumns The `ON CONFLICT` target columns, defaulting to the primary key
+ *  or first unique index for _auto id_ tables
+ *  @property fnNameId Name of the generated function
  */
 data class BulkUpsert(
     val tableGateway: TableGateway,
-    val autoIdDetails: AutoIdDetails?
+    val autoIdDetails: AutoIdDetails?,
+    val conflictColumns: List<DbColumn> = tableGateway.table.onConflictColumns,
+    val fnNameId: String = "bulk_upsert",
 ) {
 
     /** Table name */
@@ -36,7 +48,7 @@ data class BulkUpsert(
     /** Expression for number of rows returned */
     val insertCount: String
 
-    /** The returning clause, required to get _auto id_ back if auto id */
+    /** The returning clause, required to get _auto id_ back if auto id, with the conflict key to match it to its row */
     val returningId: String
 
     init {
@@ -47,26 +59,77 @@ data class BulkUpsert(
         } else {
             queryOrExecute = "query"
             insertCount = "chunk_result.len()"
-            returningId = "\nreturning ${tableGateway.autoIncQueryColumn!!.id}"
+            returningId = "\nRETURNING ${tableGateway.autoIncQueryColumn!!.id}, ${conflictColumns.asConflictKey}"
+            conflictColumns.firstOrNull { it.type.isNullable }?.let {
+                throw Exception("Conflict column `${it.nameId}` of `${table.nameId}` must not be nullable to upsert with an auto id")
+            }
         }
     }
 
-    /** The sql `ON CONFLICT` clause indicating how to match rows if already present */
+    /** The assignments updating a conflicting row, leaving its key untouched.
+     * Neither the conflict columns nor any primary key column is assigned, so upserting on a unique
+     * index never rewrites the primary key. Any `updated_at` column is set to `now()` and any soft
+     * deleted row is revived.
+     */
     val onConflictAssignments
-        get() = table.valueColumns.joinToString(",\n\t") { column ->
+        get() = (table.insertColumns.filter { it !in conflictColumns && it !in table.primaryKeyColumns }.map { column ->
             val term = column.nameId.asId
             "${term.snake} = EXCLUDED.${term.snake}"
+        } + listOfNotNull(
+            table.updatedAtAssignment,
+            table.deletedAtColumn?.let { "${it.asSqlLiteral} = NULL" }
+        )).joinToString(",\n\t")
+
+    /** The sql `ON CONFLICT` action. With nothing to assign conflicting rows are left as they are,
+     * with `DO NOTHING` - unless the table has an _auto id_, which a conflicting row would then not
+     * return, so instead a conflict column is assigned its own value.
+     */
+    val onConflictAction
+        get() = when {
+            onConflictAssignments.isNotEmpty() -> "DO UPDATE SET\n    $onConflictAssignments"
+            autoIdDetails == null -> "DO NOTHING"
+            else -> conflictColumns.first().asSqlLiteral.let { "DO UPDATE SET\n    $it = EXCLUDED.$it" }
         }
 
+    /** The insert of the rows selected by [select] resolving conflicts on the conflict columns */
+    private fun upsert(select: String) = """insert into $tableName
+${tableGateway.insertColumnSetLiteralValue}
+$select
+ON CONFLICT (${conflictColumns.asConflictKey})
+$onConflictAction$returningId"""
+
+    /** The unnested value of [column] as inserted, any array cast back from its text literal */
+    private fun unnestedValue(column: DbColumn) =
+        "unnested.${column.nameId}${if (column.type.isArray) column.sqlCast else ""}"
+
+    /** Upsert of the rows, with an _auto id_ selecting the id of each row in the order of the rows */
     val upsertStatement = rustQuote(
-        """insert into $tableName
-${tableGateway.nonAutoIncColumnSetLiteralValue}
+        if (autoIdDetails == null) {
+            "${upsert(tableGateway.unnestSelectValue)}\n"
+        } else {
+            """WITH unnested AS (
 SELECT * FROM UNNEST
-${tableGateway.unnestedColumnExpressionValue}
-ON CONFLICT (${table.onConflictKey})
-DO UPDATE SET
-    ${onConflictAssignments}$returningId
+${tableGateway.unnestedColumnExpressionValue} WITH ORDINALITY AS unnested(${
+                table.insertColumns.chunked(6).joinToString(",\n\t") { chunk -> chunk.joinToString(", ") { it.nameId } }
+            }, ordinality)
+), upserted AS (
+${
+                upsert(
+                    "SELECT ${
+                        table.insertColumns.chunked(6).joinToString(",\n\t") { chunk ->
+                            chunk.joinToString(", ") { unnestedValue(it) }
+                        }
+                    }${table.setOnInsertValues}\nFROM unnested"
+                )
+            }
+)
+SELECT upserted.${tableGateway.autoIncQueryColumn!!.id}
+FROM unnested JOIN upserted ON ${
+                conflictColumns.joinToString(" AND ") { "${unnestedValue(it)} = upserted.${it.asSqlLiteral}" }
+            }
+ORDER BY unnested.ordinality
 """
+        }
     )
 
     val rustUpsertStatement = RustSqlStatement(
@@ -82,43 +145,47 @@ DO UPDATE SET
         "Row data to insert"
     )
 
-    val bulkUpsertFn = Fn(
-        "bulk_upsert",
-        "Upsert large batch of [${id.capCamel}] rows.",
-        clientFnParam,
-        inputFnParam,
-        tableGateway.bulkInsertChunkSizeFnParam,
-        genericParamSet = genericClientParamSet,
-        returnType = "Result<${autoIdDetails?.outputType ?: "()"}, tokio_postgres::Error>".asType,
-        returnDoc = "",
-        body = FnBody(
-            """
+    /** Return type of _bulk upsert_ */
+    val returnType = "Result<${autoIdDetails?.outputType ?: "()"}, tokio_postgres::Error>".asType
+
+    /** Body of _bulk upsert_ running the statement bound by [statementLet] as [statementArg] */
+    fun bulkUpsertBody(statementLet: String, statementArg: String) = """
 ${autoIdDetails?.autoIdVecLet ?: ""}
 ${table.unnestColumnVectorDecls}
-${rustUpsertStatement.letStatement}
+$statementLet
 for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
     for row in chunk_rows.iter() {
 ${table.bulkUpdateUnnestAssignments}
     }
     let chunk_result = client.$queryOrExecute(
-        ${rustUpsertStatement.asStr},
-        &[${table.nonAutoIncColumns.joinToString(", ") { "&${it.nameId}" }}]
+        $statementArg,
+        &[${table.insertColumns.joinToString(", ") { "&${it.nameId}" }}]
     ).await;
     
     match &chunk_result {
         Err(err) => {
-            tracing::error!("Failed bulk_upsert `${table.nameId}` chunk({chunk}) -> {err}");
+            tracing::error!("Failed $fnNameId `${table.nameId}` chunk({chunk}) -> {err}");
             chunk_result?;
         }
         Ok(chunk_result) => {
             tracing::debug!("Finished bulk upsert of size({}) in `${table.nameId}`", $insertCount);
-            ${autoIdDetails?.pushAutoId ?: ""} 
+            ${autoIdDetails?.pushAutoId ?: ""}
         }
     }
     ${table.bulkUnnestClearStatements}
 }
 Ok(${autoIdDetails?.collectResult ?: "()"})""".trimIndent()
-        ),
+
+    val bulkUpsertFn = Fn(
+        fnNameId,
+        "Upsert large batch of [${id.capCamel}] rows, resolving conflicts on (${conflictColumns.asConflictKey}).",
+        clientFnParam,
+        inputFnParam,
+        tableGateway.bulkInsertChunkSizeFnParam,
+        genericParamSet = genericClientParamSet,
+        returnType = returnType,
+        returnDoc = "",
+        body = FnBody(bulkUpsertBody(rustUpsertStatement.letStatement, rustUpsertStatement.asStr)),
         isAsync = true,
         hasUnitTest = false,
         testFnAttrs = attrSerializeTest.asAttrList
@@ -0,0 +1,80 @@
+package kgen.rust.db
+
+import kgen.doubleQuote
+import kgen.rust.*
+
+/** Responsible for generating the enum of the columns of the table, used to build typed [filterEnum]s,
+ * and the `select_all_filtered` function that selects with them.
+ *
+ * @property tableGateway The table gateway
+ */
+data class ColumnEnum(val tableGateway: TableGateway) {
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Name of the rust column enum (e.g. `SampleColumn`) */
+    val enumName = "${tableGateway.id.capCamel}Column"
+
+    /** The type returned when selecting rows - the entry if table has _auto id_ else the row data */
+    val returnType = tableGateway.rowEntryStructName ?: tableGateway.rowDataStructName
+
+    val columnEnum = Enum(
+        "${tableGateway.id.snake}_column",
+        """The columns of `${table.tableName}`, with variants in the same order as the columns.""",
+        table.columns.map { column ->
+            Variant.UnitStruct(column.nameId, "The `${column.columnName}` column")
+        },
+        attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"),
+        typeImpl = TypeImpl(
+            enumName.asType,
+            emptyList(),
+            consts = listOf(
+                Const(
+                    "all",
+                    "All columns of the table",
+                    "[$enumName; ${table.columns.size}]".asType,
+                    ConstValue(
+                        "[${table.columns.joinToString(", ") { "$enumName::${it.id.capCamel}" }}]"
+                    )
+                )
+            )
+        ),
+        traitImpls = listOf(
+            TraitImpl(
+                enumName.asType,
+                sqlColumnTrait,
+                bodies = mapOf(
+                    "column_name" to listOf(
+                        "match self {",
+                        table.columns.joinToString(",\n") {
+                            "    $enumName::${it.id.capCamel} => ${
+                                doubleQuote(it.asSqlLiteral.replace("\"", "\\\""))
+                            }"
+                        },
+                        "}"
+                    ).joinToString("\n")
+                )
+            )
+        )
+    )
+
+    val selectAllFilteredFn
+        get() = Fn(
+            "select_all_filtered",
+            "Select rows of `${table.tableName}` matching the typed `filter`",
+            clientFnParam,
+            FnParam("filter", "&Filter<$enumName>".asType, "The filter on the columns of the table"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<Vec<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "Selected rows or the `tokio_postgres::Error`",
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                """
+let (where_clause, params) = filter.render();
+Self::select_all_where(client, &where_clause, &params).await
+                """.trimIndent()
+            )
+        )
+}
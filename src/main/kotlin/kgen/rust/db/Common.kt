@@ -25,3 +25,24 @@ val clientFnParam = FnParam(
 val genericClientParamSet = GenericParamSet(
     TypeParam("c", bounds = Bounds("tokio_postgres::GenericClient"))
 )
+
+/**
+ * Represents a function parameter for a pooled `deadpool_postgres` client, whose connection
+ * caches the statements prepared through it.
+ */
+val cachedClientFnParam = FnParam(
+    "client",
+    "&C".asType,
+    "The pooled deadpool postgresql client, caching the statements prepared on its connection"
+)
+
+/**
+ * Represents a generic parameter set for Rust functions preparing their statements through
+ * the statement cache of a pooled connection.
+ *
+ * The type parameter `C` is bounded by the `deadpool_postgres::GenericClient` trait, which adds
+ * `prepare_cached` to the interface of `tokio_postgres::GenericClient`.
+ */
+val cachedClientParamSet = GenericParamSet(
+    TypeParam("c", bounds = Bounds("deadpool_postgres::GenericClient"))
+)
@@ -0,0 +1,75 @@
+package kgen.rust.db
+
+import kgen.rust.*
+import kgen.rustQuote
+
+/** Responsible for generating `copy_in` method for the table.
+ * Streams rows through `COPY ... FROM STDIN BINARY`, avoiding the per-column
+ * arrays built by `bulk_insert`. Any _auto id_ is assigned by the database
+ * and not returned, since `COPY` has no `RETURNING`. Server managed columns are not
+ * copied, so take their defaults as with the other inserts.
+ *
+ *  @property tableGateway Data associated with the table
+ */
+data class CopyIn(
+    val tableGateway: TableGateway,
+) {
+
+    /** Table name */
+    val tableName get() = tableGateway.table.tableName
+
+    /** Table id */
+    val id get() = tableGateway.id
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    val rustCopyInStatement = RustSqlStatement(
+        "copy_in_statement",
+        rustQuote(
+            """COPY $tableName
+${tableGateway.callerColumnSetLiteralValue}
+FROM STDIN BINARY"""
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    val copyInFn
+        get() = Fn(
+            "copy_in",
+            """Insert large batch of [${id.capCamel}] rows with `COPY ... FROM STDIN BINARY`.
+                |Prefer over [bulk_insert] when the _auto id_ of inserted rows is not needed.
+            """.trimMargin(),
+            clientFnParam,
+            FnParam("rows", "&[${tableGateway.rowDataStructName}]".asType, "Row data to insert"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<u64, tokio_postgres::Error>".asType,
+            returnDoc = "Number of rows copied in or tokio_postgres::Error",
+            body = FnBody(
+                """
+${table.insertColumns.copyTypesLet}
+${rustCopyInStatement.letStatement}
+let sink = match client.client().copy_in(${rustCopyInStatement.asStr}).await {
+    Ok(sink) => sink,
+    Err(err) => {
+        tracing::error!("Failed copy_in `${table.nameId}` -> {err}");
+        return Err(err);
+    }
+};
+let mut writer = std::pin::pin!(BinaryCopyInWriter::new(sink, &types));
+for row in rows {
+    writer
+        .as_mut()
+        .write(&[${table.insertColumns.joinToString(", ") { it.sqlParam("row") }}])
+        .await?;
+}
+let copied = writer.finish().await?;
+tracing::debug!("Finished copy_in of size({copied}) in `${table.nameId}`");
+Ok(copied)
+                """.trimIndent()
+            ),
+            isAsync = true,
+            hasUnitTest = false,
+            testFnAttrs = attrSerializeTest.asAttrList
+        )
+}
@@ -0,0 +1,81 @@
+package kgen.rust.db
+
+import kgen.rust.*
+import kgen.rust.db.select.SelectAllWhereFn
+import kgen.rustQuote
+
+/** Responsible for generating `copy_out_where` method for the table.
+ * Exports rows through `COPY (SELECT ...) TO STDOUT BINARY`, decoding each row
+ * straight into the row type. Columns are copied in table order, the order of
+ * `FIELD_NAMES` (after any _auto id_), so rows decode exactly as in `select_all_where`.
+ * As with `select_all_where`, any soft deleted rows are skipped.
+ *
+ *  @property selectAllWhere The select function providing the row decoding and return type
+ */
+data class CopyOut(
+    val selectAllWhere: SelectAllWhereFn,
+) {
+
+    /** Underlying table */
+    val table get() = selectAllWhere.table
+
+    /** Table name */
+    val tableName get() = table.tableName
+
+    /** Table id */
+    val id get() = selectAllWhere.id
+
+    /** The type of each row copied out */
+    val returnType = selectAllWhere.returnType
+
+    val rustCopyOutStatement = RustSqlStatement(
+        "copy_out_statement",
+        rustQuote(
+            """COPY (SELECT
+${table.formattedColumnNames}
+FROM $tableName
+WHERE ${selectAllWhere.liveWhereClause}) TO STDOUT BINARY"""
+        ),
+        selectAllWhere.tableGateway.backdoorTableId,
+        true
+    )
+
+    val copyOutWhereFn
+        get() = Fn(
+            "copy_out_where",
+            """Export rows of `${id.snake}` with provided where clause with `COPY ... TO STDOUT BINARY`.
+                |`COPY` does not support parameters so the where clause is embedded as is and
+                |must not contain untrusted input.
+            """.trimMargin(),
+            clientFnParam,
+            FnParam("where_clause", "&str".asType, "The where clause (sans `where` keyword)"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<Vec<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "Copied out rows or the `tokio_postgres::Error`",
+            body = FnBody(
+                """
+${table.columns.copyTypesLet}
+${rustCopyOutStatement.letStatement}
+let stream = match client.client().copy_out(${rustCopyOutStatement.asStr}).await {
+    Ok(stream) => stream,
+    Err(err) => {
+        tracing::error!("Failed copy_out_where `${table.nameId}` -> {err}");
+        return Err(err);
+    }
+};
+let mut rows = std::pin::pin!(BinaryCopyOutStream::new(stream, &types));
+
+let mut results = Vec::<$returnType>::new();
+while let Some(row) = rows.next().await {
+    let row = row?;
+    results.push(${selectAllWhere.rowConstruction(isBinaryCopyRow = true)});
+}
+tracing::debug!("Finished copy_out_where of size({}) in `${table.nameId}`", results.len());
+Ok(results)
+                """.trimIndent()
+            ),
+            isAsync = true,
+            hasUnitTest = false,
+            testFnAttrs = attrSerializeTest.asAttrList
+        )
+}
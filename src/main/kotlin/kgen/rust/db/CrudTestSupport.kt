@@ -3,6 +3,8 @@ package kgen.rust.db
 import kgen.blockComment
 import kgen.db.DbTableClassifier
 import kgen.rust.*
+import kgen.rust.db.select.QueryColumn
+import kgen.rustQuote
 
 data class CrudTestSupport(
     val tableGateway: TableGateway,
@@ -27,8 +29,13 @@ data class CrudTestSupport(
     val uses = listOf(
         "super::support::get_pool",
         "super::support::MutateValue",
+        "futures::TryStreamExt",
         "std::collections::BTreeSet",
         "tokio_postgres::GenericClient",
+    ).asUses + listOfNotNull(
+        "kgen_db::unsigned::U64".takeIf { table.hasUnsigned },
+        "kgen_db::interval::Interval".takeIf { table.hasInterval },
+        "super::support::mutate_json".takeIf { table.jsonPayloadColumns.isNotEmpty() },
     ).asUses + listOf(
         "std::ops::Deref",
     ).asAllowUnusedUses
@@ -45,11 +52,635 @@ data class CrudTestSupport(
         "&samples"
     }
 
+    /** Accessor of the row data from a selected row - the entry's data if table has _auto id_ */
+    val selectedDataAccess = if (hasAutoId) {
+        ".data"
+    } else {
+        ""
+    }
+
+    /** Columns set by the server, cleared before comparing rows to the samples */
+    val serverManagedColumns get() = table.columns.filter { it.isServerManaged }
+
+    /** Statements clearing the server managed fields of [rowData] before comparison, if any */
+    fun clearServerManaged(rowData: String) = if (serverManagedColumns.isNotEmpty()) {
+        "clear_server_managed(&mut $rowData);"
+    } else {
+        ""
+    }
+
+    /** Statements running the bulk [fnName] of the samples, tracing the entries returned if table has _auto id_ */
+    fun bulkSamplesCall(fnName: String, binding: String) = if (hasAutoId) {
+        """
+let $binding = ${tableStructName}::$fnName(client, $bulkSamplesArg, 4).await.unwrap();
+tracing::debug!("Inserted with `$fnName` -> {$binding:?}");
+        """.trimIndent()
+    } else {
+        "${tableStructName}::$fnName(client, $bulkSamplesArg, 4).await.unwrap();"
+    }
+
+    /** Binding of a reselected row, mutable if its server managed fields are to be cleared */
+    val reselectedBinding get() = if (serverManagedColumns.isNotEmpty()) "mut reselected" else "reselected"
+
+    /** Test every nullable column round trips `NULL`, run on the empty table after basic insert */
+    val nullRoundTripTestBlock: String
+        get() {
+            val nullableColumns = table.columns.filter { it.type.isNullable }
+            if (nullableColumns.isEmpty()) {
+                return ""
+            }
+            return """
+${"Test NULL round trips for every nullable column".blockComment}
+{
+let null_samples = get_sample_rows()
+    .into_iter()
+    .map(|mut row| {
+${nullableColumns.joinToString("\n") { "        row.${it.nameId} = None;" }}
+        row
+    })
+    .collect::<Vec<_>>();
+${tableStructName}::basic_insert(client, ${if (hasAutoId) "null_samples.clone()" else "&null_samples"}).await.unwrap();
+select_and_compare_assert(client, &null_samples, "Null Round Trip Cmp").await;
+let deleted = ${tableStructName}::delete_all(client).await.unwrap();
+assert_eq!(null_samples.len(), deleted as usize);
+${"Same through `UNNEST` of bulk insert, which passes arrays as text literals".blockComment}
+${tableStructName}::bulk_insert(client, ${if (hasAutoId) "null_samples.clone()" else "&null_samples"}, 4).await.unwrap();
+select_and_compare_assert(client, &null_samples, "Null Bulk Round Trip Cmp").await;
+let deleted = ${tableStructName}::delete_all(client).await.unwrap();
+assert_eq!(null_samples.len(), deleted as usize);
+}
+            """.trimIndent()
+        }
+
+    /** True if the table has a key beyond any _auto id_, so rows may conflict */
+    val hasNaturalKey
+        get() = table.uniqueIndices.isNotEmpty() ||
+                (table.hasPrimaryKey && table.primaryKeyColumns.none { it.isAutoIncrement })
+
+    /** Test of `bulk_insert_transactional` in both modes, duplicating the first chunk of samples so the
+     * chunks after the last chunk of only samples conflict. The expected chunks follow from the sample
+     * count and chunk size. Only for tables with a key beyond any _auto id_ to conflict on.
+     */
+    val transactionalBatchTestBlock: String
+        get() {
+            if (!hasNaturalKey) {
+                return ""
+            }
+            return """
+${"Test transactional bulk insert, where duplicates of the first samples fail the trailing chunks".blockComment}
+{
+let mut batch_resource = get_pool().await.get().await.unwrap();
+let samples = get_sample_rows();
+let chunk_size = 4;
+let rows = samples.iter().chain(samples.iter().take(chunk_size)).cloned().collect::<Vec<_>>();
+let chunk_count = rows.len().div_ceil(chunk_size);
+let committed_chunks = samples.len() / chunk_size;
+let report = ${tableStructName}::bulk_insert_transactional(&mut **batch_resource, &rows, chunk_size, BatchMode::ChunkSavepoints)
+    .await
+    .unwrap();
+assert_eq!(
+    (0..committed_chunks).collect::<Vec<_>>(),
+    report.succeeded.iter().map(|success| success.chunk).collect::<Vec<_>>()
+);
+assert_eq!(
+    (committed_chunks..chunk_count).collect::<Vec<_>>(),
+    report.failed.iter().map(|failure| failure.chunk).collect::<Vec<_>>()
+);
+assert_eq!(
+    (committed_chunks * chunk_size) as i64,
+    ${tableStructName}::count_where(client, "1=1", &[]).await.unwrap()
+);
+${tableStructName}::delete_all(client).await.unwrap();
+
+let report = ${tableStructName}::bulk_insert_transactional(&mut **batch_resource, &rows, chunk_size, BatchMode::AllOrNothing)
+    .await
+    .unwrap();
+assert!(report.succeeded.is_empty());
+assert_eq!(
+    (0..chunk_count).collect::<Vec<_>>(),
+    report.failed.iter().map(|failure| failure.chunk).collect::<Vec<_>>()
+);
+assert_eq!(1, report.failed.iter().filter(|failure| failure.error.is_some()).count());
+assert!(!${tableStructName}::exists_where(client, "1=1", &[]).await.unwrap());
+
+let report = ${tableStructName}::bulk_insert_transactional(&mut **batch_resource, &samples, chunk_size, BatchMode::AllOrNothing)
+    .await
+    .unwrap();
+assert!(report.is_complete());
+assert_eq!(samples.len(), ${tableStructName}::select_all(client).await.unwrap().len());
+${tableStructName}::delete_all(client).await.unwrap();
+}
+            """.trimIndent()
+        }
+
+    /** Test of soft delete, run after bulk insert populates the table, which it leaves populated with the samples.
+     * Soft deleted rows are invisible to the selects until removed by `hard_delete_all`.
+     */
+    val softDeleteTestBlock: String
+        get() {
+            val deletedAtColumn = table.deletedAtColumn ?: return ""
+            val pkeyTest = if (tableGateway.pkeyLookup != null) {
+                """
+let key = ${tableStructName}::select_all(client).await.unwrap()[0].pkey();
+assert_eq!(1, ${tableStructName}::delete_by_pkey(client, &key).await.unwrap());
+assert!(${tableStructName}::select_by_pkey(client, &key).await.unwrap().is_none());
+assert_eq!(0, ${tableStructName}::delete_by_pkey(client, &key).await.unwrap());
+                """.trimIndent()
+            } else {
+                ""
+            }
+            return """
+${"Test soft delete hides rows from the selects until hard deleted".blockComment}
+{
+$pkeyTest
+let live = ${tableStructName}::select_all(client).await.unwrap().len();
+assert_eq!(live, ${tableStructName}::delete_all(client).await.unwrap() as usize);
+assert_eq!(0, ${tableStructName}::select_all(client).await.unwrap().len());
+let including_deleted = ${tableStructName}::select_all_where_including_deleted(client, "1=1", &[]).await.unwrap();
+assert!(including_deleted.len() >= samples.len());
+assert!(including_deleted.iter().all(|row| row$selectedDataAccess.${deletedAtColumn.nameId}.is_some()));
+assert_eq!(including_deleted.len() as u64, ${tableStructName}::hard_delete_all(client).await.unwrap());
+assert!(${tableStructName}::select_all_where_including_deleted(client, "1=1", &[]).await.unwrap().is_empty());
+${tableStructName}::bulk_insert(client, $bulkSamplesArg, 4).await.unwrap();
+}
+            """.trimIndent()
+        }
+
+    /** Test of the `_prepared` functions against the statements cached by the pooled connection,
+     * run after bulk insert populates the table, which it leaves populated with the samples
+     */
+    val preparedTestBlock: String
+        get() {
+            val upsertTest = if (tableGateway.preparedStatements.bulkUpsertPreparedFn != null) {
+                """
+${tableStructName}::bulk_upsert_prepared(&resource, $bulkSamplesArg, 4).await.unwrap();
+select_and_compare_assert(client, &samples, "Prepared Blk Upsert Cmp").await;
+                """.trimIndent()
+            } else {
+                ""
+            }
+            val pkeyTest = if (tableGateway.pkeyLookup != null) {
+                """
+for row in ${tableStructName}::select_all(client).await.unwrap() {
+    let selected = ${tableStructName}::select_by_pkey_prepared(&resource, &row.pkey()).await.unwrap().unwrap();
+    assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+}
+                """.trimIndent()
+            } else {
+                ""
+            }
+            return """
+${"Test the functions running the prepared statements".blockComment}
+{
+let deleted = ${tableStructName}::delete_all_prepared(&resource).await.unwrap();
+assert_eq!(samples.len(), deleted as usize);
+${tableStructName}::bulk_insert_prepared(&resource, $bulkSamplesArg, 4).await.unwrap();
+select_and_compare_assert(client, &samples, "Prepared Blk Ins Cmp").await;
+$upsertTest
+$pkeyTest
+}
+            """.trimIndent()
+        }
+
+    /** Rows to copy in, with the server managed fields cleared so any copied would be caught */
+    private val copyInRows
+        get() = if (serverManagedColumns.isNotEmpty()) {
+            """
+let copy_in_rows = bulk_inserted.iter().cloned().map(|mut row| {
+    clear_server_managed(&mut row);
+    row
+}).collect::<Vec<_>>();
+            """.trimIndent()
+        } else {
+            "let copy_in_rows = bulk_inserted.clone();"
+        }
+
+    /** Assertions the columns set on insert took their server defaults on copy in, if any */
+    private val copyInServerManagedAssert
+        get() = table.setOnInsertColumns.takeIf { it.isNotEmpty() }?.let { columns ->
+            """
+for row in ${tableStructName}::select_all(client).await.unwrap() {
+${
+                columns.joinToString("\n") {
+                    "    assert_ne!(<${it.asRustType.asRustName}>::default(), row$selectedDataAccess.${it.nameId});"
+                }
+            }
+}
+            """.trimIndent()
+        } ?: ""
+
+    /** Test of `copy_in` reloading the rows loaded by `bulk_insert` and of `copy_out_where`
+     * exporting the same rows as `select_all`, run after bulk insert populates the table
+     */
+    val copyInTestBlock
+        get() = """
+${"Test copy in reloads the bulk inserted data and copy out matches select".blockComment}
+{
+let bulk_inserted = ${tableStructName}::select_all(client).await.unwrap()${
+            if (hasAutoId) "\n    .into_iter().map(|row| row.data).collect::<Vec<_>>()" else ""
+        };
+let deleted = ${tableStructName}::delete_all(client).await.unwrap();
+assert_eq!(bulk_inserted.len(), deleted as usize);
+$copyInRows
+assert_eq!(bulk_inserted.len() as u64, ${tableStructName}::copy_in(client, &copy_in_rows).await.unwrap());
+select_and_compare_assert(client, &bulk_inserted, "Copy In Cmp").await;
+$copyInServerManagedAssert
+
+let selected = ${tableStructName}::select_all(client).await.unwrap();
+let copied_out = ${tableStructName}::copy_out_where(client, "1=1").await.unwrap();
+assert_eq!(
+    selected.iter().map(|row| format!("{row:?}")).collect::<BTreeSet<_>>(),
+    copied_out.iter().map(|row| format!("{row:?}")).collect::<BTreeSet<_>>()
+);
+}
+        """.trimIndent()
+
+    /** Test of the streaming selects against `select_all`, run after bulk insert populates the table */
+    val streamTestBlock
+        get() = """
+${"Test the streaming selects".blockComment}
+{
+let selected_count = ${tableStructName}::count_where(client, "1=1", &[]).await.unwrap() as usize;
+let streamed = ${tableStructName}::select_stream_where(client, "1=1", &[]).await.unwrap()
+    .try_collect::<Vec<_>>().await.unwrap();
+assert_eq!(selected_count, streamed.len());
+
+let mut chunk_resource = get_pool().await.get().await.unwrap();
+let transaction = chunk_resource.transaction().await.unwrap();
+let chunks = ${tableStructName}::select_chunks_where(&transaction, "1=1", &[], 3).await.unwrap()
+    .try_collect::<Vec<_>>().await.unwrap();
+assert!(chunks.iter().all(|chunk| chunk.len() <= 3));
+assert_eq!(selected_count, chunks.iter().map(|chunk| chunk.len()).sum::<usize>());
+transaction.commit().await.unwrap();
+}
+        """.trimIndent()
+
+    /** Test of paging through the table by keyset and by offset, run after bulk insert populates the table.
+     * Both must visit every row, in the same order.
+     */
+    val pageTestBlock: String
+        get() {
+            if (!tableGateway.selectPage.isPageable) {
+                return ""
+            }
+            return """
+${"Test paging by keyset and by offset".blockComment}
+{
+let selected_count = ${tableStructName}::count_where(client, "1=1", &[]).await.unwrap() as usize;
+let mut keyset_rows = Vec::new();
+let mut after = None;
+loop {
+    let page = ${tableStructName}::select_page_after(client, after.as_ref(), 3).await.unwrap();
+    assert!(page.rows.len() <= 3);
+    keyset_rows.extend(page.rows);
+    after = page.next;
+    if after.is_none() {
+        break;
+    }
+}
+let mut offset_rows = Vec::new();
+let mut offset = Some(0);
+while let Some(at) = offset {
+    let page = ${tableStructName}::select_page_at_offset(client, at, 3).await.unwrap();
+    offset_rows.extend(page.rows);
+    offset = page.next;
+}
+assert_eq!(selected_count, keyset_rows.len());
+assert_eq!(format!("{keyset_rows:?}"), format!("{offset_rows:?}"));
+}
+            """.trimIndent()
+        }
+
+    /** Test of the aggregates against the samples, run after bulk insert populates the table */
+    val aggregateTestBlock: String
+        get() {
+            val extremeTest = tableGateway.aggregates.orderableColumns.firstOrNull { !it.type.isNullable }?.let {
+                """
+let min = ${tableStructName}::min_${it.nameId}(client, "1=1", &[]).await.unwrap().unwrap();
+let max = ${tableStructName}::max_${it.nameId}(client, "1=1", &[]).await.unwrap().unwrap();
+assert!(min <= max);
+assert!(${tableStructName}::count_where(client, ${rustQuote("${it.asSqlLiteral} = $1")}, &[&min]).await.unwrap() >= 1);
+assert_eq!(None, ${tableStructName}::max_${it.nameId}(client, "1=0", &[]).await.unwrap());
+                """.trimIndent()
+            } ?: ""
+            return """
+${"Test the aggregates".blockComment}
+{
+assert_eq!(samples.len() as i64, ${tableStructName}::count_where(client, "1=1", &[]).await.unwrap());
+assert_eq!(0, ${tableStructName}::count_where(client, "1=0", &[]).await.unwrap());
+assert!(${tableStructName}::exists_where(client, "1=1", &[]).await.unwrap());
+assert!(!${tableStructName}::exists_where(client, "1=0", &[]).await.unwrap());
+$extremeTest
+}
+            """.trimIndent()
+        }
+
+    /** Statement mutating the field of [queryColumn] of `row_data`, any json payload through its `Value` */
+    private fun mutateField(queryColumn: QueryColumn) = when {
+        queryColumn.dbColumn?.jsonPayload == null -> "row_data.${queryColumn.id}.mutate_value();"
+        queryColumn.type.isNullable -> "if let Some(payload) = row_data.${queryColumn.id}.as_mut() { mutate_json(payload); }"
+        else -> "mutate_json(&mut row_data.${queryColumn.id});"
+    }
+
+    /** Test of the typed filter on the first data column without a json payload, run after bulk insert
+     * populates the table
+     */
+    val filterTestBlock: String
+        get() {
+            val column = tableGateway.dataQueryColumns.queryColumns.first { it.dbColumn?.jsonPayload == null }.dbColumn!!
+            val columnVariant = "${tableGateway.columnEnum.enumName}::${column.id.capCamel}"
+            return """
+${"Test the typed filter".blockComment}
+{
+let value = ${column.ownedValue("samples[0]")};
+let expected = samples.iter().filter(|row| row.${column.nameId} == value).count();
+let matched = ${tableStructName}::select_all_filtered(client, &Filter::eq($columnVariant, value)).await.unwrap();
+assert_eq!(expected, matched.len());
+let unmatched = ${tableStructName}::select_all_filtered(
+    client,
+    &Filter::ne($columnVariant, ${column.ownedValue("samples[0]")}).or(Filter::is_null($columnVariant))
+).await.unwrap();
+assert_eq!(samples.len(), matched.len() + unmatched.len());
+assert_eq!(
+    samples.len(),
+    ${tableStructName}::select_all_filtered(client, &Filter::And(vec![])).await.unwrap().len()
+);
+}
+            """.trimIndent()
+        }
+
+    /** Test of the primary key functions, run after bulk insert populates the table */
+    val pkeyTestBlock: String
+        get() {
+            val pkeyLookup = tableGateway.pkeyLookup ?: return ""
+            val updateTest = if (pkeyLookup.updateByKeyFn != null) {
+                """
+let mut updated = ${tableStructName}::select_by_pkey(client, key).await.unwrap().unwrap();
+mutate_row_data(&mut updated$selectedDataAccess);
+assert_eq!(1, ${tableStructName}::update_by_pkey(client, key, &updated$selectedDataAccess).await.unwrap());
+let ${reselectedBinding} = ${tableStructName}::select_by_pkey(client, key).await.unwrap().unwrap();
+${clearServerManaged("updated$selectedDataAccess")}
+${clearServerManaged("reselected$selectedDataAccess")}
+assert_eq!(format!("{updated:?}"), format!("{reselected:?}"));
+                """.trimIndent()
+            } else {
+                ""
+            }
+
+            return """
+${"Test the primary key functions".blockComment}
+{
+let selected_all = ${tableStructName}::select_all(client).await.unwrap();
+let keys = selected_all.iter().map(|row| row.pkey()).collect::<Vec<_>>();
+for (row, key) in selected_all.iter().zip(keys.iter()) {
+    let selected = ${tableStructName}::select_by_pkey(client, key).await.unwrap().unwrap();
+    assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+}
+assert_eq!(keys.len(), ${tableStructName}::select_by_pkeys(client, &keys).await.unwrap().len());
+
+let key = &keys[0];
+$updateTest
+assert_eq!(1, ${tableStructName}::delete_by_pkey(client, key).await.unwrap());
+assert!(${tableStructName}::select_by_pkey(client, key).await.unwrap().is_none());
+assert_eq!(0, ${tableStructName}::delete_by_pkey(client, key).await.unwrap());
+}
+            """.trimIndent()
+        }
+
+    /** Test of the bulk update and delete by primary key, run after bulk insert populates the table.
+     * The rows deleted are restored so the phases that follow see the same table.
+     */
+    val pkeyBulkTestBlock: String
+        get() {
+            val pkeyBulk = tableGateway.pkeyBulk ?: return ""
+            val keyInEntry = table.primaryKeyColumns.any { it.isAutoIncrement }
+            val updateTest = if (pkeyBulk.bulkUpdateFn != null) {
+                val updateRows = if (hasAutoId && !keyInEntry) {
+                    "&bulk_rows.iter().map(|row| row.data.clone()).collect::<Vec<_>>()"
+                } else {
+                    "&bulk_rows"
+                }
+                """
+bulk_rows.iter_mut().for_each(${if (hasAutoId) "|row| mutate_row_data(&mut row.data)" else "mutate_row_data"});
+assert_eq!(bulk_rows.len() as u64, ${tableStructName}::bulk_update(client, $updateRows, 4).await.unwrap());
+select_and_compare_assert(client, ${if (hasAutoId) "&bulk_rows.iter().map(|row| row.data.clone()).collect::<Vec<_>>()" else "&bulk_rows"}, "Blk Update Cmp").await;
+                """.trimIndent()
+            } else {
+                ""
+            }
+            val restoreArg = if (hasAutoId) {
+                "bulk_rows.into_iter().map(|row| row.data).collect()"
+            } else {
+                "&bulk_rows"
+            }
+            return """
+${"Test the bulk update and delete by primary key".blockComment}
+{
+let ${if (pkeyBulk.bulkUpdateFn != null) "mut bulk_rows" else "bulk_rows"} = ${tableStructName}::select_all(client).await.unwrap();
+$updateTest
+let keys = bulk_rows.iter().map(|row| row${if (keyInEntry) "" else selectedDataAccess}.pkey()).collect::<Vec<_>>();
+assert_eq!(keys.len() as u64, ${tableStructName}::bulk_delete_by_pkeys(client, &keys, 4).await.unwrap());
+assert_eq!(0, ${tableStructName}::count_where(client, "1=1", &[]).await.unwrap());
+assert_eq!(0, ${tableStructName}::bulk_delete_by_pkeys(client, &keys, 4).await.unwrap());
+${tableStructName}::bulk_upsert(client, $restoreArg, 4).await.unwrap();
+}
+            """.trimIndent()
+        }
+
+    /** Test of `sync_rows`, run after bulk insert populates the table. The table is synced to its rows
+     * with one row removed, one changed and one deleted beforehand from the table, then synced again
+     * to no changes, with a stale copy of a row ahead of it that the later row must override. The row removed is restored so the phases that follow see the same table.
+     */
+    val syncRowsTestBlock: String
+        get() {
+            val syncRows = tableGateway.syncRows
+            if (!syncRows.isSyncable) {
+                return ""
+            }
+            val hasUpdates = tableGateway.pkeyBulk?.bulkUpdateFn != null
+            return """
+${"Test the sync of the table to rows".blockComment}
+{
+let mut sync_resource = get_pool().await.get().await.unwrap();
+let mut target = ${tableStructName}::select_all(client).await.unwrap();
+assert_eq!(1, ${tableStructName}::delete_by_pkey(client, &target[0].pkey()).await.unwrap());
+${if (hasUpdates) "mutate_row_data(&mut target[1]);" else ""}
+let removed = target.remove(2);
+let summary = ${tableStructName}::sync_rows(&mut **sync_resource, &target).await.unwrap();
+assert_eq!(
+    SyncSummary {
+        inserted: 1,
+        updated: ${if (hasUpdates) 1 else 0},
+        deleted: 1,
+        unchanged: target.len() as u64 - ${if (hasUpdates) 2 else 1},
+    },
+    summary
+);
+select_and_compare_assert(client, &target, "Sync Cmp").await;
+let mut repeated = target.clone();
+${if (hasUpdates) "let mut stale = target[0].clone();\nmutate_row_data(&mut stale);" else "let stale = target[0].clone();"}
+repeated.insert(0, stale);
+let summary = ${tableStructName}::sync_rows(&mut **sync_resource, &repeated).await.unwrap();
+assert_eq!(
+    SyncSummary {
+        unchanged: target.len() as u64,
+        ..Default::default()
+    },
+    summary
+);
+${tableStructName}::bulk_upsert(client, &[removed], 4).await.unwrap();
+}
+            """.trimIndent()
+        }
+
+    /** Test of the update guarded by the version column, run after bulk insert populates the table.
+     * A stale version must conflict, leaving the row as last updated.
+     */
+    val versionedUpdateTestBlock: String
+        get() {
+            val pkeyLookup = tableGateway.pkeyLookup ?: return ""
+            val versionColumn = table.versionColumn ?: return ""
+            if (pkeyLookup.updateByKeyIfVersionFn == null) {
+                return ""
+            }
+            val version = "$selectedDataAccess.${versionColumn.nameId}"
+            return """
+${"Test the update guarded by the version column".blockComment}
+{
+let key = ${tableStructName}::select_all(client).await.unwrap()[0].pkey();
+let mut read = ${tableStructName}::select_by_pkey(client, &key).await.unwrap().unwrap();
+let read_version = read$version;
+mutate_row_data(&mut read$selectedDataAccess);
+read$version = read_version;
+let updated = ${tableStructName}::update_by_pkey_if_version(client, &key, &read$selectedDataAccess).await.unwrap();
+let VersionedUpdate::Updated { version } = updated else {
+    panic!("Expected update at version {read_version:?}, got {updated:?}");
+};
+assert_ne!(read_version, version);
+let ${reselectedBinding} = ${tableStructName}::select_by_pkey(client, &key).await.unwrap().unwrap();
+read$version = version;
+${clearServerManaged("read$selectedDataAccess")}
+${clearServerManaged("reselected$selectedDataAccess")}
+assert_eq!(format!("{read:?}"), format!("{reselected:?}"));
+
+read$version = read_version;
+assert_eq!(
+    VersionedUpdate::Conflict,
+    ${tableStructName}::update_by_pkey_if_version(client, &key, &read$selectedDataAccess).await.unwrap()
+);
+let unchanged = ${tableStructName}::select_by_pkey(client, &key).await.unwrap().unwrap();
+assert_eq!(format!("{reselected:?}"), format!("{unchanged:?}"));
+}
+            """.trimIndent()
+        }
+
+    /** Test of `bulk_upsert` of _auto id_ tables mixing rows inserted with rows updated, in reverse
+     * order, asserting updated rows keep their ids and every entry has the id of its row.
+     */
+    val upsertEntriesTestBlock: String
+        get() {
+            val uniqueIndex = tableGateway.uniqueIndices.firstOrNull { it.indexColumns == table.onConflictColumns }
+            if (!hasAutoId || uniqueIndex == null) {
+                return ""
+            }
+            val indexId = uniqueIndex.id.snake
+            val keyAccessor = uniqueIndex.keyAccessorNameId
+            val autoId = tableGateway.autoIncQueryColumn!!.id.snake
+            return """
+${"Test bulk upsert entries of rows inserted and rows updated".blockComment}
+{
+let existing = ${tableStructName}::select_all(client).await.unwrap();
+let (deleted, kept) = existing.split_at(existing.len() / 2);
+for entry in deleted.iter() {
+    assert_eq!(1, ${tableStructName}::delete_by_$indexId(client, &entry.data.$keyAccessor()).await.unwrap());
+}
+let mut upsert_rows = kept.iter().chain(deleted.iter()).rev().map(|entry| entry.data.clone()).collect::<Vec<_>>();
+upsert_rows.iter_mut().for_each(mutate_row_data);
+let upserted = ${tableStructName}::bulk_upsert(client, upsert_rows.clone(), 4).await.unwrap();
+assert_eq!(upsert_rows.len(), upserted.len());
+for entry in upserted.iter() {
+    let selected = ${tableStructName}::select_by_$indexId(client, &entry.data.$keyAccessor()).await.unwrap().unwrap();
+    assert_eq!(selected.$autoId, entry.$autoId);
+}
+for entry in kept.iter() {
+    let selected = ${tableStructName}::select_by_$indexId(client, &entry.data.$keyAccessor()).await.unwrap().unwrap();
+    assert_eq!(entry.$autoId, selected.$autoId);
+}
+select_and_compare_assert(client, &upsert_rows, "Upsert Entries Cmp").await;
+}
+            """.trimIndent()
+        }
+
+    /** Tests of each unique index's functions, run after bulk insert populates the table.
+     * Rows deleted by index are restored so the phases that follow see the same table.
+     */
+    val uniqueIndexTestBlocks
+        get() = tableGateway.uniqueIndices.joinToString("\n\n") { uniqueIndex ->
+            val indexId = uniqueIndex.id.snake
+            val keyAccessor = uniqueIndex.keyAccessorNameId
+            val upsertArg = if (hasAutoId) "upsert_rows.clone()" else "&upsert_rows"
+            """
+${"Test the unique index `${uniqueIndex.indexName}` functions".blockComment}
+{
+let selected_all = ${tableStructName}::select_all(client).await.unwrap();
+for row in selected_all.iter() {
+    let key = row$selectedDataAccess.$keyAccessor();
+    let selected = ${tableStructName}::select_by_$indexId(client, &key).await.unwrap().unwrap();
+    assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+}
+
+let mut upsert_rows = selected_all.into_iter().map(|row| row$selectedDataAccess).collect::<Vec<_>>();
+upsert_rows.iter_mut().for_each(mutate_row_data);
+${tableStructName}::bulk_upsert_by_$indexId(client, $upsertArg, 4).await.unwrap();
+select_and_compare_assert(client, &upsert_rows, "Upsert By $indexId Cmp").await;
+
+let key = upsert_rows[0].$keyAccessor();
+assert_eq!(1, ${tableStructName}::delete_by_$indexId(client, &key).await.unwrap());
+assert!(${tableStructName}::select_by_$indexId(client, &key).await.unwrap().is_none());
+${tableStructName}::bulk_upsert_by_$indexId(client, $upsertArg, 4).await.unwrap();
+}
+            """.trimIndent()
+        }
+
+    /** Sample rows, shared by the tests and the benches */
+    val getSampleRowsFn
+        get() = Fn(
+            "get_sample_rows",
+            "Get a set of sample rows for testing",
+            body = FnBody(
+                listOf(
+                    "vec![",
+                    run {
+                        val generators = tableGateway.dataQueryColumns.queryColumns
+                            .associateWith { it.type.getSampleIterator() }
+                        (0 until sampleCount)
+                            .joinToString(",\n") {
+                                listOf(
+                                    "${tableGateway.rowDataStructName} {",
+                                    generators.entries.joinToString(",\n") { (queryColumn, generator) ->
+                                        "${queryColumn.id.snake}: ${
+                                            queryColumn.asRustLiteral(
+                                                generator.next()
+                                            )
+                                        }"
+                                    },
+                                    "}"
+                                ).joinToString("\n")
+                            }
+                    },
+                    "]"
+                ).joinToString("\n")
+            ),
+            returnType = "Vec<${tableGateway.rowDataStruct.structName}>".asType,
+            returnDoc = "Set of sample rows to test CRUD methods"
+        )
+
     val testFns = listOfNotNull(
         Fn(
             "mutate_row_data",
             """Mutate fields not appearing in pkey or unique keys for test purposes with pkey.
                 |Only fields not appearing in unique keys allows stability in the uniqueness of rows.
+                |Fields set by the server are left as is.
             """.trimMargin(),
             FnParam("row_data", "& mut ${tableGateway.rowDataStructName}".asType, "Data to mutate"),
             body = FnBody(
@@ -57,14 +688,13 @@ data class CrudTestSupport(
                     .dataQueryColumns
                     .queryColumns
                     .filter { queryColumn ->
-                        queryColumn.dbColumn !in tableGateway.table.primaryKeyColumns &&
+                        queryColumn.dbColumn?.isServerManaged != true &&
+                                queryColumn.dbColumn !in tableGateway.table.primaryKeyColumns &&
                                 tableGateway.table.uniqueIndices.all { uniqueIndice ->
                                     queryColumn.dbColumn !in uniqueIndice.value
                                 }
                     }
-                    .joinToString("\n") {
-                        "row_data.${it.id}.mutate_value();"
-                    })
+                    .joinToString("\n") { mutateField(it) })
         ),
 
         Fn(
@@ -72,7 +702,7 @@ data class CrudTestSupport(
             "Select all from the database and assert they compare to [values]",
             FnParam("client", "&T".asType, "The pool connection"),
             FnParam(
-                "values", "&Vec<${tableGateway.rowDataStructName}>".asType,
+                "values", "&[${tableGateway.rowDataStructName}]".asType,
                 "Values to compare to selected"
             ),
             FnParam("label", "&str".asType, "Label for assert"),
@@ -88,14 +718,14 @@ data class CrudTestSupport(
                     when (tableGateway.classifier) {
                         DbTableClassifier.AutoIdWithPkey, DbTableClassifier.AutoId -> {
                             """
-   let selected_entries = ${tableStructName}::select_all(client).await;
+   let selected_entries = ${tableStructName}::select_all(client).await.unwrap();
    let selected = entries_to_row_data(&selected_entries);
             """.trimIndent()
                         }
 
                         else -> {
                             """
-   let selected = ${tableStructName}::select_all(client).await;
+   let selected = ${tableStructName}::select_all(client).await.unwrap();
             """.trimIndent()
                         }
                     },
@@ -104,7 +734,7 @@ assert_eq!(selected.len(), values.len());
 get_sample_rows_sorted(&selected).iter().zip(get_sample_rows_sorted(values).iter()).for_each(|(a, b)| {
     let matched = a == b;
     tracing::debug!("{label}: {}", if matched { format!("Match({a:?})") } else { format!("Mismatch\n{a:?}\n---\n{b:?}") }); 
-    assert_eq!(true, matched);
+    assert!(matched);
 });
                 """.trimIndent()
                 ).joinToString("\n"),
@@ -124,61 +754,60 @@ get_sample_rows_sorted(&selected).iter().zip(get_sample_rows_sorted(values).iter
 
             else -> null
         },
+        if (serverManagedColumns.isNotEmpty()) {
+            Fn(
+                "clear_server_managed",
+                "Clear the fields set by the server, which the samples can not predict",
+                FnParam("row_data", "&mut ${tableGateway.rowDataStructName}".asType, "Data to clear"),
+                body = FnBody(serverManagedColumns.joinToString("\n") { "row_data.${it.nameId} = Default::default();" }),
+                inlineDecl = InlineDecl.Inline
+            )
+        } else {
+            null
+        },
         Fn(
             "get_sample_rows_sorted",
             "Get the sample rows as a set",
             FnParam("rows", "&[${tableGateway.rowDataStructName}]".asType, "The rows to stringify and sort"),
-            body = FnBody("rows.iter().cloned().map(|r| format!(\"{r:?}\")).collect()"),
+            body = FnBody(
+                if (serverManagedColumns.isNotEmpty()) {
+                    """
+rows.iter().cloned().map(|mut r| {
+    clear_server_managed(&mut r);
+    format!("{r:?}")
+}).collect()
+                    """.trimIndent()
+                } else {
+                    "rows.iter().map(|r| format!(\"{r:?}\")).collect()"
+                }
+            ),
             returnType = "BTreeSet<String>".asType,
             returnDoc = "The samples as set",
             inlineDecl = InlineDecl.Inline
         ),
-        Fn(
-            "get_sample_rows",
-            "Get a set of sample rows for testing",
-            body = FnBody(
-                listOf(
-                    "vec![",
-                    run {
-                        val generators = tableGateway.dataQueryColumns.queryColumns
-                            .associateWith { it.type.getSampleIterator() }
-                        (0 until sampleCount)
-                            .joinToString(",\n") {
-                                listOf(
-                                    "${tableGateway.rowDataStructName} {",
-                                    generators.entries.joinToString(",\n") { (queryColumn, generator) ->
-                                        "${queryColumn.id.snake}: ${
-                                            queryColumn.asRustLiteral(
-                                                generator.next().toString()
-                                            )
-                                        }"
-                                    },
-                                    "}"
-                                ).joinToString("\n")
-                            }
-                    },
-                    "]"
-                ).joinToString("\n")
-            ),
-            returnType = "Vec<${tableGateway.rowDataStruct.structName}>".asType,
-            returnDoc = "Set of sample rows to test CRUD methods"
-        ),
+        getSampleRowsFn,
         Fn(
             "test_crud",
             "Test by delete, bulk insert, select, bulk upsert, then delete for ${table.nameId}",
             isTokioTest = true,
-            attrs = listOf(attrTestLogTestFn, attrSerializeTest).asAttrList,
+            attrs = listOf(attrTracingTest, attrSerializeTest).asAttrList,
             body = listOf(
                 """
 let resource = get_pool().await.get().await.unwrap();
 let client = resource.client();
+// Provision the schema from the generated DDL. The table is shared, so it is only dropped and
+// recreated when `KGEN_DB_RECREATE_TABLES` opts in, otherwise created if missing.
+if std::env::var("KGEN_DB_RECREATE_TABLES").is_ok() {
+    ${tableStructName}::drop_table(client).await.unwrap();
+}
+${tableStructName}::create_table(client).await.unwrap();
 // First delete all, assuming it worked
 let deleted = ${tableStructName}::delete_all(client).await.unwrap();
 tracing::info!("Initialize phase deleted {deleted}");
 
 ${"Validate that delete work by selecting back an empty set".blockComment}
 {
-    assert_eq!(0, ${tableStructName}::select_all(client).await.len());
+    assert_eq!(0, ${tableStructName}::select_all(client).await.unwrap().len());
 }
 let ${
                     // Keyless does not support upsert since no key - so no mutation
@@ -204,7 +833,7 @@ ${"Test the basic insert functionality".blockComment}
     ${"Select back out the inserted data and compare to samples".blockComment}
     {
         select_and_compare_assert(client, ${
-                    tableGateway.autoIdDetails?.insertedDataTransform ?: "&get_sample_rows().iter().cloned().collect()"
+                    tableGateway.autoIdDetails?.insertedDataTransform ?: "&get_sample_rows()"
                 }, "Basic Ins Cmp").await;
     }
     let deleted = ${tableStructName}::delete_all(client).await.unwrap();
@@ -212,24 +841,52 @@ ${"Test the basic insert functionality".blockComment}
     assert_eq!(samples.len(), deleted as usize);
 }
 
+$nullRoundTripTestBlock
+
+$transactionalBatchTestBlock
+
 ${"Test the bulk insert functionality".blockComment}
 {
-    let inserted = ${tableStructName}::bulk_insert(client, $bulkSamplesArg, 4).await.unwrap();
-    tracing::debug!("Inserted with `bulk_insert` -> {inserted:?}");
+    ${bulkSamplesCall("bulk_insert", "inserted")}
     ${"Select back out the inserted data and compare to samples".blockComment}
-    select_and_compare_assert(client, ${tableGateway.autoIdDetails?.insertedDataTransform ?: "&get_sample_rows().iter().cloned().collect()"}, "Blk Ins Cmp").await;
+    select_and_compare_assert(client, ${tableGateway.autoIdDetails?.insertedDataTransform ?: "&get_sample_rows()"}, "Blk Ins Cmp").await;
 }
 
+$softDeleteTestBlock
+
+$preparedTestBlock
+
+$copyInTestBlock
+
+$streamTestBlock
+
+$pageTestBlock
+
+$aggregateTestBlock
+
+$filterTestBlock
+
+$versionedUpdateTestBlock
+
+$pkeyTestBlock
+
+$pkeyBulkTestBlock
+
+$syncRowsTestBlock
+
+$uniqueIndexTestBlocks
+
+$upsertEntriesTestBlock
+
 ${
                     if (!isKeyless) {
                         """
 ${"Mutate the data, and bulk upsert.".blockComment}
 {
-    samples.iter_mut().for_each(|data| mutate_row_data(data));
+    samples.iter_mut().for_each(mutate_row_data);
     tracing::debug!("Mutated Samples: {samples:?}");
-    let upserted = ${tableStructName}::bulk_upsert(client, $bulkSamplesArg, 4).await.unwrap();
-    tracing::debug!("Inserted with `bulk_upsert` -> {upserted:?}");
-    select_and_compare_assert(client, &samples.iter().cloned().collect(), "Blk Upsert Cmp").await;
+    ${bulkSamplesCall("bulk_upsert", "upserted")}
+    select_and_compare_assert(client, &samples, "Blk Upsert Cmp").await;
 }    
       """.trimIndent()
                     } else {
@@ -242,13 +899,40 @@ ${"Deleted all entries".blockComment}
     let deleted = ${tableStructName}::delete_all(client).await.unwrap();
     tracing::info!("Deleted all {deleted} ${tableStructName} entries");
     assert_eq!(deleted as usize, samples.len());
-    let selected = ${tableStructName}::select_all(client).await;
+    let selected = ${tableStructName}::select_all(client).await.unwrap();
     assert_eq!(0, selected.len());
 }
             """.trimIndent()
             )
                 .joinToString("\n")
                 .asFnBody,
+            uses = listOfNotNull(
+                "kgen_db::${table.id}::*",
+                "kgen_db::filter::Filter",
+                "kgen_db::batch::BatchMode".takeIf { hasNaturalKey },
+                "kgen_db::version::VersionedUpdate".takeIf { table.versionColumn != null },
+                "kgen_db::batch::SyncSummary".takeIf { tableGateway.syncRows.isSyncable }
+            ).asUses,
+        ),
+        Fn(
+            "test_verify_schema",
+            "Test the schema provisioned from the generated DDL matches that assumed by ${table.nameId}",
+            isTokioTest = true,
+            attrs = listOf(attrTracingTest, attrSerializeTest).asAttrList,
+            body = FnBody(
+                """
+let resource = get_pool().await.get().await.unwrap();
+let client = resource.client();
+${tableStructName}::create_table(client).await.unwrap();
+let report = ${tableStructName}::verify_schema(client).await.unwrap();
+assert!(
+    report.is_consistent(),
+    "Schema drift in `{}`: {:?}",
+    report.table_name,
+    report.drifts
+);
+                """.trimIndent()
+            ),
             uses = listOf("kgen_db::${table.id}::*").asUses,
         )
     )
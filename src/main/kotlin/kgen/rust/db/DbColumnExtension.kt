@@ -4,7 +4,8 @@ import kgen.asId
 import kgen.db.DbColumn
 import kgen.db.DbType
 import kgen.doubleQuote
-import kgen.rust.Field
+import kgen.rust.*
+import kgen.rustQuote
 
 /** Convert [DbColumn] into an sql literal, double quoting if name is mixed
  * case.
@@ -16,8 +17,15 @@ val DbColumn.asSqlLiteral
         columnName
     }
 
+/** The name of the rust type of any json payload of the column, imported into the table module */
+val DbColumn.jsonPayloadType
+    get() = jsonPayload?.substringAfterLast("::")
+
 val DbColumn.asRustType
-    get() = type.asRustType
+    get() = when (val payloadType = jsonPayloadType) {
+        null -> type.asRustType
+        else -> if (type.isNullable) "Option<$payloadType>".asType else payloadType.asType
+    }
 
 val DbColumn.asRustField
     get() = Field(
@@ -26,32 +34,186 @@ val DbColumn.asRustField
         this.asRustType
     )
 
+/** The postgres type as the name used in sql casts paired with the
+ * matching `tokio_postgres::types::Type` constant, or null if not yet supported.
+ * User defined types, like enums, have no constant as their oid is only known at runtime.
+ */
+private val DbType.pgType: Pair<String, String?>?
+    get() = when (val type = this) {
+        DbType.Integer, DbType.NullableInteger, DbType.IntegerAutoInc -> "int" to "INT4"
+        DbType.Double, DbType.NullableDouble -> "double precision" to "FLOAT8"
+        DbType.Bool, DbType.NullableBool -> "boolean" to "BOOL"
+        DbType.Byte, DbType.NullableByte -> "\"char\"" to "CHAR"
+        DbType.Binary, DbType.NullableBinary, DbType.Blob, DbType.NullableBlob,
+        is DbType.BinarySized, is DbType.NullableBinarySized -> "bytea" to "BYTEA"
+        is DbType.Numeric, is DbType.NullableNumeric -> "numeric" to "NUMERIC"
+        DbType.Time, DbType.NullableTime -> "time" to "TIME"
+        DbType.BigInteger, DbType.NullableBigInteger, DbType.LongAutoInc, DbType.UlongAutoInc,
+        DbType.UnsignedBigInteger, DbType.NullableUnsignedBigInteger -> "bigint" to "INT8"
+        DbType.Json, DbType.NullableJson -> "json" to "JSON"
+        DbType.JsonBinary, DbType.NullableJsonBinary -> "jsonb" to "JSONB"
+        DbType.Date, DbType.NullableDate -> "date" to "DATE"
+        DbType.DateTime, DbType.NullableDateTime -> "timestamp" to "TIMESTAMP"
+        DbType.SmallInteger, DbType.NullableSmallInteger -> "smallint" to "INT2"
+        DbType.Timestamp, DbType.NullableTimestamp -> "timestamptz" to "TIMESTAMPTZ"
+        DbType.Interval, DbType.NullableInterval -> "interval" to "INTERVAL"
+        DbType.Uuid, DbType.NullableUuid -> "uuid" to "UUID"
+        DbType.Text, DbType.NullableText -> "text" to "TEXT"
+        is DbType.VarChar, is DbType.NullableVarChar -> "varchar" to "VARCHAR"
+        is DbType.PgEnum -> type.typeName to null
+        is DbType.NullablePgEnum -> type.typeName to null
+        is DbType.Array -> type.elementType.arrayPgType
+        is DbType.NullableArray -> type.elementType.arrayPgType
+        else -> null
+    }
+
+private val DbType.arrayPgType
+    get() = pgType?.let { (castName, typeConstant) -> "$castName[]" to typeConstant?.let { "${it}_ARRAY" } }
+
+private val DbColumn.pgType get() = type.pgType
+
+/** The postgres type of the column as declared in `CREATE TABLE` */
+val DbColumn.ddlType
+    get() = type.ddlType
+
+private val DbType.ddlType: String
+    get() = when (val type = this) {
+        DbType.IntegerAutoInc -> "serial"
+        DbType.LongAutoInc, DbType.UlongAutoInc -> "bigserial"
+        is DbType.VarChar -> varcharDdlType(type.length)
+        is DbType.NullableVarChar -> varcharDdlType(type.length)
+        is DbType.Numeric -> "numeric(${type.precision}, ${type.scale})"
+        is DbType.NullableNumeric -> "numeric(${type.precision}, ${type.scale})"
+        is DbType.Array -> "${type.elementType.ddlType}[]"
+        is DbType.NullableArray -> "${type.elementType.ddlType}[]"
+        else -> pgType?.first ?: throw Exception("Unsupported ddl type for $this")
+    }
+
+/** The postgres type of the column as named by `information_schema.columns.udt_name` */
+val DbColumn.udtName
+    get() = type.udtName
+
+private val DbType.udtName: String
+    get() = when (val type = this) {
+        is DbType.Array -> "_${type.elementType.udtName}"
+        is DbType.NullableArray -> "_${type.elementType.udtName}"
+        is DbType.PgEnum -> type.typeName
+        is DbType.NullablePgEnum -> type.typeName
+        else -> pgType?.second?.lowercase() ?: throw Exception("Unsupported udt name for $this")
+    }
+
+/** A `varchar` of length `0` is unbounded */
+private fun varcharDdlType(length: Int) = if (length > 0) "varchar($length)" else "varchar"
+
+/** Cast of a value to the postgres type of the column, failing generation for an unmapped type */
+val DbColumn.sqlCast
+    get() = "::${pgType?.first ?: throw Exception("Unsupported sql cast for $this")}"
+
+/** Cast of the elements of the array passed to `UNNEST` for the column.
+ * Nested arrays can not be unnested into rows, so array columns are passed as
+ * their text literals (see [pushValue]) and cast back in [TableGateway.unnestSelectValue].
+ */
 val DbColumn.unnestCast
+    get() = if (type.isArray) "::text" else sqlCast
+
+/** The `tokio_postgres::types::Type` of the column, as required by binary copy,
+ * or null if only known at runtime
+ */
+val DbColumn.copyType
+    get() = pgType?.second?.let { "Type::$it" }
+
+/** Rust statement binding `types` to the `tokio_postgres::types::Type` of each of the columns.
+ * If any type is only known at runtime all are resolved by preparing a statement casting `NULL`
+ * to each column type, which requires a `client` in scope.
+ */
+val List<DbColumn>.copyTypesLet
+    get() = if (all { it.copyType != null }) {
+        "let types = [${joinToString(", ") { it.copyType!! }}];"
+    } else {
+        """
+let types = client
+    .prepare(${rustQuote("SELECT ${joinToString(", ") { "NULL${it.sqlCast}" }}")})
+    .await?
+    .columns()
+    .iter()
+    .map(|column| column.type_().clone())
+    .collect::<Vec<_>>();
+        """.trimIndent()
+    }
+
+/** Rust statement pushing the column field of [item] onto the column vector passed to `UNNEST` */
+fun DbColumn.pushValue(item: String) = when {
+    jsonPayload != null -> "${this.nameId}.push(${jsonParam(item)});"
+    type is DbType.Array -> "${this.nameId}.push(pg_array_literal(&$item.${this.nameId}));"
+    type is DbType.NullableArray -> "${this.nameId}.push($item.${this.nameId}.as_deref().map(pg_array_literal));"
+    else -> "${this.nameId}.push(${paramValue(item)});"
+}
+
+/** Private function of the table module converting an array field to its postgres text literal,
+ * as required to pass arrays through `UNNEST`. Every element is quoted, so any element type with
+ * a `Display` matching the postgres input format (numbers, text, uuid, dates) is supported.
+ */
+val pgArrayLiteralFn = Fn(
+    "pg_array_literal",
+    "Postgres text literal of the array, e.g. `{\"a\",\"b\"}`",
+    FnParam("values", "&[T]".asType, "Elements of the array"),
+    genericParamSet = GenericParamSet(TypeParam("t", bounds = Bounds("std::fmt::Display"))),
+    returnType = RustString,
+    returnDoc = "The array literal",
+    visibility = Visibility.None,
+    inlineDecl = InlineDecl.Inline,
+    body = FnBody(
+        """
+let elements = values
+    .iter()
+    .map(|value| format!("\"{}\"", value.to_string().replace('\\', "\\\\").replace('"', "\\\"")))
+    .collect::<Vec<_>>();
+format!("{{{}}}", elements.join(","))
+        """.trimIndent()
+    ),
+    hasUnitTest = false
+)
+
+/** True if the rust type of the column is `Copy`, so values may be taken without borrow or clone */
+val DbColumn.isRustCopy
     get() = when (type) {
-        DbType.Integer, DbType.NullableInteger, DbType.IntegerAutoInc -> "::int"
-        DbType.Double, DbType.NullableDouble -> "::double precision"
-        DbType.Bool, DbType.NullableBool -> "::boolean"
-        DbType.Byte, DbType.NullableByte -> "::bytea"
-        DbType.BigInteger, DbType.NullableBigInteger -> "::bigint"
-        DbType.Json, DbType.NullableJson -> "::json"
-        DbType.JsonBinary, DbType.NullableJsonBinary -> "::jsonb"
-        DbType.Date, DbType.NullableDate -> "::date"
-        DbType.DateTime, DbType.NullableDateTime -> "::timestamp"
-        DbType.SmallInteger, DbType.NullableSmallInteger -> "::smallint"
-        DbType.Timestamp, DbType.Timestamp -> "::timestamptz"
-        DbType.Interval, DbType.NullableInterval -> "::interval"
-        DbType.Uuid, DbType.NullableUuid -> "::uuid"
-        DbType.Text, DbType.NullableText -> "::text"
-        is DbType.VarChar, is DbType.NullableVarChar -> "::varchar"
-        else -> "::TODO"
+        is DbType.VarChar, is DbType.NullableVarChar,
+        DbType.Text, DbType.NullableText,
+        DbType.Json, DbType.NullableJson,
+        DbType.JsonBinary, DbType.NullableJsonBinary,
+        DbType.Binary, DbType.NullableBinary, DbType.Blob, DbType.NullableBlob,
+        is DbType.BinarySized, is DbType.NullableBinarySized,
+        is DbType.Array, is DbType.NullableArray -> false
+
+        else -> true
     }
 
-fun DbColumn.pushValue(item: String) = when (this.type) {
-    is DbType.VarChar, is DbType.JsonBinary, is DbType.Json,
-    is DbType.NullableJsonBinary, is DbType.NullableJson,
-    is DbType.NullableText, is DbType.NullableVarChar -> "${this.nameId}.push(&$item.${this.nameId});"
+/** Rust expression to pass the column field of [item] as an sql parameter element */
+fun DbColumn.paramValue(item: String) = if (isRustCopy) {
+    "$item.${this.nameId}"
+} else {
+    "&$item.${this.nameId}"
+}
+
+/** Rust expression wrapping the json payload field of [item] in `Json` to bind it as an sql parameter */
+fun DbColumn.jsonParam(item: String) = if (type.isNullable) {
+    "$item.${this.nameId}.as_ref().map(Json)"
+} else {
+    "Json(&$item.${this.nameId})"
+}
 
-    DbType.Text -> "${this.nameId}.push(&$item.${this.nameId});"
-    else -> "${this.nameId}.push($item.${this.nameId});"
+/** Rust expression referencing the column field of [item] as an element of an sql parameter slice,
+ * binding any json payload through `Json`
+ */
+fun DbColumn.sqlParam(item: String) = if (jsonPayload != null) {
+    "&${jsonParam(item)}"
+} else {
+    "&$item.${this.nameId}"
 }
 
+/** Rust expression to get an owned copy of the column field of [item] */
+fun DbColumn.ownedValue(item: String) = if (isRustCopy) {
+    "$item.${this.nameId}"
+} else {
+    "$item.${this.nameId}.clone()"
+}
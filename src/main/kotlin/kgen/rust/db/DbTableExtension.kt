@@ -1,5 +1,6 @@
 package kgen.rust.db
 
+import kgen.db.DbColumn
 import kgen.db.DbTable
 
 /** Literal list of column names suitable for `insert` and `select` statements */
@@ -21,8 +22,34 @@ val DbTable.nonAutoIncGroupedColumnNames
             chunk.map { it.asSqlLiteral }.joinToString(", ")
         }
 
+/** Literal list of the columns set by an insert - those supplied by callers then those set to `now()` */
+val DbTable.insertGroupedColumnNames
+    get() = (this.insertColumns + this.setOnInsertColumns).chunked(6)
+        .joinToString(",\n\t") { chunk ->
+            chunk.map { it.asSqlLiteral }.joinToString(", ")
+        }
+
+/** Literal list of the columns supplied by callers, leaving the server managed columns to their defaults */
+val DbTable.callerGroupedColumnNames
+    get() = this.insertColumns.chunked(6)
+        .joinToString(",\n\t") { chunk ->
+            chunk.map { it.asSqlLiteral }.joinToString(", ")
+        }
+
+/** The `now()` values of the server managed columns set on insert, each preceded by a separator */
+val DbTable.setOnInsertValues
+    get() = this.setOnInsertColumns.joinToString("") { ", now()" }
+
+/** Assignment of any `updated_at` column to `now()` for updates */
+val DbTable.updatedAtAssignment
+    get() = updatedAtColumn?.let { "${it.asSqlLiteral} = now()" }
+
+/** Predicate matching rows not soft deleted, if the table has soft delete */
+val DbTable.notDeletedPredicate
+    get() = deletedAtColumn?.let { "${it.asSqlLiteral} IS NULL" }
+
 val DbTable.unnestedColumnExpressions
-    get() = this.nonAutoIncColumns
+    get() = this.insertColumns
         .withIndex()
         .chunked(6)
         .map { chunks ->
@@ -32,27 +59,35 @@ val DbTable.unnestedColumnExpressions
         }
         .joinToString(",\n\t")
 
-val DbTable.onConflictKey
+/** Columns identifying a conflicting row for an upsert - first unique index if _auto inc_ else the primary key */
+val DbTable.onConflictColumns
     get() = if (this.hasAutoInc) {
         this.uniqueIndices.entries.first().value
     } else {
         this.primaryKeyColumns
-    }.chunked(6)
+    }
+
+val DbTable.onConflictKey
+    get() = onConflictColumns.asConflictKey
+
+/** Literal list of the column names as the target of an `ON CONFLICT` clause */
+val List<DbColumn>.asConflictKey
+    get() = this.chunked(6)
         .joinToString(", ") { chunk ->
             chunk.joinToString(", ") { it.asSqlLiteral }
         }
 
 val DbTable.unnestColumnVectorDecls
-    get() = this.nonAutoIncColumns.joinToString("\n") {
+    get() = this.insertColumns.joinToString("\n") {
         "let mut ${it.nameId} = Vec::with_capacity(chunk_size);"
     }
 
 val DbTable.bulkUpdateUnnestAssignments
     get() = listOf(
-        this.nonAutoIncColumns.joinToString("\n") { it.pushValue("row") },
+        this.insertColumns.joinToString("\n") { it.pushValue("row") },
     ).joinToString("\n")
 
 val DbTable.bulkUnnestClearStatements
     get() = listOf(
-        this.nonAutoIncColumns.joinToString("\n") { "${it.nameId}.clear();" },
+        this.insertColumns.joinToString("\n") { "${it.nameId}.clear();" },
     ).joinToString("\n")
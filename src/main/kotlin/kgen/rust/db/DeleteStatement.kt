@@ -7,6 +7,9 @@ import kgen.rust.InlineDecl
 import kgen.rust.asType
 
 /** Responsible for generating `delete` method for the table.
+ * If the table has soft delete, `delete_all` sets the `deleted_at` column of the live rows
+ * and `hard_delete_all` removes all rows.
+ *
  * @property tableGateway The table gateway
  */
 data class DeleteStatement(
@@ -19,28 +22,60 @@ data class DeleteStatement(
     /** Id for the table */
     val tableId = table.id
 
-    val rustDeleteStatement = RustSqlStatement(
+    val rustHardDeleteStatement = RustSqlStatement(
         "delete_statement",
-         doubleQuote("DELETE FROM $tableId"),
+        doubleQuote("DELETE FROM $tableId"),
         tableGateway.backdoorTableId
     )
 
+    val rustDeleteStatement = table.deletedAtColumn?.let {
+        RustSqlStatement(
+            "delete_statement",
+            doubleQuote("UPDATE $tableId SET ${it.asSqlLiteral} = now() WHERE ${table.notDeletedPredicate}"),
+            tableGateway.backdoorTableId
+        )
+    } ?: rustHardDeleteStatement
+
+    private fun deleteFn(nameId: String, doc: String, statement: RustSqlStatement, returnDoc: String) =
+        Fn(
+            nameId,
+            doc,
+            clientFnParam,
+            genericParamSet = genericClientParamSet,
+            isAsync = true,
+            hasTokioTest = true,
+            inlineDecl = InlineDecl.Inline,
+            returnType = "Result<u64, tokio_postgres::Error>".asType,
+            returnDoc = returnDoc,
+            body = FnBody(
+                """${statement.letStatement}
+                    client.execute(${statement.asStr}, &[]).await""".trimIndent()
+            ),
+            hasUnitTest = false
+        )
+
     val deleteAllFn
-        get() =
-            Fn(
+        get() = if (table.isSoftDelete) {
+            deleteFn(
                 "delete_all",
-                "Delete all rows of `$tableId`",
-                clientFnParam,
-                genericParamSet = genericClientParamSet,
-                isAsync = true,
-                hasTokioTest = true,
-                inlineDecl = InlineDecl.Inline,
-                returnType = "Result<u64, tokio_postgres::Error>".asType,
-                returnDoc = "Number of rows deleted",
-                body = FnBody(
-                    """${rustDeleteStatement.letStatement}
-                    client.execute(${rustDeleteStatement.asStr}, &[]).await""".trimIndent()
-                ),
-                hasUnitTest = false
+                "Soft delete all live rows of `$tableId`, setting `${table.deletedAtColumn!!.nameId}`",
+                rustDeleteStatement,
+                "Number of rows soft deleted"
+            )
+        } else {
+            deleteFn("delete_all", "Delete all rows of `$tableId`", rustDeleteStatement, "Number of rows deleted")
+        }
+
+    /** Removes all rows, including those soft deleted - only for tables with soft delete */
+    val hardDeleteAllFn
+        get() = if (table.isSoftDelete) {
+            deleteFn(
+                "hard_delete_all",
+                "Delete all rows of `$tableId`, including those soft deleted",
+                rustHardDeleteStatement,
+                "Number of rows deleted"
             )
-}
\ No newline at end of file
+        } else {
+            null
+        }
+}
@@ -0,0 +1,254 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Trait implemented by each table's generated column enum, providing the sql name of the column */
+val sqlColumnTrait = Trait(
+    "sql_column",
+    "A column of a table that may be referenced in a [Filter]",
+    Fn(
+        "column_name",
+        "The name of the column as it appears in sql",
+        refSelf,
+        returnType = "&'static str".asType,
+        returnDoc = "The column name"
+    ),
+)
+
+/** Bound on the column type of [Filter] */
+private val filterGenericParamSet = GenericParamSet(TypeParam("c", bounds = Bounds("SqlColumn")))
+
+/** Bound on values bound as parameters of a [Filter] */
+private val filterValueParamSet =
+    GenericParamSet(TypeParam("v", bounds = Bounds("ToSql", "Sync", lifetimes = listOf(static))))
+
+/** Builder function for filter of `column <op> value` */
+private fun compareFn(nameId: String, op: String, doc: String) = Fn(
+    nameId,
+    "Filter rows where column $doc `value`",
+    FnParam("column", "C".asType, "The column to compare"),
+    FnParam("value", "V".asType, "The value to compare to"),
+    genericParamSet = filterValueParamSet,
+    returnType = Self,
+    returnDoc = "The filter",
+    hasUnitTest = false,
+    inlineDecl = InlineDecl.Inline,
+    body = FnBody("""Filter::Compare(column, "$op", Box::new(value))""")
+)
+
+/** Generic typed filter rendering an sql where clause with correctly numbered parameters */
+val filterEnum = Enum(
+    "filter",
+    """A typed, composable filter on the columns `C` of a table.
+        |Rendered into a where clause and parameters suitable for `select_all_where`.
+    """.trimMargin(),
+    listOf(
+        Variant.TupleStruct(
+            "compare",
+            "Compare column to value with the operator",
+            "C".asType, "&'static str".asType, "Box<dyn ToSql + Sync>".asType
+        ),
+        Variant.TupleStruct(
+            "in",
+            "Column matches one of the values",
+            "C".asType, "Vec<Box<dyn ToSql + Sync>>".asType
+        ),
+        Variant.TupleStruct("is_null", "Column is null", "C".asType),
+        Variant.TupleStruct("is_not_null", "Column is not null", "C".asType),
+        Variant.TupleStruct("like", "Column matches the `LIKE` pattern", "C".asType, RustString),
+        Variant.TupleStruct("and", "All filters match", "Vec<Filter<C>>".asType),
+        Variant.TupleStruct("or", "Any filter matches", "Vec<Filter<C>>".asType),
+    ),
+    genericParamSet = GenericParamSet("c"),
+    typeImpl = TypeImpl(
+        "Filter<C>".asType,
+        listOf(
+            compareFn("eq", "=", "equals"),
+            compareFn("ne", "<>", "does not equal"),
+            compareFn("lt", "<", "is less than"),
+            compareFn("le", "<=", "is less than or equal to"),
+            compareFn("gt", ">", "is greater than"),
+            compareFn("ge", ">=", "is greater than or equal to"),
+            Fn(
+                "is_in",
+                "Filter rows where column matches one of `values`",
+                FnParam("column", "C".asType, "The column to compare"),
+                FnParam("values", "impl IntoIterator<Item = V>".asType, "The values to match"),
+                genericParamSet = filterValueParamSet,
+                returnType = Self,
+                returnDoc = "The filter",
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+Filter::In(
+    column,
+    values
+        .into_iter()
+        .map(|value| Box::new(value) as Box<dyn ToSql + Sync>)
+        .collect(),
+)
+                    """.trimIndent()
+                )
+            ),
+            Fn(
+                "is_null",
+                "Filter rows where column is null",
+                FnParam("column", "C".asType, "The column to check"),
+                returnType = Self,
+                returnDoc = "The filter",
+                hasUnitTest = false,
+                inlineDecl = InlineDecl.Inline,
+                body = FnBody("Filter::IsNull(column)")
+            ),
+            Fn(
+                "is_not_null",
+                "Filter rows where column is not null",
+                FnParam("column", "C".asType, "The column to check"),
+                returnType = Self,
+                returnDoc = "The filter",
+                hasUnitTest = false,
+                inlineDecl = InlineDecl.Inline,
+                body = FnBody("Filter::IsNotNull(column)")
+            ),
+            Fn(
+                "like",
+                "Filter rows where column matches the `LIKE` pattern",
+                FnParam("column", "C".asType, "The column to match"),
+                FnParam("pattern", "impl Into<String>".asType, "The `LIKE` pattern"),
+                returnType = Self,
+                returnDoc = "The filter",
+                hasUnitTest = false,
+                inlineDecl = InlineDecl.Inline,
+                body = FnBody("Filter::Like(column, pattern.into())")
+            ),
+            Fn(
+                "and",
+                "Combine with `other` requiring both to match",
+                self,
+                FnParam("other", "Filter<C>".asType, "The other filter"),
+                returnType = Self,
+                returnDoc = "The combined filter",
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+match self {
+    Filter::And(mut filters) => {
+        filters.push(other);
+        Filter::And(filters)
+    }
+    filter => Filter::And(vec![filter, other]),
+}
+                    """.trimIndent()
+                )
+            ),
+            Fn(
+                "or",
+                "Combine with `other` requiring either to match",
+                self,
+                FnParam("other", "Filter<C>".asType, "The other filter"),
+                returnType = Self,
+                returnDoc = "The combined filter",
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+match self {
+    Filter::Or(mut filters) => {
+        filters.push(other);
+        Filter::Or(filters)
+    }
+    filter => Filter::Or(vec![filter, other]),
+}
+                    """.trimIndent()
+                )
+            ),
+            Fn(
+                "render",
+                "Render the filter as a where clause (sans `where` keyword) and its parameters",
+                refSelf,
+                returnType = "(String, Vec<&(dyn ToSql + Sync)>)".asType,
+                returnDoc = "The where clause and parameters numbered to match",
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+let mut sql = String::new();
+let mut params = Vec::new();
+self.render_into(&mut sql, &mut params);
+(sql, params)
+                    """.trimIndent()
+                )
+            ),
+            Fn(
+                "render_into",
+                "Render the filter into `sql`, numbering parameters from those already in `params`",
+                refSelf("a"),
+                FnParam("sql", "&mut String".asType, "The sql being rendered"),
+                FnParam("params", "&mut Vec<&'a (dyn ToSql + Sync)>".asType, "The parameters being collected"),
+                genericParamSet = tickA,
+                visibility = Visibility.None,
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+match self {
+    Filter::Compare(column, op, value) => {
+        params.push(value.as_ref());
+        sql.push_str(&format!("{} {op} ${'$'}{}", column.column_name(), params.len()));
+    }
+    Filter::In(column, values) => {
+        if values.is_empty() {
+            sql.push_str("FALSE");
+        } else {
+            let placeholders = values
+                .iter()
+                .map(|value| {
+                    params.push(value.as_ref());
+                    format!("${'$'}{}", params.len())
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            sql.push_str(&format!("{} IN ({placeholders})", column.column_name()));
+        }
+    }
+    Filter::IsNull(column) => sql.push_str(&format!("{} IS NULL", column.column_name())),
+    Filter::IsNotNull(column) => sql.push_str(&format!("{} IS NOT NULL", column.column_name())),
+    Filter::Like(column, pattern) => {
+        params.push(pattern);
+        sql.push_str(&format!("{} LIKE ${'$'}{}", column.column_name(), params.len()));
+    }
+    Filter::And(filters) | Filter::Or(filters) => {
+        let (separator, empty) = if matches!(self, Filter::And(_)) {
+            (" AND ", "TRUE")
+        } else {
+            (" OR ", "FALSE")
+        };
+        if filters.is_empty() {
+            sql.push_str(empty);
+        } else {
+            sql.push('(');
+            for (i, filter) in filters.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(separator);
+                }
+                filter.render_into(sql, params);
+            }
+            sql.push(')');
+        }
+    }
+}
+                    """.trimIndent()
+                )
+            ),
+        ),
+        genericParamSet = filterGenericParamSet
+    )
+)
+
+/** Module shared by all table gateways supporting typed filters.
+ * Include in the crate alongside the table gateway modules.
+ */
+val filterModule = Module(
+    "filter",
+    "Typed, composable filters rendered into where clauses for the table gateways",
+    traits = listOf(sqlColumnTrait),
+    enums = listOf(filterEnum),
+    uses = listOf("tokio_postgres::types::ToSql").asUses
+)
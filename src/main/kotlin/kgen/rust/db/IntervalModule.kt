@@ -0,0 +1,84 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Newtype modeling the `interval` columns, wrapping `pg_interval::Interval` */
+val intervalStruct = Struct(
+    "interval",
+    """A postgres `interval`, wrapping `pg_interval::Interval`.
+        |`pg_interval::Interval` implements neither `Default` nor `Hash`, both required of the row data,
+        |so interval columns are modeled by this newtype. The default is the zero interval and the hash
+        |is that of the months, days and microseconds, consistent with the derived equality.
+    """.trimMargin(),
+    listOf(Field("value", "The wrapped interval", "pg_interval::Interval".asType)),
+    asTupleStruct = true,
+    attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq"),
+)
+
+/** The impls of [intervalStruct] missing from `pg_interval::Interval`, with those mapping it to and from
+ * `interval` through the wrapped value
+ */
+private val intervalImpls = """
+impl Default for Interval {
+    fn default() -> Self {
+        Interval(pg_interval::Interval::new(0, 0, 0))
+    }
+}
+
+impl std::hash::Hash for Interval {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.0.months, self.0.days, self.0.microseconds).hash(state);
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        <pg_interval::Interval as ToSql>::to_sql(&self.0, ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <pg_interval::Interval as ToSql>::accepts(ty)
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Interval(pg_interval::Interval::from_sql(ty, raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <pg_interval::Interval as FromSql>::accepts(ty)
+    }
+}
+
+impl From<pg_interval::Interval> for Interval {
+    fn from(value: pg_interval::Interval) -> Self {
+        Interval(value)
+    }
+}
+
+impl From<Interval> for pg_interval::Interval {
+    fn from(value: Interval) -> Self {
+        value.0
+    }
+}
+""".trim()
+
+/** Module shared by all table gateways with interval columns.
+ * Include in the crate alongside the table gateway modules.
+ */
+val intervalModule = Module(
+    "interval",
+    "The newtype modeling interval columns",
+    structs = listOf(intervalStruct),
+    moduleBody = FnBody(intervalImpls),
+    uses = listOf(
+        "bytes::BytesMut",
+        "tokio_postgres::types::FromSql",
+        "tokio_postgres::types::IsNull",
+        "tokio_postgres::types::ToSql",
+        "tokio_postgres::types::Type",
+    ).asUses
+)
@@ -0,0 +1,275 @@
+package kgen.rust.db
+
+import kgen.asId
+import kgen.db.DbColumn
+import kgen.db.DbType
+import kgen.rust.*
+import kgen.rustQuote
+
+/** Responsible for generating the functions that access rows of the table by a key,
+ * `select_by_<key>`, `select_by_<key>s`, `update_by_<key>` and `delete_by_<key>`, with
+ * `update_by_<key>_if_version` if the table has a version column.
+ * The key is either the primary key or the columns of a unique index.
+ *
+ * @property tableGateway The table gateway
+ * @property keyNameId Snake case name of the key used in function names (e.g. `pkey`)
+ * @property keyColumns The columns identifying a row
+ * @property keyStructName Name of the rust struct modeling the key
+ */
+data class KeyLookup(
+    val tableGateway: TableGateway,
+    val keyNameId: String,
+    val keyColumns: List<DbColumn>,
+    val keyStructName: String,
+) {
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Table name */
+    val tableName get() = table.tableName
+
+    /** The key id */
+    val keyId = keyNameId.asId
+
+    /** The type returned when selecting rows - the entry if table has _auto id_ else the row data */
+    val returnType = tableGateway.rowEntryStructName ?: tableGateway.rowDataStructName
+
+    /** Sql predicate matching the key columns to parameters starting at [firstParam] */
+    fun keyMatchPredicate(firstParam: Int = 1) = keyColumns
+        .withIndex()
+        .joinToString(" AND ") { (i, column) -> "${column.asSqlLiteral} = ${'$'}${i + firstParam}" }
+
+    /** Sql predicate matching key columns against the unnested key arrays */
+    val keyInUnnestPredicate
+        get() = "(${keyColumns.joinToString(", ") { it.asSqlLiteral }}) IN (SELECT * FROM UNNEST(${
+            keyColumns.withIndex().joinToString(", ") { (i, column) ->
+                "${'$'}${i + 1}${column.unnestCast}[]"
+            }
+        }))"
+
+    /** The key fields as sql parameters */
+    val keyParams = keyColumns.joinToString(", ") { "&key.${it.nameId}" }
+
+    /** Columns set by the update - the columns supplied on insert not in the key */
+    val updateColumns = table.insertColumns.filter { it !in keyColumns }
+
+    /** Predicate matching the key columns to parameters starting at [firstParam], skipping soft deleted rows */
+    fun liveKeyMatchPredicate(firstParam: Int = 1) =
+        listOfNotNull(keyMatchPredicate(firstParam), table.notDeletedPredicate).joinToString(" AND ")
+
+    val selectByKeyFn
+        get() = Fn(
+            "select_by_${keyId.snake}",
+            "Select the row of `$tableName` identified by [$keyStructName]",
+            clientFnParam,
+            FnParam("key", "&$keyStructName".asType, "The key identifying the row"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<Option<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "The row if found",
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                """
+let mut selected = Self::select_all_where(
+    client,
+    ${rustQuote(keyMatchPredicate())},
+    &[$keyParams]
+).await?;
+Ok(selected.pop())
+                """.trimIndent()
+            )
+        )
+
+    /** Select of the row by key, fixed so it may be prepared once per connection */
+    val rustSelectStatement = RustSqlStatement(
+        "select_statement",
+        rustQuote(
+            """SELECT
+${table.formattedColumnNames}
+FROM $tableName
+WHERE ${liveKeyMatchPredicate()}"""
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    val selectByKeysFn
+        get() = Fn(
+            "select_by_${keyId.snake}s",
+            "Select the rows of `$tableName` identified by the batch of [$keyStructName]",
+            clientFnParam,
+            FnParam("keys", "&[$keyStructName]".asType, "The keys identifying the rows"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<Vec<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "The rows found",
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                listOf(
+                    keyColumns.joinToString("\n") {
+                        "let ${it.nameId} = keys.iter().map(|key| ${it.paramValue("key")}).collect::<Vec<_>>();"
+                    },
+                    """
+Self::select_all_where(
+    client,
+    ${rustQuote(keyInUnnestPredicate)},
+    &[${keyColumns.joinToString(", ") { "&${it.nameId}" }}]
+).await
+                    """.trimIndent()
+                ).joinToString("\n")
+            )
+        )
+
+    val rustUpdateStatement = RustSqlStatement(
+        "update_statement",
+        rustQuote(
+            """UPDATE $tableName SET
+    ${
+                (updateColumns.withIndex().map { (i, column) -> "${column.asSqlLiteral} = ${'$'}${i + 1}" } +
+                        listOfNotNull(table.updatedAtAssignment)).joinToString(",\n\t")
+            }
+WHERE ${liveKeyMatchPredicate(updateColumns.size + 1)}"""
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    /** Update function, only available if there are columns outside the key to update */
+    val updateByKeyFn
+        get() = if (updateColumns.isNotEmpty()) {
+            Fn(
+                "update_by_${keyId.snake}",
+                "Update the row of `$tableName` identified by [$keyStructName] with the non-key fields of `row`",
+                clientFnParam,
+                FnParam("key", "&$keyStructName".asType, "The key identifying the row"),
+                FnParam("row", "&${tableGateway.rowDataStructName}".asType, "The new values for the row"),
+                genericParamSet = genericClientParamSet,
+                returnType = "Result<u64, tokio_postgres::Error>".asType,
+                returnDoc = "Number of rows updated",
+                isAsync = true,
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+${rustUpdateStatement.letStatement}
+client.execute(
+    ${rustUpdateStatement.asStr},
+    &[${(updateColumns.map { it.sqlParam("row") } + keyColumns.map { "&key.${it.nameId}" }).joinToString(", ")}]
+).await
+                    """.trimIndent()
+                )
+            )
+        } else {
+            null
+        }
+
+    /** Column for optimistic concurrency, if the table has one */
+    val versionColumn get() = table.versionColumn
+
+    /** Columns set from the row by the versioned update - those updated, less the version */
+    val versionedUpdateColumns = updateColumns.filter { it != table.versionColumn }
+
+    /** The sql expression for the next version - incremented if an integer, else the time of the update */
+    val nextVersionExpression
+        get() = versionColumn?.let { column ->
+            when (column.type) {
+                DbType.DateTime -> "clock_timestamp()::timestamp"
+                DbType.Timestamp -> "clock_timestamp()"
+                else -> "${column.asSqlLiteral} + 1"
+            }
+        }
+
+    val rustVersionedUpdateStatement
+        get() = versionColumn?.let { column ->
+            RustSqlStatement(
+                "update_statement",
+                rustQuote(
+                    """UPDATE $tableName SET
+    ${
+                        (versionedUpdateColumns.withIndex().map { (i, updateColumn) ->
+                            "${updateColumn.asSqlLiteral} = ${'$'}${i + 1}"
+                        } + "${column.asSqlLiteral} = $nextVersionExpression" +
+                                listOfNotNull(table.updatedAtAssignment)).joinToString(",\n\t")
+                    }
+WHERE ${liveKeyMatchPredicate(versionedUpdateColumns.size + 1)} AND ${column.asSqlLiteral} = ${'$'}${versionedUpdateColumns.size + keyColumns.size + 1}
+RETURNING ${column.asSqlLiteral}"""
+                ),
+                tableGateway.backdoorTableId
+            )
+        }
+
+    /** Update guarded by the version column, only available if the table has one */
+    val updateByKeyIfVersionFn
+        get() = rustVersionedUpdateStatement?.let { statement ->
+            val column = versionColumn!!
+            Fn(
+                "update_by_${keyId.snake}_if_version",
+                """Update the row of `$tableName` identified by [$keyStructName] with the non-key fields of `row`,
+                    |only if the row is still at the version `row.${column.nameId}` it was read at.
+                    |The version is advanced by the update.
+                """.trimMargin(),
+                clientFnParam,
+                FnParam("key", "&$keyStructName".asType, "The key identifying the row"),
+                FnParam(
+                    "row",
+                    "&${tableGateway.rowDataStructName}".asType,
+                    "The new values for the row, with the version read"
+                ),
+                genericParamSet = genericClientParamSet,
+                returnType = "Result<VersionedUpdate<${column.asRustType.asRustName}>, tokio_postgres::Error>".asType,
+                returnDoc = "The new version, or the conflict if the row is not at the version read",
+                isAsync = true,
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+${statement.letStatement}
+match client.query_opt(
+    ${statement.asStr},
+    &[${
+                        (versionedUpdateColumns.map { it.sqlParam("row") } +
+                                keyColumns.map { "&key.${it.nameId}" } +
+                                "&row.${column.nameId}").joinToString(", ")
+                    }]
+).await? {
+    Some(updated) => Ok(VersionedUpdate::Updated { version: updated.try_get(0)? }),
+    None => {
+        tracing::debug!("Version conflict update_by_${keyId.snake}_if_version `${table.nameId}`");
+        Ok(VersionedUpdate::Conflict)
+    }
+}
+                    """.trimIndent()
+                )
+            )
+        }
+
+    val rustDeleteStatement = RustSqlStatement(
+        "delete_statement",
+        rustQuote(
+            table.deletedAtColumn?.let {
+                "UPDATE $tableName SET ${it.asSqlLiteral} = now() WHERE ${liveKeyMatchPredicate()}"
+            } ?: "DELETE FROM $tableName WHERE ${keyMatchPredicate()}"
+        ),
+        tableGateway.backdoorTableId
+    )
+
+    val deleteByKeyFn
+        get() = Fn(
+            "delete_by_${keyId.snake}",
+            if (table.isSoftDelete) {
+                "Soft delete the row of `$tableName` identified by [$keyStructName], setting `${table.deletedAtColumn!!.nameId}`"
+            } else {
+                "Delete the row of `$tableName` identified by [$keyStructName]"
+            },
+            clientFnParam,
+            FnParam("key", "&$keyStructName".asType, "The key identifying the row"),
+            genericParamSet = genericClientParamSet,
+            returnType = "Result<u64, tokio_postgres::Error>".asType,
+            returnDoc = "Number of rows deleted",
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                """
+${rustDeleteStatement.letStatement}
+client.execute(${rustDeleteStatement.asStr}, &[$keyParams]).await
+                """.trimIndent()
+            )
+        )
+}
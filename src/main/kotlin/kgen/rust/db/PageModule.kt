@@ -0,0 +1,28 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** A page of rows with the cursor to the next page */
+val pageStruct = Struct(
+    "page",
+    "A page of selected rows with the cursor to select the page following",
+    listOf(
+        Field("rows", "The rows of the page, in page order", "Vec<T>".asType),
+        Field(
+            "next",
+            "Cursor to select the next page, `None` if this page is the last",
+            "Option<C>".asType
+        ),
+    ),
+    genericParamSet = GenericParamSet("t", "c"),
+    attrs = derive("Debug", "Clone", "PartialEq"),
+)
+
+/** Module shared by all table gateways supporting pagination.
+ * Include in the crate alongside the table gateway modules.
+ */
+val pageModule = Module(
+    "page",
+    "Pages returned by the pagination functions of the table gateways",
+    structs = listOf(pageStruct)
+)
@@ -0,0 +1,79 @@
+package kgen.rust.db
+
+import kgen.asId
+import kgen.db.DbTable
+import kgen.db.DbType
+import kgen.rust.*
+
+/** Distinct postgres enum types of the columns of the table, nullable or not */
+val DbTable.pgEnums
+    get() = columns.mapNotNull {
+        when (val type = it.type) {
+            is DbType.PgEnum -> type
+            is DbType.NullablePgEnum -> DbType.PgEnum(type.typeName, type.values)
+            else -> null
+        }
+    }.distinct()
+
+/** Distinct postgres enum types of the columns of the tables, shared by name across tables */
+val List<DbTable>.pgEnums get() = flatMap { it.pgEnums }.distinct()
+
+/** Name of the rust enum modeling the postgres enum */
+val DbType.PgEnum.rustName get() = typeName.asId.capCamel
+
+/** Rust path of the variant for [value] */
+fun DbType.PgEnum.variant(value: String) = "$rustName::${value.asId.capCamel}"
+
+/** Path of the rust enum in the generated crate */
+val DbType.PgEnum.cratePath get() = "crate::pg_enum::$rustName"
+
+/** The rust enum modeling the postgres enum, mapped to and from sql by name.
+ * The first label is the default, as for the row data deriving `Default`.
+ */
+val DbType.PgEnum.asRustEnum
+    get() = Enum(
+        typeName,
+        "Models postgres enum type `$typeName`",
+        values.mapIndexed { i, value ->
+            Variant.UnitStruct(
+                value,
+                "Label `$value`",
+                isDefault = i == 0,
+                attrs = Attr.Dict("postgres", "name" to value).asAttrList
+            )
+        },
+        attrs = derive(
+            "Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash", "Default",
+            "postgres_types::ToSql", "postgres_types::FromSql"
+        ) + Attr.Dict("postgres", "name" to typeName)
+    )
+
+/** Module of the rust enums modeling the postgres enums of [tables], each defined once
+ * and shared by every table using it.
+ */
+fun pgEnumModule(tables: List<DbTable>) = Module(
+    "pg_enum",
+    "Rust enums modeling the postgres enum types",
+    enums = tables.pgEnums.map { it.asRustEnum }
+)
+
+/** Impls of `MutateValue` for the enum and its option, advancing to the next label */
+val DbType.PgEnum.mutateValueImpls
+    get() = listOf(
+        TraitImpl(
+            rustName.asType, TableGateway.mutateValueTrait,
+            bodies = mapOf(
+                "mutate_value" to listOf(
+                    "*self = match self {",
+                    values.withIndex().joinToString(",\n") { (i, value) ->
+                        "    ${variant(value)} => ${variant(values[(i + 1) % values.size])}"
+                    },
+                    "};"
+                ).joinToString("\n")
+            )
+        ),
+        TraitImpl(
+            "Option<$rustName>".asType, TableGateway.mutateValueTrait,
+            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.mutate_value() }")
+        )
+    )
@@ -0,0 +1,217 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Responsible for generating the `_prepared` variants of the gateway functions running the fixed
+ * statements (bulk insert, bulk upsert, delete all and select by primary key). Each prepares its
+ * statement with `prepare_cached` of the `deadpool_postgres::GenericClient`, so a statement is
+ * prepared once per pooled connection and reused from the cache of that connection thereafter,
+ * rather than sending the statement text on every call.
+ *
+ * Also generates the bench comparing the gateway functions with their `_prepared` variants.
+ *
+ *  @property tableGateway Data associated with the table
+ */
+data class PreparedStatements(
+    val tableGateway: TableGateway,
+) {
+
+    /** Table id */
+    val id get() = tableGateway.id
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Table name */
+    val tableName get() = table.tableName
+
+    /** Name of the table struct providing the gateway functions */
+    val tableStructName = "Table${id.capCamel}"
+
+    /** The bulk upsert, only prepared if there is a conflict key to upsert on */
+    private val bulkUpsert = tableGateway.bulkUpsert.takeIf { it.conflictColumns.isNotEmpty() }
+
+    /** Statements binding `prepared` to [statement] from the statement cache of the connection of `client` */
+    private fun prepareCached(statement: RustSqlStatement) = """
+${statement.letStatement}
+let prepared = client.prepare_cached(${statement.asStr}).await?;
+    """.trimIndent()
+
+    val bulkInsertPreparedFn
+        get() = tableGateway.bulkInsert.let { bulkInsert ->
+            Fn(
+                "bulk_insert_prepared",
+                "Insert large batch of [${id.capCamel}] rows with the statement cached by the connection of `client`.",
+                cachedClientFnParam,
+                bulkInsert.inputFnParam,
+                tableGateway.bulkInsertChunkSizeFnParam,
+                genericParamSet = cachedClientParamSet,
+                returnType = bulkInsert.returnType,
+                returnDoc = bulkInsert.returnDoc,
+                body = FnBody(bulkInsert.bulkInsertBody(prepareCached(bulkInsert.rustInsertStatement), "&prepared")),
+                isAsync = true,
+                hasUnitTest = false
+            )
+        }
+
+    val bulkUpsertPreparedFn
+        get() = bulkUpsert?.let {
+            Fn(
+                "bulk_upsert_prepared",
+                "Upsert large batch of [${id.capCamel}] rows with the statement cached by the connection of `client`.",
+                cachedClientFnParam,
+                it.inputFnParam,
+                tableGateway.bulkInsertChunkSizeFnParam,
+                genericParamSet = cachedClientParamSet,
+                returnType = it.returnType,
+                returnDoc = "",
+                body = FnBody(it.bulkUpsertBody(prepareCached(it.rustUpsertStatement), "&prepared")),
+                isAsync = true,
+                hasUnitTest = false
+            )
+        }
+
+    val deleteAllPreparedFn
+        get() = Fn(
+            "delete_all_prepared",
+            if (table.isSoftDelete) {
+                "Soft delete all live rows of `$tableName` with the statement cached by the connection of `client`"
+            } else {
+                "Delete all rows of `$tableName` with the statement cached by the connection of `client`"
+            },
+            cachedClientFnParam,
+            genericParamSet = cachedClientParamSet,
+            isAsync = true,
+            inlineDecl = InlineDecl.Inline,
+            returnType = "Result<u64, tokio_postgres::Error>".asType,
+            returnDoc = "Number of rows deleted",
+            body = FnBody(
+                """
+${prepareCached(tableGateway.deleteStatement.rustDeleteStatement)}
+client.execute(&prepared, &[]).await
+                """.trimIndent()
+            ),
+            hasUnitTest = false
+        )
+
+    val selectByPkeyPreparedFn
+        get() = tableGateway.pkeyLookup?.let { pkeyLookup ->
+            Fn(
+                "select_by_pkey_prepared",
+                "Select the row of `$tableName` identified by [${pkeyLookup.keyStructName}] with the statement cached by the connection of `client`",
+                cachedClientFnParam,
+                FnParam("key", "&${pkeyLookup.keyStructName}".asType, "The key identifying the row"),
+                genericParamSet = cachedClientParamSet,
+                returnType = "Result<Option<${pkeyLookup.returnType}>, tokio_postgres::Error>".asType,
+                returnDoc = "The row if found",
+                isAsync = true,
+                hasUnitTest = false,
+                body = FnBody(
+                    """
+${prepareCached(pkeyLookup.rustSelectStatement)}
+client
+    .query_opt(&prepared, &[${pkeyLookup.keyParams}])
+    .await?
+    .map(|row| Self::from_row(&row))
+    .transpose()
+                    """.trimIndent()
+                )
+            )
+        }
+
+    /** The `_prepared` variants of the gateway functions */
+    val preparedFns
+        get() = listOfNotNull(bulkInsertPreparedFn, bulkUpsertPreparedFn, deleteAllPreparedFn, selectByPkeyPreparedFn)
+
+    /** Argument to the bulk insert in the bench - for _auto id_ the rows are consumed */
+    private val benchSamplesArg = if (tableGateway.hasAutoId) "samples.clone()" else "&samples"
+
+    /** Bench timing [ITERATIONS] runs of [plain], the gateway function, then of [prepared], its `_prepared` variant */
+    private fun benchLoop(label: String, plain: String, prepared: String) = """
+let start = Instant::now();
+for _ in 0..ITERATIONS {
+    $plain
+}
+report("$label", start.elapsed());
+let start = Instant::now();
+for _ in 0..ITERATIONS {
+    $prepared
+}
+report("${label}_prepared", start.elapsed());
+    """.trimIndent()
+
+    val benchModule
+        get() = Module(
+            "prepared_${table.nameId}",
+            "Bench of the gateway functions of `$tableName` against their `_prepared` variants",
+            functions = listOf(
+                tableGateway.crudTestSupport.getSampleRowsFn,
+                Fn(
+                    "report",
+                    "Print the time per iteration of a bench loop",
+                    FnParam("label", "&str".asType, "The function timed"),
+                    FnParam("elapsed", "Duration".asType, "Time of all iterations"),
+                    body = FnBody("println!(\"$tableName {label:<32} {:?}/iteration\", elapsed / ITERATIONS);"),
+                    hasUnitTest = false
+                ),
+                Fn(
+                    "main",
+                    "Time the gateway functions of `$tableName` sending the statement text against the prepared statements",
+                    body = FnBody(
+                        listOfNotNull(
+                            """
+let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+runtime.block_on(async {
+let mut cfg = Config::new();
+cfg.dbname = Some("kgen".to_string());
+cfg.user = Some("kgen".to_string());
+cfg.password = Some("kgen".to_string());
+let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+let resource = pool.get().await.unwrap();
+let client = resource.client();
+$tableStructName::drop_table(client).await.unwrap();
+$tableStructName::create_table(client).await.unwrap();
+let samples = get_sample_rows();
+                            """.trimIndent(),
+                            benchLoop(
+                                "bulk_insert",
+                                """$tableStructName::bulk_insert(client, $benchSamplesArg, 4).await.unwrap();
+    $tableStructName::delete_all(client).await.unwrap();""",
+                                """$tableStructName::bulk_insert_prepared(&resource, $benchSamplesArg, 4).await.unwrap();
+    $tableStructName::delete_all_prepared(&resource).await.unwrap();"""
+                            ),
+                            tableGateway.pkeyLookup?.let {
+                                listOf(
+                                    "$tableStructName::bulk_insert(client, $benchSamplesArg, 4).await.unwrap();",
+                                    "let keys = $tableStructName::select_all(client).await.unwrap().iter().map(|row| row.pkey()).collect::<Vec<_>>();",
+                                    benchLoop(
+                                        "select_by_pkey",
+                                        """for key in keys.iter() {
+        $tableStructName::select_by_pkey(client, key).await.unwrap().unwrap();
+    }""",
+                                        """for key in keys.iter() {
+        $tableStructName::select_by_pkey_prepared(&resource, key).await.unwrap().unwrap();
+    }"""
+                                    )
+                                ).joinToString("\n")
+                            },
+                            "$tableStructName::drop_table(client).await.unwrap();",
+                            "});"
+                        ).joinToString("\n")
+                    )
+                )
+            ),
+            consts = listOf(Const("iterations", "Iterations of each bench loop", U32, 100)),
+            uses = listOfNotNull(
+                "kgen_db::${table.nameId}::*",
+                "kgen_db::unsigned::U64".takeIf { table.hasUnsigned },
+                "kgen_db::interval::Interval".takeIf { table.hasInterval },
+                "deadpool_postgres::Config",
+                "deadpool_postgres::Runtime",
+                "std::time::Duration",
+                "std::time::Instant",
+                "tokio_postgres::GenericClient",
+                "tokio_postgres::NoTls",
+            ).asUses
+        )
+}
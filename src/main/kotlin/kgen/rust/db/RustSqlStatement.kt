@@ -7,7 +7,9 @@ import kgen.Id
  *
  * @property varName Name of the rust statement variable
  * @property sqlStatement The statement modeled
- * @property backdoorId If table has backdoor support the id to replace original table name with
+ * @property backdoorId If table has backdoor support the id to replace each occurrence of the original
+ * table name with. Only whole identifiers are replaced, so names merely starting with the table name,
+ * like those of its indices and enums, are left as they are
  * @property formatRequired If true, the SQL statement has embedded strings and requires `format!(...)`
  */
 data class RustSqlStatement(
@@ -17,7 +19,7 @@ data class RustSqlStatement(
     val formatRequired: Boolean = false,
 ) {
     val letStatement get() = if(backdoorId != null) {
-        val patched = sqlStatement.replace(backdoorId.snake, "{}")
+        val patched = sqlStatement.replace(Regex("\\b${Regex.escape(backdoorId.snake)}\\b"), "{0}")
         "let $varName = format!($patched, *${backdoorId.shout}_TABLE_NAME);"
     } else if(formatRequired) {
         "let $varName = format!($sqlStatement);"
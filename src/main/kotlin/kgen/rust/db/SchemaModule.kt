@@ -0,0 +1,151 @@
+package kgen.rust.db
+
+import kgen.rust.*
+import kgen.rustQuote
+
+/** Column of a table as assumed by the generated code, compared against `information_schema.columns` */
+val columnSchemaStruct = Struct(
+    "column_schema",
+    "A column of a table as assumed by the generated table gateway",
+    listOf(
+        Field("name", "Name of the column", "&'static str".asType),
+        Field("udt_name", "Postgres type as named by `information_schema.columns.udt_name`", "&'static str".asType),
+        Field("is_nullable", "True if the column admits `NULL`", RustBoolean),
+    ),
+    attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq")
+)
+
+/** Single difference between the assumed and the live schema */
+val schemaDriftEnum = Enum(
+    "schema_drift",
+    "A difference between the columns assumed by the generated code and those of the live table",
+    listOf(
+        Variant.Struct(
+            "missing_column",
+            "Column assumed by the generated code is not in the table",
+            Field("column", "The column name", "&'static str".asType),
+        ),
+        Variant.Struct(
+            "unexpected_column",
+            "Column of the table unknown to the generated code",
+            Field("column", "The column name", RustString),
+            Field("udt_name", "The postgres type of the column", RustString),
+        ),
+        Variant.Struct(
+            "moved_column",
+            "Column is at a different position than assumed, so reading rows by index is off",
+            Field("column", "The column name", "&'static str".asType),
+            Field("expected_index", "Index assumed by the generated code", USize),
+            Field("actual_index", "Index in the table", USize),
+        ),
+        Variant.Struct(
+            "type_mismatch",
+            "Column has a different postgres type than assumed",
+            Field("column", "The column name", "&'static str".asType),
+            Field("expected", "The assumed `udt_name`", "&'static str".asType),
+            Field("actual", "The `udt_name` of the table", RustString),
+        ),
+        Variant.Struct(
+            "nullability_mismatch",
+            "Column nullability differs from that assumed",
+            Field("column", "The column name", "&'static str".asType),
+            Field("expected_nullable", "True if the generated code assumed the column nullable", RustBoolean),
+        ),
+    ),
+    attrs = derive("Debug", "Clone", "PartialEq", "Eq")
+)
+
+/** Result of verifying a table against the live schema */
+val schemaReportStruct = Struct(
+    "schema_report",
+    "Differences between the columns assumed by a table gateway and the live table",
+    listOf(
+        Field("table_name", "The table verified", RustString),
+        Field("drifts", "Every difference found, empty if consistent", "Vec<SchemaDrift>".asType),
+    ),
+    attrs = derive("Debug", "Clone", "PartialEq", "Eq"),
+    typeImpl = TypeImpl(
+        "SchemaReport".asType,
+        Fn(
+            "is_consistent",
+            "True if no drift was found",
+            refSelf,
+            returnType = RustBoolean,
+            returnDoc = "True if consistent",
+            inlineDecl = InlineDecl.Inline,
+            hasUnitTest = false,
+            body = FnBody("self.drifts.is_empty()")
+        )
+    )
+)
+
+/** Shared function comparing the assumed columns with `information_schema.columns` */
+val verifyTableSchemaFn = Fn(
+    "verify_table_schema",
+    """Compare the columns assumed for `table_name` with those in `information_schema.columns`
+        |of the current schema, by name, position, type and nullability.
+    """.trimMargin(),
+    clientFnParam,
+    FnParam("table_name", "&str".asType, "The table to verify"),
+    FnParam("expected", "&[ColumnSchema]".asType, "The columns assumed, in order"),
+    genericParamSet = genericClientParamSet,
+    isAsync = true,
+    hasUnitTest = false,
+    returnType = "Result<SchemaReport, tokio_postgres::Error>".asType,
+    returnDoc = "The report of any drift or the `tokio_postgres::Error` reading the schema",
+    body = FnBody(
+        """
+let columns_statement = ${
+            rustQuote(
+                """SELECT column_name::text, udt_name::text, is_nullable = 'YES'
+FROM information_schema.columns
+WHERE table_schema = current_schema() AND table_name = ${'$'}1
+ORDER BY ordinal_position"""
+            )
+        };
+let actual = client
+    .query(columns_statement, &[&table_name])
+    .await?
+    .into_iter()
+    .map(|row| Ok((row.try_get::<_, String>(0)?, row.try_get::<_, String>(1)?, row.try_get::<_, bool>(2)?)))
+    .collect::<Result<Vec<_>, tokio_postgres::Error>>()?;
+
+let mut drifts = Vec::new();
+for (expected_index, column) in expected.iter().enumerate() {
+    match actual.iter().position(|(name, _, _)| name == column.name) {
+        None => drifts.push(SchemaDrift::MissingColumn { column: column.name }),
+        Some(actual_index) => {
+            let (_, udt_name, is_nullable) = &actual[actual_index];
+            if actual_index != expected_index {
+                drifts.push(SchemaDrift::MovedColumn { column: column.name, expected_index, actual_index });
+            }
+            if udt_name != column.udt_name {
+                drifts.push(SchemaDrift::TypeMismatch { column: column.name, expected: column.udt_name, actual: udt_name.clone() });
+            }
+            if *is_nullable != column.is_nullable {
+                drifts.push(SchemaDrift::NullabilityMismatch { column: column.name, expected_nullable: column.is_nullable });
+            }
+        }
+    }
+}
+drifts.extend(
+    actual
+        .into_iter()
+        .filter(|(name, _, _)| !expected.iter().any(|column| column.name == name))
+        .map(|(column, udt_name, _)| SchemaDrift::UnexpectedColumn { column, udt_name }),
+);
+Ok(SchemaReport { table_name: table_name.into(), drifts })
+        """.trimIndent()
+    )
+)
+
+/** Module shared by all table gateways supporting verification of the live schema.
+ * Include in the crate alongside the table gateway modules.
+ */
+val schemaModule = Module(
+    "schema",
+    "Verification of the columns assumed by the table gateways against the live schema",
+    structs = listOf(columnSchemaStruct, schemaReportStruct),
+    enums = listOf(schemaDriftEnum),
+    functions = listOf(verifyTableSchemaFn)
+)
@@ -0,0 +1,132 @@
+package kgen.rust.db
+
+import kgen.db.DbTableClassifier
+import kgen.rust.*
+
+/** Responsible for generating `sync_rows`, making the table match a set of rows with the
+ * fewest writes. The current rows are selected and matched to the rows by primary key,
+ * then in one transaction the rows absent from the table are upserted, those differing are
+ * updated with `bulk_update` and those absent from the rows are deleted with
+ * `bulk_delete_by_pkeys`. Rows already matching are not written.
+ *
+ * Only generated for tables keyed by a primary key without _auto id_, as only their row data
+ * carries the key. Rows are matched with the derived `PartialEq` of the row data, after taking
+ * the server managed fields of a row from the current row, so those fields are ignored.
+ * Where `rows` repeats a key the last row with that key is kept.
+ *
+ * @property tableGateway The table gateway
+ */
+data class SyncRows(
+    val tableGateway: TableGateway,
+) {
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** The bulk functions by primary key applying the changes, if the table supports sync */
+    val pkeyBulk = tableGateway.pkeyBulk.takeIf { table.classifier == DbTableClassifier.Pkey }
+
+    /** True if the table supports sync */
+    val isSyncable get() = pkeyBulk != null
+
+    /** True if rows may differ outside the key, requiring updates */
+    private val hasUpdates get() = pkeyBulk?.bulkUpdateFn != null
+
+    /** The server managed columns, whose values in the rows are replaced by the current values */
+    private val serverManagedColumns get() = table.columns.filter { it.isServerManaged }
+
+    /** The match arm of a row with a current row of the same key, comparing them with `==`.
+     * With server managed columns the row first takes their values from `current`, so only the
+     * caller supplied fields decide a match.
+     */
+    private val matchedArms
+        get() = when {
+            !hasUpdates -> "Some(_) => unchanged += 1,"
+            serverManagedColumns.isEmpty() -> """
+Some(current) if current == row => unchanged += 1,
+Some(_) => updates.push(row.clone()),
+            """.trimIndent()
+
+            else -> {
+                val adopted = serverManagedColumns.joinToString(", ") { "${it.nameId}: current.${it.nameId}" }
+                """
+Some(current) => {
+    let row = ${tableGateway.rowDataStructName} { $adopted, ..row.clone() };
+    if *current == row { unchanged += 1 } else { updates.push(row) }
+}
+                """.trimIndent()
+            }
+        }
+
+    /** Statement declaring the rows to update */
+    private val updatesDecl get() = if (hasUpdates) "let mut updates = Vec::new();" else ""
+
+    /** Statement applying the updates, binding `updated` */
+    private val applyUpdates
+        get() = if (hasUpdates) {
+            "let updated = if updates.is_empty() { 0 } else { Self::bulk_update(&transaction, &updates, updates.len()).await? };"
+        } else {
+            "let updated = 0;"
+        }
+
+    val syncRowsFn
+        get() = if (isSyncable) {
+            Fn(
+                "sync_rows",
+                """Make `${table.tableName}` match `rows` exactly, writing only the rows that differ.
+                    |Rows with a key absent from the table are inserted, rows with a key present but other values
+                    |are updated and rows of the table with a key absent from `rows` are deleted, all in one transaction.
+                """.trimMargin(),
+                FnParam("client", "&mut C".asType, "The tokio postgresql client, beginning the transaction"),
+                FnParam(
+                    "rows",
+                    "&[${tableGateway.rowDataStructName}]".asType,
+                    "The rows the table is to match, the last row kept where a primary key repeats"
+                ),
+                genericParamSet = genericClientParamSet,
+                isAsync = true,
+                hasUnitTest = false,
+                returnType = "Result<SyncSummary, tokio_postgres::Error>".asType,
+                returnDoc = "Summary of the changes applied, or the `tokio_postgres::Error` rolling them back",
+                body = FnBody(
+                    """
+let transaction = client.transaction().await?;
+let current_rows = Self::select_all(&transaction).await?;
+let mut current_by_key = current_rows.iter().map(|current| (current.pkey(), current)).collect::<std::collections::HashMap<_, _>>();
+// Keep only the last row for each key, in the order of the rows kept
+let mut seen = std::collections::HashSet::new();
+let mut unique_rows = rows.iter().rev().filter(|row| seen.insert(row.pkey())).collect::<Vec<_>>();
+unique_rows.reverse();
+let mut inserts = Vec::new();
+$updatesDecl
+let mut unchanged = 0;
+for row in unique_rows {
+    match current_by_key.remove(&row.pkey()) {
+        None => inserts.push(row.clone()),
+        $matchedArms
+    }
+}
+let deletes = current_by_key.into_keys().collect::<Vec<_>>();
+
+let deleted = if deletes.is_empty() { 0 } else { Self::bulk_delete_by_pkeys(&transaction, &deletes, deletes.len()).await? };
+$applyUpdates
+if !inserts.is_empty() {
+    Self::bulk_upsert(&transaction, &inserts, inserts.len()).await?;
+}
+transaction.commit().await?;
+
+let summary = SyncSummary {
+    inserted: inserts.len() as u64,
+    updated,
+    deleted,
+    unchanged,
+};
+tracing::debug!("Finished sync of size({}) in `${table.nameId}` -> {summary:?}", rows.len());
+Ok(summary)
+                    """.trimIndent()
+                )
+            )
+        } else {
+            null
+        }
+}
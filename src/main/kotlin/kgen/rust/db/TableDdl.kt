@@ -0,0 +1,128 @@
+package kgen.rust.db
+
+import kgen.doubleQuote
+import kgen.rust.*
+import kgen.rustQuote
+
+/** Responsible for generating the schema of the table - the `CREATE_DDL` constant with
+ * `create_table` and `drop_table` methods - so a database can be provisioned from the
+ * generated code alone.
+ *
+ * The DDL creates any postgres enum types used by the columns, the table with its primary
+ * key and the unique indices. All statements are idempotent, so `create_table` may be run
+ * against an existing schema. Enum types are left in place by `drop_table`, as other tables
+ * may share them. The `created_at` and `updated_at` columns default to `now()`.
+ *
+ *  @property tableGateway Data associated with the table
+ */
+data class TableDdl(
+    val tableGateway: TableGateway,
+) {
+
+    /** Table name */
+    val tableName get() = tableGateway.table.tableName
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Statements creating each enum type, ignoring those already present */
+    val createTypeStatements
+        get() = table.pgEnums.map { pgEnum ->
+            """DO $$ BEGIN
+    CREATE TYPE ${pgEnum.typeName} AS ENUM (${pgEnum.values.joinToString(", ") { "'$it'" }});
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;"""
+        }
+
+    /** Statement creating the table with its primary key */
+    val createTableStatement
+        get() = listOf(
+            "CREATE TABLE IF NOT EXISTS $tableName (",
+            (table.columns.map { column ->
+                "\t${column.asSqlLiteral} ${column.ddlType}${if (column.type.isNullable) "" else " NOT NULL"}${
+                    if (column.role.isSetOnInsert) " DEFAULT now()" else ""
+                }"
+            } + listOfNotNull(
+                table.primaryKeyColumns.takeIf { it.isNotEmpty() }?.let { "\tPRIMARY KEY (${it.asConflictKey})" }
+            )).joinToString(",\n"),
+            ");"
+        ).joinToString("\n")
+
+    /** Statements creating each unique index */
+    val createIndexStatements
+        get() = table.uniqueIndices.map { (indexName, indexColumns) ->
+            "CREATE UNIQUE INDEX IF NOT EXISTS $indexName ON $tableName (${indexColumns.asConflictKey});"
+        }
+
+    /** All statements creating the schema of the table, in order */
+    val createDdl
+        get() = (createTypeStatements + createTableStatement + createIndexStatements).joinToString("\n\n")
+
+    val createDdlConst
+        get() = Const(
+            "create_ddl",
+            "Statements creating the table `$tableName`, its enum types and unique indices",
+            "&str".asType,
+            rustQuote(createDdl).asConstValue
+        )
+
+    /** The statements of [createDdl], naming the backdoor table if set */
+    val rustCreateStatement = RustSqlStatement(
+        "create_statement",
+        rustQuote(createDdl),
+        tableGateway.backdoorTableId
+    )
+
+    val rustDropStatement = RustSqlStatement(
+        "drop_statement",
+        doubleQuote("DROP TABLE IF EXISTS $tableName"),
+        tableGateway.backdoorTableId
+    )
+
+    val createTableFn
+        get() = Fn(
+            "create_table",
+            """Create the table `$tableName`, with any enum types and unique indices, if not present.
+                |Runs the statements of [Self::CREATE_DDL], against the backdoor table name if set.
+            """.trimMargin(),
+            clientFnParam,
+            genericParamSet = genericClientParamSet,
+            isAsync = true,
+            inlineDecl = InlineDecl.Inline,
+            returnType = "Result<(), tokio_postgres::Error>".asType,
+            returnDoc = "Success or tokio_postgres::Error",
+            body = FnBody(
+                """
+${rustCreateStatement.letStatement}
+match client.batch_execute(${rustCreateStatement.asStr}).await {
+    Ok(()) => Ok(()),
+    Err(err) => {
+        tracing::error!("Failed create_table `${table.nameId}` -> {err}");
+        Err(err)
+    }
+}
+                """.trimIndent()
+            ),
+            hasUnitTest = false
+        )
+
+    val dropTableFn
+        get() = Fn(
+            "drop_table",
+            "Drop the table `$tableName` if present, leaving any enum types in place",
+            clientFnParam,
+            genericParamSet = genericClientParamSet,
+            isAsync = true,
+            inlineDecl = InlineDecl.Inline,
+            returnType = "Result<(), tokio_postgres::Error>".asType,
+            returnDoc = "Success or tokio_postgres::Error",
+            body = FnBody(
+                """
+${rustDropStatement.letStatement}
+client.execute(${rustDropStatement.asStr}, &[]).await.map(|_| ())
+                """.trimIndent()
+            ),
+            hasUnitTest = false
+        )
+}
@@ -2,8 +2,10 @@ package kgen.rust.db
 
 import kgen.Id
 import kgen.asId
+import kgen.db.DbColumn
 import kgen.db.DbTable
 import kgen.db.DbTableClassifier
+import kgen.db.DbType
 import kgen.doubleQuote
 import kgen.markdownQuoteComment
 import kgen.rust.*
@@ -91,9 +93,33 @@ data class TableGateway(
     val autoIncQueryColumn = allQueryColumns.firstOrNull { it.isAutoInc }
 
     val nonAutoIncColumnSetLiteralValue = "(\n\t${table.nonAutoIncGroupedColumnNames}\n)"
+
+    /** Columns set by inserts - those supplied by callers then any server managed set to `now()` */
+    val insertColumnSetLiteralValue = "(\n\t${table.insertGroupedColumnNames}\n)"
+
+    /** Columns supplied by callers - those copied in, any server managed taking their defaults */
+    val callerColumnSetLiteralValue = "(\n\t${table.callerGroupedColumnNames}\n)"
     val bulkInsertChunkSizeFnParam = FnParam("chunk_size", USize, "How to chunk the inserts")
     val unnestedColumnExpressionValue = "(\n\t${table.unnestedColumnExpressions}\n)"
 
+    /** Select of the rows unnested from the column vectors bound as parameters.
+     * Array columns are bound as their text literals, so when present the unnested
+     * columns are named and the arrays cast back to their column type.
+     * Any server managed columns set on insert follow as `now()`.
+     */
+    val unnestSelectValue = if (table.insertColumns.none { it.type.isArray }) {
+        "SELECT *${table.setOnInsertValues} FROM UNNEST\n$unnestedColumnExpressionValue"
+    } else {
+        val columns = table.insertColumns.chunked(6)
+        "SELECT ${
+            columns.joinToString(",\n\t") { chunk ->
+                chunk.joinToString(", ") { if (it.type.isArray) "${it.nameId}${it.sqlCast}" else it.nameId }
+            }
+        }${table.setOnInsertValues}\nFROM UNNEST\n$unnestedColumnExpressionValue AS unnested(${
+            columns.joinToString(",\n\t") { chunk -> chunk.joinToString(", ") { it.nameId } }
+        })"
+    }
+
     val rowEntryStruct = autoIncQueryColumn?.let {
         Struct(
             "${id}_entry",
@@ -116,10 +142,16 @@ data class TableGateway(
 
     val selectAllWhere = SelectAllWhereFn(this)
     val selectAll = SelectAllFn(this)
+    val selectStreamWhere = SelectStreamWhereFn(selectAllWhere)
     val basicInsert = BasicInsert(this, autoIdDetails)
     val bulkInsert = BulkInsert(this, autoIdDetails)
     val bulkUpsert = BulkUpsert(this, autoIdDetails)
+    val transactionalBatch = TransactionalBatch(this, autoIdDetails)
+    val copyIn = CopyIn(this)
+    val copyOut = CopyOut(selectAllWhere)
     val deleteStatement = DeleteStatement(this)
+    val tableDdl = TableDdl(this)
+    val verifySchema = VerifySchema(this)
     val keyColumnSet = if (table.hasPrimaryKey) {
         QueryColumnSet(
             "${id.snake}_pkey",
@@ -132,6 +164,83 @@ data class TableGateway(
 
     val keyStruct = keyColumnSet?.asRustStruct
 
+    /** Functions to select, update and delete by primary key */
+    val pkeyLookup = keyStruct?.let { KeyLookup(this, "pkey", table.primaryKeyColumns, it.structName) }
+
+    /** Functions to update and delete batches of rows by primary key */
+    val pkeyBulk = pkeyLookup?.let { BulkByKey(it) }
+
+    /** Function making the table match a set of rows */
+    val syncRows = SyncRows(this)
+
+    /** Functions aggregating the rows in the database */
+    val aggregates = AggregateFns(selectAllWhere)
+
+    /** Functions to select the rows a page at a time */
+    val selectPage = SelectPageFn(selectAllWhere)
+
+    /** The `_prepared` functions running the fixed statements cached per connection */
+    val preparedStatements = PreparedStatements(this)
+
+    /** Support for each unique index of the table */
+    val uniqueIndices = table.uniqueIndices.map { (indexName, indexColumns) ->
+        UniqueIndex(this, indexName, indexColumns)
+    }
+
+    /** The column enum supporting typed filters */
+    val columnEnum = ColumnEnum(this)
+
+    /** Returns the type to attach a key accessor to and the accessor function.
+     * The accessor is on the row data, or on the entry if the key includes the _auto id_.
+     */
+    private fun keyAccessor(
+        fnNameId: String,
+        doc: String,
+        keyStructName: String,
+        keyColumns: List<DbColumn>
+    ): Pair<String, Fn> {
+        val keyInEntry = keyColumns.any { it.isAutoIncrement }
+        return Pair(
+            if (keyInEntry) rowEntryStructName!! else rowDataStructName,
+            Fn(
+                fnNameId,
+                doc,
+                refSelf,
+                returnType = keyStructName.asType,
+                returnDoc = "The key",
+                hasUnitTest = false,
+                inlineDecl = InlineDecl.Inline,
+                body = FnBody(
+                    listOf(
+                        "$keyStructName {",
+                        keyColumns.joinToString(",\n") { column ->
+                            val item = if (keyInEntry && !column.isAutoIncrement) "self.data" else "self"
+                            "${column.nameId}: ${column.ownedValue(item)}"
+                        },
+                        "}"
+                    ).joinToString("\n")
+                )
+            )
+        )
+    }
+
+    /** Impls providing `pkey()` and the unique index key accessors */
+    val keyAccessorImpls = (
+            listOfNotNull(
+                keyStruct?.let {
+                    keyAccessor("pkey", "Get the primary key of the row", it.structName, table.primaryKeyColumns)
+                }
+            ) + uniqueIndices.map {
+                keyAccessor(
+                    it.keyAccessorNameId,
+                    "Get the key of unique index `${it.indexName}` of the row",
+                    it.keyStruct.structName,
+                    it.indexColumns
+                )
+            })
+        .groupBy({ it.first }, { it.second })
+        .map { (typeName, functions) -> TypeImpl(typeName.asType, functions) }
+
     val tableStruct = Struct(
         "table_${id.snake}",
         """Table Gateway Support for table `${id.snake}`.
@@ -143,18 +252,43 @@ data class TableGateway(
                 "The total number of key and value columns",
                 USize,
                 columnCount
-            )
+            ),
+            tableDdl.createDdlConst,
+            verifySchema.columnSchemaConst
         ),
         typeImpl = TypeImpl(
             "Table${id.capCamel}".asType,
             functions = listOfNotNull(
+                selectAllWhere.fromRowFn,
                 selectAllWhere.selectAllWhereFn,
+                selectAllWhere.selectAllWhereIncludingDeletedFn,
                 selectAll.selectAllFn,
+                selectStreamWhere.selectStreamWhereFn,
+                selectStreamWhere.selectChunksWhereFn,
+                selectPage.selectPageAfterFn,
+                selectPage.selectPageAtOffsetFn,
+                columnEnum.selectAllFilteredFn,
                 basicInsert.basicInsertFn,
                 bulkInsert.bulkInsertFn,
+                copyIn.copyInFn,
+                copyOut.copyOutWhereFn,
                 bulkUpsert.bulkUpsertFn,
-                deleteStatement.deleteAllFn
-            )
+                transactionalBatch.bulkInsertTransactionalFn,
+                transactionalBatch.bulkUpsertTransactionalFn,
+                deleteStatement.deleteAllFn,
+                deleteStatement.hardDeleteAllFn,
+                tableDdl.createTableFn,
+                tableDdl.dropTableFn,
+                verifySchema.verifySchemaFn,
+                pkeyLookup?.selectByKeyFn,
+                pkeyLookup?.selectByKeysFn,
+                pkeyLookup?.updateByKeyFn,
+                pkeyLookup?.updateByKeyIfVersionFn,
+                pkeyLookup?.deleteByKeyFn,
+                pkeyBulk?.bulkUpdateFn,
+                pkeyBulk?.bulkDeleteByKeysFn,
+                syncRows.syncRowsFn,
+            ) + aggregates.aggregateFns + preparedStatements.preparedFns + uniqueIndices.flatMap { it.functions }
         ),
         attrs = commonDerives + derive("Default")
     )
@@ -168,12 +302,40 @@ data class TableGateway(
         ).joinToString("\n\n"),
         uses = listOf(
             "tokio_postgres::types::ToSql",
-        ).asUses + Use("std::sync::LazyLock", attrAllowUnused),
+            "crate::batch::BatchMode",
+            "crate::batch::BatchReport",
+            "crate::batch::ChunkFailure",
+            "crate::batch::ChunkSuccess",
+            "crate::filter::Filter",
+            "crate::filter::SqlColumn",
+            "crate::schema::ColumnSchema",
+            "crate::schema::SchemaReport",
+            "crate::schema::verify_table_schema",
+            "futures::StreamExt",
+            "tokio_postgres::binary_copy::BinaryCopyInWriter",
+            "tokio_postgres::binary_copy::BinaryCopyOutStream",
+        ).asUses + listOfNotNull(
+            // Only the copies of columns all having a static type name the `Type`, else types are prepared
+            "tokio_postgres::types::Type".takeIf { table.insertColumns.all { it.copyType != null } },
+            "crate::version::VersionedUpdate".takeIf { table.versionColumn != null },
+            "crate::page::Page".takeIf { selectPage.isPageable },
+            "crate::batch::SyncSummary".takeIf { syncRows.isSyncable },
+            "crate::unsigned::U64".takeIf { table.hasUnsigned },
+            "crate::interval::Interval".takeIf { table.hasInterval },
+            "tokio_postgres::types::Json".takeIf { table.jsonPayloadColumns.isNotEmpty() }
+        ).asUses + table.jsonPayloadColumns.mapNotNull { it.jsonPayload }.distinct().asUses +
+                Use("std::sync::LazyLock", attrAllowUnused) +
+                table.pgEnums.map { it.cratePath }.asPubUses,
+        enums = listOf(columnEnum.columnEnum),
         structs = listOfNotNull(
             rowDataStruct, rowEntryStruct,
             keyStruct, tableStruct
+        ) + uniqueIndices.map { it.keyStruct },
+        statics = listOfNotNull(tableNameStatic),
+        functions = listOfNotNull(
+            pgArrayLiteralFn.takeIf { table.insertColumns.any { it.type.isArray } }
         ),
-        statics = listOfNotNull(tableNameStatic)
+        typeImpls = keyAccessorImpls
     )
 
     val crudTestSupport = CrudTestSupport(this, tableStruct)
@@ -193,7 +355,8 @@ data class TableGateway(
             Fn("mutate_value", "Change the value in some deterministic way", refMutSelf),
         )
 
-        val testSupportModule = Module(
+        /** Module supporting the db tests, implementing `MutateValue` once for each of [pgEnums] */
+        fun testSupportModule(pgEnums: List<DbType.PgEnum>) = Module(
             "support",
             "Support for db tests",
             statics = listOf(
@@ -206,7 +369,7 @@ data class TableGateway(
             ),
             traits = listOf(mutateValueTrait),
             traitImpls = listOf(
-                "i64", "u64", "u32", "i32", "i16"
+                "i64", "u64", "u32", "i32", "i16", "i8"
             ).map {
                 listOf(
                     TraitImpl(
@@ -222,19 +385,39 @@ data class TableGateway(
                     listOf(
                         TraitImpl(
                             RustString, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.push_str(\"*\");")
+                            bodies = mapOf("mutate_value" to "self.push('*');")
                         ),
                         TraitImpl(
                             "Option<String>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|v| v.push_str(\"*\"));")
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.push('*') }")
                         ),
+                        // Mutates every leaf in place, so the shape of any typed json payload is kept
                         TraitImpl(
                             "Value".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "// Need easy mutation")
+                            bodies = mapOf(
+                                "mutate_value" to """
+match self {
+    Value::Null => {}
+    Value::Bool(value) => *value = !*value,
+    Value::Number(number) => {
+        if let Some(value) = number.as_i64() {
+            *number = value.wrapping_add(1).into();
+        } else if let Some(value) = number.as_u64() {
+            *number = value.wrapping_add(1).into();
+        } else if let Some(value) = number.as_f64().and_then(|value| Number::from_f64(value + 1.0)) {
+            *number = value;
+        }
+    }
+    Value::String(value) => value.push('*'),
+    Value::Array(values) => values.iter_mut().for_each(|value| value.mutate_value()),
+    Value::Object(values) => values.values_mut().for_each(|value| value.mutate_value()),
+}
+                                """.trimIndent()
+                            )
                         ),
                         TraitImpl(
                             "Option<Value>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "// Need easy way to mutate")
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.mutate_value() }")
                         ),
                         TraitImpl(
                             RustChar, mutateValueTrait,
@@ -242,7 +425,7 @@ data class TableGateway(
                         ),
                         TraitImpl(
                             "Option<char>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|c| *c = (*c as u8 + 1) as char);")
+                            bodies = mapOf("mutate_value" to "if let Some(c) = self.as_mut() { *c = (*c as u8 + 1) as char }")
                         ),
                         TraitImpl(
                             RustBoolean, mutateValueTrait,
@@ -250,23 +433,73 @@ data class TableGateway(
                         ),
                         TraitImpl(
                             "Option<bool>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|v| *v = !*v);")
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v = !*v }")
                         ),
                         TraitImpl(
                             "NaiveDate".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "*self = *self + Duration::days(1);")
+                            bodies = mapOf("mutate_value" to "*self += Duration::days(1);")
                         ),
                         TraitImpl(
                             "Option<NaiveDate>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|v| *v = *v + Duration::days(1));")
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += Duration::days(1) }")
                         ),
                         TraitImpl(
                             "NaiveDateTime".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "*self = *self + Duration::days(1);")
+                            bodies = mapOf("mutate_value" to "*self += Duration::days(1);")
                         ),
                         TraitImpl(
                             "Option<NaiveDateTime>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|v| *v = *v + Duration::days(1));")
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += Duration::days(1) }")
+                        ),
+                        TraitImpl(
+                            F64, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "*self += 1.0;")
+                        ),
+                        TraitImpl(
+                            "Option<f64>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += 1.0 }")
+                        ),
+                        TraitImpl(
+                            "Vec<T>".asType, mutateValueTrait,
+                            genericParamSet = GenericParamSet(TypeParam("t", bounds = Bounds("Default"))),
+                            bodies = mapOf("mutate_value" to "self.push(T::default());")
+                        ),
+                        TraitImpl(
+                            "Option<Vec<T>>".asType, mutateValueTrait,
+                            genericParamSet = GenericParamSet(TypeParam("t", bounds = Bounds("Default"))),
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.push(T::default()) }")
+                        ),
+                        TraitImpl(
+                            "Decimal".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "*self += Decimal::ONE;")
+                        ),
+                        TraitImpl(
+                            "Option<Decimal>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += Decimal::ONE }")
+                        ),
+                        TraitImpl(
+                            "NaiveTime".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "*self += Duration::minutes(1);")
+                        ),
+                        TraitImpl(
+                            "Option<NaiveTime>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += Duration::minutes(1) }")
+                        ),
+                        TraitImpl(
+                            "DateTime<Utc>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "*self += Duration::days(1);")
+                        ),
+                        TraitImpl(
+                            "Option<DateTime<Utc>>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { *v += Duration::days(1) }")
+                        ),
+                        TraitImpl(
+                            "Interval".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "self.0.days += 1;")
+                        ),
+                        TraitImpl(
+                            "Option<Interval>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.0.days += 1 }")
                         ),
                         TraitImpl(
                             "Uuid".asType, mutateValueTrait,
@@ -280,9 +513,18 @@ let namespace = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"kgen-test");
                         ),
                         TraitImpl(
                             "Option<Uuid>".asType, mutateValueTrait,
-                            bodies = mapOf("mutate_value" to "self.as_mut().map(|u| u.mutate_value());")
+                            bodies = mapOf("mutate_value" to "if let Some(u) = self.as_mut() { u.mutate_value() }")
+                        ),
+                        // Toggling the low bit keeps the value within the `bigint` range
+                        TraitImpl(
+                            "U64".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "self.0 ^= 1;")
+                        ),
+                        TraitImpl(
+                            "Option<U64>".asType, mutateValueTrait,
+                            bodies = mapOf("mutate_value" to "if let Some(v) = self.as_mut() { v.0 ^= 1 }")
                         )
-                    ),
+                    ) + pgEnums.flatMap { it.mutateValueImpls },
             functions = listOf(
                 Fn(
                     "get_pool",
@@ -313,22 +555,47 @@ let namespace = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"kgen-test");
     """.trimIndent()
                     ),
                     isAsync = true
+                ),
+                Fn(
+                    "mutate_json",
+                    "Mutate a typed json payload as its `Value`, requiring no `MutateValue` impl of the payload type",
+                    FnParam("payload", "&mut T".asType, "The payload to mutate"),
+                    genericParamSet = GenericParamSet(
+                        TypeParam("t", bounds = Bounds("Serialize", "DeserializeOwned"))
+                    ),
+                    body = FnBody(
+                        """
+let mut value = serde_json::to_value(&*payload).unwrap();
+value.mutate_value();
+*payload = serde_json::from_value(value).unwrap();
+                        """.trimIndent()
+                    ),
+                    hasUnitTest = false
                 )
             ),
             uses = listOf(
+                "kgen_db::unsigned::U64",
+                "kgen_db::interval::Interval",
                 "deadpool_postgres::Config",
                 "deadpool_postgres::ManagerConfig",
                 "deadpool_postgres::Pool",
                 "deadpool_postgres::Runtime",
                 "deadpool_postgres::RecyclingMethod",
+                "chrono::DateTime",
                 "chrono::Duration",
                 "chrono::NaiveDate",
                 "chrono::NaiveDateTime",
+                "chrono::NaiveTime",
+                "chrono::Utc",
+                "rust_decimal::Decimal",
+                "serde::Serialize",
+                "serde::de::DeserializeOwned",
+                "serde_json::Number",
                 "serde_json::Value",
                 "std::ops::Add",
                 "tokio_postgres::NoTls",
                 "uuid::Uuid",
-            ).asUses
+            ).asUses + pgEnums.map { "kgen_db::pg_enum::${it.rustName}" }.asUses
         )
     }
 }
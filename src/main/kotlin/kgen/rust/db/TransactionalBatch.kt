@@ -0,0 +1,109 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Responsible for generating the transactional variants of `bulk_insert` and `bulk_upsert`.
+ * Each chunk is run with the wrapped function inside a single transaction, either
+ * all-or-nothing or with a savepoint per chunk, and the outcome of every chunk is reported.
+ *
+ *  @property tableGateway Data associated with the table
+ *  @property autoIdDetails Details if table has _auto id_
+ */
+data class TransactionalBatch(
+    val tableGateway: TableGateway,
+    val autoIdDetails: AutoIdDetails?,
+) {
+
+    /** Table id */
+    val id get() = tableGateway.id
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Rows of a chunk as taken by the wrapped function - owned if table has _auto id_ */
+    private val chunkRowsArg = if (autoIdDetails != null) "chunk_rows.to_vec()" else "chunk_rows"
+
+    /** The transactional variant of wrapped function [wrappedNameId] */
+    private fun transactionalFn(wrappedNameId: String, verb: String) = Fn(
+        "${wrappedNameId}_transactional",
+        """${verb.replaceFirstChar { it.uppercase() }} large batch of [${id.capCamel}] rows in a transaction, reporting the outcome of each chunk.
+            |With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+            |With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+        """.trimMargin(),
+        FnParam("client", "&mut C".asType, "The tokio postgresql client, beginning the transaction"),
+        FnParam("rows", "&[${tableGateway.rowDataStructName}]".asType, "Row data to $verb"),
+        tableGateway.bulkInsertChunkSizeFnParam,
+        FnParam("mode", "BatchMode".asType, "How the chunks are committed"),
+        genericParamSet = genericClientParamSet,
+        isAsync = true,
+        hasUnitTest = false,
+        returnType = "Result<BatchReport<${autoIdDetails?.outputType ?: "()"}>, tokio_postgres::Error>".asType,
+        returnDoc = "Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction",
+        body = FnBody(
+            """
+let mut transaction = client.transaction().await?;
+let mut report = BatchReport {
+    succeeded: Vec::new(),
+    failed: Vec::new(),
+};
+for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+    let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+    let result = match mode {
+        BatchMode::AllOrNothing => Self::$wrappedNameId(&transaction, $chunkRowsArg, chunk_size).await,
+        BatchMode::ChunkSavepoints => {
+            let savepoint = transaction.transaction().await?;
+            match Self::$wrappedNameId(&savepoint, $chunkRowsArg, chunk_size).await {
+                Ok(result) => savepoint.commit().await.map(|_| result),
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    Err(err)
+                }
+            }
+        }
+    };
+    match result {
+        Ok(result) => report.succeeded.push(ChunkSuccess {
+            chunk,
+            rows: chunk_range,
+            result,
+        }),
+        Err(err) => {
+            tracing::error!("Failed ${wrappedNameId}_transactional chunk({chunk}) `${table.nameId}` -> {err}");
+            if mode == BatchMode::AllOrNothing {
+                // Nothing is committed, so every chunk failed - only this one with an error
+                transaction.rollback().await?;
+                let mut error = Some(err);
+                let failed = (0..rows.len().div_ceil(chunk_size))
+                    .map(|failed_chunk| ChunkFailure {
+                        chunk: failed_chunk,
+                        rows: failed_chunk * chunk_size..rows.len().min((failed_chunk + 1) * chunk_size),
+                        error: if failed_chunk == chunk { error.take() } else { None },
+                    })
+                    .collect();
+                return Ok(BatchReport {
+                    succeeded: Vec::new(),
+                    failed,
+                });
+            }
+            report.failed.push(ChunkFailure {
+                chunk,
+                rows: chunk_range,
+                error: Some(err),
+            });
+        }
+    }
+}
+transaction.commit().await?;
+Ok(report)
+            """.trimIndent()
+        )
+    )
+
+    val bulkInsertTransactionalFn get() = transactionalFn("bulk_insert", "insert")
+
+    /** Transactional upsert, only available if the table has columns to resolve conflicts on */
+    val bulkUpsertTransactionalFn
+        get() = tableGateway.bulkUpsert.takeIf { it.conflictColumns.isNotEmpty() }?.let {
+            transactionalFn(it.fnNameId, "upsert")
+        }
+}
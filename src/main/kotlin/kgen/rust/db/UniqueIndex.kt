@@ -0,0 +1,55 @@
+package kgen.rust.db
+
+import kgen.asId
+import kgen.db.DbColumn
+import kgen.rust.db.select.QueryColumnSet
+import kgen.rust.db.select.asQueryColumns
+
+/** Responsible for generating support for looking up rows by a unique index of the table.
+ * Provides a key struct named after the index, `select_by_<index>`, `delete_by_<index>`
+ * and a `bulk_upsert_by_<index>` whose `ON CONFLICT` target is the index.
+ *
+ * @property tableGateway The table gateway
+ * @property indexName The name of the unique index
+ * @property indexColumns The columns of the unique index
+ */
+data class UniqueIndex(
+    val tableGateway: TableGateway,
+    val indexName: String,
+    val indexColumns: List<DbColumn>,
+) {
+    /** Id of the index used to name the key struct and functions */
+    val id = indexName.asId
+
+    /** The fields of the index */
+    val keyColumnSet = QueryColumnSet(
+        id.snake,
+        "Fields of unique index `$indexName` of `${tableGateway.id.capCamel}`",
+        indexColumns.asQueryColumns
+    )
+
+    /** The key struct for the index */
+    val keyStruct = keyColumnSet.asRustStruct
+
+    /** Name of function on the row returning the index key */
+    val keyAccessorNameId = "${id.snake}_key"
+
+    /** Functions to select and delete by the index */
+    val keyLookup = KeyLookup(tableGateway, id.snake, indexColumns, keyStruct.structName)
+
+    /** Upsert with conflicts resolved on the index */
+    val bulkUpsert = BulkUpsert(
+        tableGateway,
+        tableGateway.autoIdDetails,
+        indexColumns,
+        "bulk_upsert_by_${id.snake}"
+    )
+
+    /** All table functions supporting the index */
+    val functions
+        get() = listOf(
+            keyLookup.selectByKeyFn,
+            keyLookup.deleteByKeyFn,
+            bulkUpsert.bulkUpsertFn
+        )
+}
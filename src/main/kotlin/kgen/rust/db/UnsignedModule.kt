@@ -0,0 +1,82 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Checked newtype modeling the unsigned columns, stored in a postgres `bigint` */
+val unsignedStruct = Struct(
+    "u64",
+    """A `u64` stored in a postgres `bigint`.
+        |`tokio_postgres` has no `ToSql`/`FromSql` for `u64`, so unsigned columns are modeled by this newtype,
+        |checked both ways: values above `i64::MAX` fail to bind and negative values fail to decode,
+        |rather than silently wrapping.
+    """.trimMargin(),
+    listOf(Field("value", "The unsigned value", U64)),
+    asTupleStruct = true,
+    attrs = derive("Debug", "Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash", "Default"),
+)
+
+/** The impls mapping [unsignedStruct] to and from `bigint` through `i64`, and converting to and from `u64` */
+private val unsignedImpls = """
+impl ToSql for U64 {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        i64::try_from(self.0)?.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i64 as ToSql>::accepts(ty)
+    }
+
+    fn to_sql_checked(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        i64::try_from(self.0)?.to_sql_checked(ty, out)
+    }
+}
+
+impl<'a> FromSql<'a> for U64 {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(U64(u64::try_from(i64::from_sql(ty, raw)?)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <i64 as FromSql>::accepts(ty)
+    }
+}
+
+impl std::fmt::Display for U64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u64> for U64 {
+    fn from(value: u64) -> Self {
+        U64(value)
+    }
+}
+
+impl From<U64> for u64 {
+    fn from(value: U64) -> Self {
+        value.0
+    }
+}
+""".trim()
+
+/** Module shared by all table gateways with unsigned columns.
+ * Include in the crate alongside the table gateway modules.
+ */
+val unsignedModule = Module(
+    "unsigned",
+    "The checked newtype modeling unsigned columns",
+    structs = listOf(unsignedStruct),
+    moduleBody = FnBody(unsignedImpls),
+    uses = listOf(
+        "bytes::BytesMut",
+        "tokio_postgres::types::FromSql",
+        "tokio_postgres::types::IsNull",
+        "tokio_postgres::types::ToSql",
+        "tokio_postgres::types::Type",
+    ).asUses
+)
@@ -0,0 +1,55 @@
+package kgen.rust.db
+
+import kgen.doubleQuote
+import kgen.rust.*
+
+/** Responsible for generating `verify_schema` method for the table.
+ * Compares the columns the generated code assumes - names in `FIELD_NAMES` order, types and
+ * nullability - with the live table, so a schema that drifted from the generated crate is reported
+ * up front rather than failing as a row decode error.
+ *
+ *  @property tableGateway Data associated with the table
+ */
+data class VerifySchema(
+    val tableGateway: TableGateway,
+) {
+
+    /** Underlying table */
+    val table get() = tableGateway.table
+
+    /** Table name, from the static if the table has backdoor support */
+    val tableNameArg
+        get() = tableGateway.tableNameVarId?.let { "&${it.shout}" } ?: doubleQuote(table.tableName)
+
+    val columnSchemaConst
+        get() = Const(
+            "column_schema",
+            "The columns of the table, in order, as assumed by the generated code",
+            "[ColumnSchema; Self::COLUMN_COUNT]".asType,
+            listOf(
+                "[",
+                table.columns.joinToString(",\n") { column ->
+                    "ColumnSchema { name: ${doubleQuote(column.columnName)}, udt_name: ${
+                        doubleQuote(column.udtName)
+                    }, is_nullable: ${column.type.isNullable} }"
+                },
+                "]"
+            ).joinToString("\n").asConstValue
+        )
+
+    val verifySchemaFn
+        get() = Fn(
+            "verify_schema",
+            """Verify the live table matches the columns assumed in [Self::COLUMN_SCHEMA].
+                |Drift is returned in the report rather than as an error.
+            """.trimMargin(),
+            clientFnParam,
+            genericParamSet = genericClientParamSet,
+            isAsync = true,
+            inlineDecl = InlineDecl.Inline,
+            returnType = "Result<SchemaReport, tokio_postgres::Error>".asType,
+            returnDoc = "The report of any drift or the `tokio_postgres::Error` reading the schema",
+            body = FnBody("verify_table_schema(client, $tableNameArg, &Self::COLUMN_SCHEMA).await"),
+            hasUnitTest = false
+        )
+}
@@ -0,0 +1,31 @@
+package kgen.rust.db
+
+import kgen.rust.*
+
+/** Outcome of an update guarded by the version column */
+val versionedUpdateEnum = Enum(
+    "versioned_update",
+    "Outcome of an update guarded by the version column of the row",
+    listOf(
+        Variant.Struct(
+            "updated",
+            "The row was at the expected version and is updated",
+            Field("version", "The new version of the row", "V".asType),
+        ),
+        Variant.UnitStruct(
+            "conflict",
+            "No row with the key is at the expected version - it was updated or deleted since read"
+        ),
+    ),
+    genericParamSet = GenericParamSet("v"),
+    attrs = derive("Debug", "Clone", "PartialEq")
+)
+
+/** Module shared by all table gateways with a version column.
+ * Include in the crate alongside the table gateway modules.
+ */
+val versionModule = Module(
+    "version",
+    "Outcomes of the optimistic concurrency functions of the table gateways",
+    enums = listOf(versionedUpdateEnum)
+)
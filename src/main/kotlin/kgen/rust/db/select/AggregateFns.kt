@@ -0,0 +1,120 @@
+package kgen.rust.db.select
+
+import kgen.db.DbColumn
+import kgen.rust.*
+import kgen.rust.db.*
+import kgen.rustQuote
+
+/**
+ * Generates the Rust functions aggregating the rows matching a `WHERE` clause in the database,
+ * rather than selecting them to aggregate in rust.
+ *
+ * - `count_where` counts the rows.
+ * - `exists_where` checks for any row.
+ * - `min_<column>` and `max_<column>` for each column of an orderable type.
+ *
+ * All take the where clause and parameters as `select_all_where` does and, like it, skip any
+ * soft deleted rows.
+ *
+ * @property selectAllWhere The select function providing the where clause convention.
+ */
+data class AggregateFns(
+    val selectAllWhere: SelectAllWhereFn,
+) {
+
+    /** The identifier for the table gateway. */
+    val id = selectAllWhere.id
+
+    /** The database table associated with the query. */
+    val table = selectAllWhere.table
+
+    /** The columns with `min_` and `max_` functions */
+    val orderableColumns = table.columns.filter { it.type.isOrderable }
+
+    /** Statement selecting [expression] over the rows matching the where clause */
+    private fun aggregateStatement(expression: String) = RustSqlStatement(
+        "aggregate_statement",
+        rustQuote("SELECT $expression FROM ${table.tableName} WHERE ${selectAllWhere.liveWhereClause}"),
+        selectAllWhere.tableGateway.backdoorTableId,
+        true
+    )
+
+    /** Function returning the single value of [statement] as [returnType] */
+    private fun aggregateFn(
+        nameId: String,
+        doc: String,
+        statement: RustSqlStatement,
+        returnType: String,
+        returnDoc: String
+    ) = Fn(
+        nameId,
+        doc,
+        clientFnParam,
+        FnParam("where_clause", "&str".asType, "The where clause (sans `where` keyword)"),
+        FnParam("params", "&[&(dyn ToSql + Sync)]".asType, "Any clause parameters"),
+        genericParamSet = genericClientParamSet,
+        isAsync = true,
+        hasUnitTest = false,
+        returnType = "Result<$returnType, tokio_postgres::Error>".asType,
+        returnDoc = returnDoc,
+        body = FnBody(
+            """
+${statement.letStatement}
+match client.query_one(${statement.asStr}, params).await {
+    Ok(row) => row.try_get(0),
+    Err(err) => {
+        tracing::error!("Failed $nameId `${table.nameId}` -> {err}");
+        Err(err)
+    }
+}
+            """.trimIndent()
+        )
+    )
+
+    val countWhereFn
+        get() = aggregateFn(
+            "count_where",
+            "Count rows of `${id.snake}` with provided where clause",
+            aggregateStatement("count(*)"),
+            "i64",
+            "Number of rows matched or the `tokio_postgres::Error`"
+        )
+
+    val existsWhereFn
+        get() = RustSqlStatement(
+            "aggregate_statement",
+            rustQuote("SELECT EXISTS (SELECT 1 FROM ${table.tableName} WHERE ${selectAllWhere.liveWhereClause})"),
+            selectAllWhere.tableGateway.backdoorTableId,
+            true
+        ).let { statement ->
+            aggregateFn(
+                "exists_where",
+                "Check for any row of `${id.snake}` with provided where clause",
+                statement,
+                "bool",
+                "True if any row matched or the `tokio_postgres::Error`"
+            )
+        }
+
+    /** The value of [column] aggregated, `None` if no rows or only `NULL` values match */
+    private fun aggregateType(column: DbColumn) = if (column.type.isNullable) {
+        column.asRustType.asRustName
+    } else {
+        "Option<${column.asRustType.asRustName}>"
+    }
+
+    /** The `min_` and `max_` functions of [column] */
+    private fun extremeFns(column: DbColumn) = listOf("min" to "Least", "max" to "Greatest").map { (fnName, extreme) ->
+        aggregateFn(
+            "${fnName}_${column.nameId}",
+            "$extreme `${column.nameId}` of rows of `${id.snake}` with provided where clause",
+            aggregateStatement("$fnName(${column.asSqlLiteral})"),
+            aggregateType(column),
+            "The $fnName value, `None` if no rows match, or the `tokio_postgres::Error`"
+        )
+    }
+
+    /** All aggregate functions of the table */
+    val aggregateFns
+        get() = listOf(countWhereFn, existsWhereFn) + orderableColumns.flatMap { extremeFns(it) }
+}
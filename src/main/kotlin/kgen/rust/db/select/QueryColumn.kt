@@ -4,6 +4,9 @@ import kgen.Id
 import kgen.db.DbColumn
 import kgen.db.DbType
 import kgen.doubleQuote
+import kgen.rust.db.asRustType
+import kgen.rust.db.jsonPayloadType
+import kgen.rust.db.variant
 
 /**
  * Represents a queryable column in Rust, with support for database column mapping,
@@ -90,9 +93,9 @@ sealed class QueryColumn {
     }
 
     /**
-     * Returns the Rust type associated with this query column.
+     * Returns the Rust type associated with this query column, the payload type of a typed json column.
      */
-    val rustType get() = type.asRustType
+    val rustType get() = dbColumn?.asRustType ?: type.asRustType
 
     /**
      * Returns the name of the Rust type associated with this query column.
@@ -109,12 +112,27 @@ sealed class QueryColumn {
 
     /**
      * Generates Rust code for reading the column value from a query result row.
+     * Uses `try_get` so a decode failure (e.g. column type mismatch) is propagated
+     * as an error rather than a panic. A typed json payload is decoded through `Json`
+     * and unwrapped.
      *
      * @param fromVar The variable representing the query result row.
      * @param columnIndex The index of the column in the row.
+     * @param isBinaryCopyRow If true the row is a `BinaryCopyOutRow`, whose `try_get` takes only
+     * the value type, rather than a `tokio_postgres::Row` also taking the index type.
      * @return The Rust code for accessing the column value.
      */
-    fun columnReadAccess(fromVar: String, columnIndex: Int) = "${fromVar}.get($columnIndex)"
+    fun columnReadAccess(fromVar: String, columnIndex: Int, isBinaryCopyRow: Boolean = false): String {
+        val indexTypeArg = if (isBinaryCopyRow) "" else "_, "
+        return when (val payloadType = dbColumn?.jsonPayloadType) {
+            null -> "${fromVar}.try_get($columnIndex)?"
+            else -> if (type.isNullable) {
+                "${fromVar}.try_get::<${indexTypeArg}Option<Json<$payloadType>>>($columnIndex)?.map(|json| json.0)"
+            } else {
+                "${fromVar}.try_get::<${indexTypeArg}Json<$payloadType>>($columnIndex)?.0"
+            }
+        }
+    }
 
     /**
      * Generates Rust code for reading the value of this query column.
@@ -125,43 +143,85 @@ sealed class QueryColumn {
     fun readAccess(fromVar: String) = "${fromVar}.${id.snake}"
 
     /**
-     * Generates a Rust literal representation of the given value for this column's type.
+     * Generates a Rust literal representation of the given sample value for this column's type.
+     *
+     * A typed json payload has no literal of its own, so samples are its default.
      *
-     * @param value The value to be represented as a Rust literal.
+     * @param sample The value to be represented as a Rust literal, a list of element values for arrays.
      * @return The Rust literal for the value, formatted based on the column's type.
      * @throws Exception If the type is unsupported for Rust literal representation.
      */
-    fun asRustLiteral(value: String): String = when (this.type) {
+    fun asRustLiteral(sample: Any): String = when {
+        dbColumn?.jsonPayload == null -> type.asRustLiteral(sample)
+        type.isNullable -> "Some(Default::default())"
+        else -> "Default::default()"
+    }
+}
+
+private fun DbType.asRustLiteral(sample: Any): String {
+    val value = sample.toString()
+    return when (val type = this) {
         is DbType.Byte, is DbType.Double, is DbType.Integer, is DbType.SmallInteger, is DbType.BigInteger -> value
         is DbType.Text -> "${doubleQuote(value)}.into()"
-        is DbType.Date -> "chrono::NaiveDate::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%d\").unwrap()"
-        is DbType.DateTime, is DbType.Timestamp ->
-            "chrono::NaiveDateTime::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%dT%H:%M\").unwrap()"
-
-        is DbType.IntegerAutoInc, is DbType.LongAutoInc, is DbType.UlongAutoInc -> value
-        is DbType.Binary, is DbType.BinarySized -> value
+        is DbType.Date -> dateLiteral(value)
+        is DbType.DateTime -> dateTimeLiteral(value)
+        is DbType.Timestamp -> "${dateTimeLiteral(value)}.and_utc()"
+        is DbType.Time -> timeLiteral(value)
+        is DbType.Interval -> intervalLiteral(value)
+        is DbType.Numeric -> numericLiteral(value)
+
+        is DbType.IntegerAutoInc, is DbType.LongAutoInc -> value
+        is DbType.UnsignedBigInteger, is DbType.UlongAutoInc -> unsignedLiteral(value)
+        is DbType.Binary, is DbType.BinarySized, is DbType.Blob -> "vec![$value]"
         is DbType.Bool -> value
         is DbType.Uuid -> "uuid::Uuid::parse_str(\"$value\").unwrap()"
-        is DbType.Json, is DbType.VarChar -> "${doubleQuote(value)}.into()"
+        is DbType.Json, is DbType.JsonBinary, is DbType.VarChar -> "${doubleQuote(value)}.into()"
         is DbType.NullableByte, is DbType.NullableDouble, is DbType.NullableInteger,
-        is DbType.NullableSmallInteger, is DbType.NullableBigInteger, is DbType.NullableBinary,
-        is DbType.NullableBinarySized -> "Some($value)"
+        is DbType.NullableSmallInteger, is DbType.NullableBigInteger -> "Some($value)"
 
+        is DbType.NullableBinary, is DbType.NullableBinarySized, is DbType.NullableBlob -> "Some(vec![$value])"
         is DbType.NullableBool -> "Some($value)"
-        is DbType.NullableText, is DbType.NullableJson, is DbType.NullableVarChar ->
+        is DbType.NullableText, is DbType.NullableJson, is DbType.NullableJsonBinary, is DbType.NullableVarChar ->
             "Some(${doubleQuote(value)}.into())"
 
-        is DbType.NullableDate ->
-            "Some(chrono::NaiveDate::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%d\").unwrap())"
-
-        is DbType.NullableDateTime, is DbType.NullableTimestamp ->
-            "Some(chrono::NaiveDateTime::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%dT%H:%M\").unwrap())"
+        is DbType.NullableDate -> "Some(${dateLiteral(value)})"
+        is DbType.NullableDateTime -> "Some(${dateTimeLiteral(value)})"
+        is DbType.NullableTimestamp -> "Some(${dateTimeLiteral(value)}.and_utc())"
+        is DbType.NullableTime -> "Some(${timeLiteral(value)})"
+        is DbType.NullableInterval -> "Some(${intervalLiteral(value)})"
+        is DbType.NullableNumeric -> "Some(${numericLiteral(value)})"
 
+        is DbType.NullableUnsignedBigInteger -> "Some(${unsignedLiteral(value)})"
         is DbType.NullableUuid -> "Some(uuid::Uuid::parse_str(\"$value\").unwrap())"
+        is DbType.PgEnum -> type.variant(value)
+        is DbType.NullablePgEnum -> "Some(${DbType.PgEnum(type.typeName, type.values).variant(value)})"
+        is DbType.Array -> arrayLiteral(type.elementType, sample)
+        is DbType.NullableArray -> "Some(${arrayLiteral(type.elementType, sample)})"
         else -> throw (Exception("Unsupported rust type for $this"))
     }
 }
 
+/** Sample arrays are lists of element samples */
+private fun arrayLiteral(elementType: DbType, sample: Any) =
+    "vec![${(sample as List<*>).joinToString(", ") { elementType.asRustLiteral(it!!) }}]"
+
+private fun dateLiteral(value: String) =
+    "chrono::NaiveDate::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%d\").unwrap()"
+
+private fun dateTimeLiteral(value: String) =
+    "chrono::NaiveDateTime::parse_from_str(${doubleQuote(value)}, \"%Y-%m-%dT%H:%M\").unwrap()"
+
+private fun timeLiteral(value: String) =
+    "chrono::NaiveTime::parse_from_str(${doubleQuote(value)}, \"%H:%M\").unwrap()"
+
+/** Sample intervals are a number of days */
+private fun intervalLiteral(value: String) =
+    "${DbType.intervalRustType.asRustName}(pg_interval::Interval::new(0, $value, 0))"
+
+private fun unsignedLiteral(value: String) = "${DbType.unsignedRustType.asRustName}($value)"
+
+private fun numericLiteral(value: String) = "rust_decimal::Decimal::from_str_exact(${doubleQuote(value)}).unwrap()"
+
 /**
  * Extension property to convert a [DbColumn] to a [QueryColumn].
  */
@@ -53,7 +53,8 @@ data class QueryColumnSet(
      * - Fields corresponding to the query columns.
      * - A constant for the number of fields (`NUM_FIELDS`).
      * - A constant for the names of the fields (`FIELD_NAMES`).
-     * - Common traits derived, such as `Default`, `Eq`, `PartialEq`, and `Hash`.
+     * - Common traits derived, such as `Default`, `Eq`, `PartialEq`, and `Hash` - sans `Eq` and `Hash`
+     *   with any float or json payload field.
      *
      * @return A [Struct] instance representing the Rust struct.
      */
@@ -76,7 +77,9 @@ data class QueryColumnSet(
                         .asConstValue
                 )
             ),
-            attrs = commonDerives + if (queryColumns.any { it.rustType in listOf(F64, "Option<f64>".asType) }) {
+            attrs = commonDerives + if (queryColumns.any {
+                    it.rustType in listOf(F64, "Option<f64>".asType) || it.dbColumn?.jsonPayload != null
+                }) {
                 derive("Default", "PartialEq")
             } else {
                 derive("Default", "Eq", "PartialEq", "Hash")
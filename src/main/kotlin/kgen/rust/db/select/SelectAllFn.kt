@@ -37,11 +37,11 @@ data class SelectAllFn(
      * This function is generated to retrieve all rows from the specified table. It internally
      * calls the `select_all_where` function with a default `WHERE` clause of `1=1` to include all rows.
      *
-     * @return A vector containing all rows from the table.
+     * @return A vector containing all rows from the table or the `tokio_postgres::Error`.
      *
      * Example Rust function output:
      * ```
-     * async fn select_all(client: &impl GenericClient) -> Vec<RowType> {
+     * async fn select_all(client: &impl GenericClient) -> Result<Vec<RowType>, tokio_postgres::Error> {
      *     Self::select_all_where(client, "1=1", &[]).await
      * }
      * ```
@@ -55,8 +55,8 @@ data class SelectAllFn(
             isAsync = true,
             hasUnitTest = false,
             body = FnBody("""Self::select_all_where(client, "1=1", &[]).await"""),
-            returnType = "Vec<$returnType>".asType,
-            returnDoc = "Selected rows",
+            returnType = "Result<Vec<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "Selected rows or the `tokio_postgres::Error`",
             inlineDecl = InlineDecl.Inline,
             testFnAttrs = attrSerializeTest.asAttrList
         )
@@ -6,6 +6,7 @@ import kgen.rust.db.TableGateway
 import kgen.rust.db.clientFnParam
 import kgen.rust.db.formattedColumnNames
 import kgen.rust.db.genericClientParamSet
+import kgen.rust.db.notDeletedPredicate
 import kgen.rustQuote
 
 /**
@@ -40,15 +41,22 @@ data class SelectAllWhereFn(
 
     /**
      * The SQL `SELECT` statement template for retrieving rows from the table, with a placeholder for the `WHERE` clause.
+     * Any soft deleted rows are skipped.
      */
     val selectStatement
-        get() = rustQuote(
-            """SELECT 
+        get() = selectStatementWhere(liveWhereClause)
+
+    /** The `WHERE` clause placeholder, guarded to skip any soft deleted rows */
+    val liveWhereClause = table.notDeletedPredicate?.let { "$it AND ({where_clause})" } ?: "{where_clause}"
+
+    /** The SQL `SELECT` statement template retrieving rows matching [wherePredicate] */
+    private fun selectStatementWhere(wherePredicate: String) = rustQuote(
+        """SELECT 
 ${table.formattedColumnNames}
 FROM ${table.tableName}
-WHERE {where_clause}
+WHERE $wherePredicate
             """.trimMargin()
-        )
+    )
 
     val rustSqlStatement = RustSqlStatement(
         "select_where_statement",
@@ -57,6 +65,18 @@ WHERE {where_clause}
         true
     )
 
+    /** The statement including soft deleted rows, only for tables with soft delete */
+    val rustIncludingDeletedStatement = if (table.isSoftDelete) {
+        RustSqlStatement(
+            "select_where_statement",
+            selectStatementWhere("{where_clause}"),
+            tableGateway.backdoorTableId,
+            true
+        )
+    } else {
+        null
+    }
+
     /**
      * The return type for the query results, typically a struct representing a row in the table.
      */
@@ -74,44 +94,81 @@ WHERE {where_clause}
     /**
      * The field assignment for the auto-increment column, if it exists.
      */
-    val autoIncFieldAssignment = if (autoIncQueryColumn != null) {
-        "${autoIncQueryColumn.id.snake}: ${autoIncQueryColumn.columnReadAccess("row", 0)}"
-    } else {
-        null
+    private fun autoIncFieldAssignment(isBinaryCopyRow: Boolean) = autoIncQueryColumn?.let {
+        "${it.id.snake}: ${it.columnReadAccess("row", 0, isBinaryCopyRow)}"
     }
 
     /**
      * The field assignments for all query columns, used to construct the data structure representing a row.
      */
-    val fieldAssignments = listOf(
+    private fun fieldAssignments(isBinaryCopyRow: Boolean) = listOf(
         "${tableGateway.rowDataStruct.structName} {",
         tableGateway.dataQueryColumns.queryColumns.withIndex().joinToString(",\n") { (i, queryColumn) ->
-            "${queryColumn.id.snake}: ${queryColumn.columnReadAccess("row", i + firstDataOffset)}"
+            "${queryColumn.id.snake}: ${queryColumn.columnReadAccess("row", i + firstDataOffset, isBinaryCopyRow)}"
         },
         "}"
     ).joinToString("\n")
 
     /**
-     * The `push` statement for adding a row to the results vector, including handling of the auto-increment column if present.
+     * The expression constructing the returned type from `row`, including handling of the auto-increment column if present.
+     *
+     * @param isBinaryCopyRow If true `row` is a `BinaryCopyOutRow` rather than a `tokio_postgres::Row`
      */
-    val pushStatement = if (autoIncQueryColumn != null) {
-        """results.push($returnType { $autoIncFieldAssignment, data: $fieldAssignments });"""
+    fun rowConstruction(isBinaryCopyRow: Boolean = false) = if (autoIncQueryColumn != null) {
+        """$returnType { ${autoIncFieldAssignment(isBinaryCopyRow)}, data: ${fieldAssignments(isBinaryCopyRow)} }"""
     } else {
-        """results.push($fieldAssignments);"""
+        fieldAssignments(isBinaryCopyRow)
     }
 
+    /**
+     * The generated Rust function decoding a selected row, shared by the select functions.
+     * Decode failures are returned as `tokio_postgres::Error`.
+     */
+    val fromRowFn
+        get() = Fn(
+            "from_row",
+            "Decode a row of `${id.snake}` selected with all columns in table order",
+            FnParam("row", "&tokio_postgres::Row".asType, "The selected row"),
+            returnType = "Result<$returnType, tokio_postgres::Error>".asType,
+            returnDoc = "The decoded row or the `tokio_postgres::Error`",
+            hasUnitTest = false,
+            body = FnBody("Ok(${rowConstruction()})")
+        )
+
     /**
      * The generated Rust function for executing the "SELECT * FROM ..." query with a `WHERE` clause.
      *
      * This function formats the `SELECT` statement, executes it on the provided database client,
-     * retrieves the rows, and converts them into the appropriate data structure.
+     * retrieves the rows, and converts them into the appropriate data structure. Query and
+     * row decode failures are returned as `tokio_postgres::Error` rather than panicking.
      *
-     * @return A vector containing the selected rows.
+     * @return A vector containing the selected rows or the `tokio_postgres::Error`.
      */
     val selectAllWhereFn
-        get() = Fn(
+        get() = selectWhereFn(
             "select_all_where",
-            "Select rows of `${id.snake}` with provided where clause",
+            if (table.isSoftDelete) {
+                "Select rows of `${id.snake}` with provided where clause, skipping soft deleted rows"
+            } else {
+                "Select rows of `${id.snake}` with provided where clause"
+            },
+            rustSqlStatement
+        )
+
+    /** Select including the soft deleted rows, only for tables with soft delete */
+    val selectAllWhereIncludingDeletedFn
+        get() = rustIncludingDeletedStatement?.let {
+            selectWhereFn(
+                "select_all_where_including_deleted",
+                "Select rows of `${id.snake}` with provided where clause, including soft deleted rows",
+                it
+            )
+        }
+
+    private fun selectWhereFn(nameId: String, doc: String, statement: RustSqlStatement) =
+        Fn(
+            nameId,
+            doc,
             clientFnParam,
             FnParam("where_clause", "&str".asType, "The where clause (sans `where` keyword)"),
             FnParam("params", "&[&(dyn ToSql + Sync)]".asType, "Any clause parameters"),
@@ -121,26 +178,27 @@ WHERE {where_clause}
             body = FnBody(
                 listOf(
                     """
-${rustSqlStatement.letStatement}                        
-let rows = match client.query(${rustSqlStatement.asStr}, params).await {
-    Ok(stmt) => stmt,
-    Err(e) => {
-        panic!("Error preparing statement: {e}");
+${statement.letStatement}                        
+let rows = match client.query(${statement.asStr}, params).await {
+    Ok(rows) => rows,
+    Err(err) => {
+        tracing::error!("Failed $nameId `${table.nameId}` -> {err}");
+        return Err(err);
     }
 };
 
 let mut results = Vec::<${returnType}>::with_capacity(rows.len());
 
 for row in rows {
-    $pushStatement
+    results.push(Self::from_row(&row)?);
     tracing::trace!("{:?}", results.last().unwrap());
 }
-results
+Ok(results)
                     """.trimMargin(),
                 ).joinToString("\n")
             ),
-            returnType = "Vec<$returnType>".asType,
-            returnDoc = "Selected rows",
+            returnType = "Result<Vec<$returnType>, tokio_postgres::Error>".asType,
+            returnDoc = "Selected rows or the `tokio_postgres::Error`",
             testFnAttrs = attrSerializeTest.asAttrList
         )
 }
@@ -0,0 +1,174 @@
+package kgen.rust.db.select
+
+import kgen.rust.*
+import kgen.rust.db.*
+import kgen.rustQuote
+
+/**
+ * Generates the Rust functions selecting the rows of a table a page at a time, ordered by the
+ * primary key, or by the _auto id_ if the table has no primary key.
+ *
+ * - `select_page_after` pages by keyset, selecting the rows after the key of the last row of the
+ *   previous page, so each page is an index range scan however deep.
+ * - `select_page_at_offset` pages by `LIMIT`/`OFFSET`, for callers needing to jump to a page.
+ *
+ * Both return a `Page` with the cursor to the next page, which is `None` once a page comes back
+ * short. A full last page is followed by an empty page with no cursor. Neither is generated for
+ * keyless tables, having no order to page in.
+ *
+ * @property selectAllWhere The select function providing the row decoding and return type.
+ */
+data class SelectPageFn(
+    val selectAllWhere: SelectAllWhereFn,
+) {
+
+    /** The table gateway */
+    val tableGateway get() = selectAllWhere.tableGateway
+
+    /** The identifier for the table gateway. */
+    val id = selectAllWhere.id
+
+    /** The database table associated with the query. */
+    val table = selectAllWhere.table
+
+    /** The return type for each row of a page */
+    val returnType = selectAllWhere.returnType
+
+    /** The columns ordering the pages - the primary key, else the _auto id_ */
+    val orderColumns = table.primaryKeyColumns.ifEmpty { listOfNotNull(table.autoIncColumn) }
+
+    /** True if the rows of the table have an order to page in */
+    val isPageable = orderColumns.isNotEmpty()
+
+    /** The type of the keyset cursor - the primary key struct, else the _auto id_ */
+    val cursorType = tableGateway.keyStruct?.structName ?: table.autoIncColumn?.asRustType?.asRustName
+
+    /** Expression for the keyset cursor of `last`, the last row of the page */
+    private val lastCursor
+        get() = when {
+            tableGateway.keyStruct == null -> "last.${tableGateway.autoIncQueryColumn!!.id.snake}"
+            tableGateway.hasAutoId && table.primaryKeyColumns.none { it.isAutoIncrement } -> "last.data.pkey()"
+            else -> "last.pkey()"
+        }
+
+    /** The cursor fields as sql parameters */
+    private val cursorParams
+        get() = if (tableGateway.keyStruct == null) {
+            "key"
+        } else {
+            orderColumns.joinToString(", ") { "&key.${it.nameId}" }
+        }
+
+    /** The columns ordering the pages as a comma separated list */
+    private val orderBy = orderColumns.joinToString(", ") { it.asSqlLiteral }
+
+    /** The select of the rows matching [predicates], in page order, limited by parameter [limitParam] */
+    private fun pageStatement(varName: String, predicates: List<String>, limitParam: Int, suffix: String = "") =
+        RustSqlStatement(
+            varName,
+            rustQuote(
+                listOfNotNull(
+                    "SELECT",
+                    table.formattedColumnNames,
+                    "FROM ${table.tableName}",
+                    predicates.takeIf { it.isNotEmpty() }?.let { "WHERE ${it.joinToString(" AND ")}" },
+                    "ORDER BY $orderBy",
+                    "LIMIT ${'$'}$limitParam$suffix"
+                ).joinToString("\n")
+            ),
+            tableGateway.backdoorTableId
+        )
+
+    /** Select of the first page */
+    val rustFirstPageStatement = pageStatement("select_statement", listOfNotNull(table.notDeletedPredicate), 1)
+
+    /** Select of the page after the cursor */
+    val rustPageAfterStatement = pageStatement(
+        "select_statement",
+        listOfNotNull(
+            table.notDeletedPredicate,
+            "($orderBy) > (${orderColumns.indices.joinToString(", ") { "${'$'}${it + 1}" }})"
+        ),
+        orderColumns.size + 1
+    )
+
+    /** Select of the page at an offset */
+    val rustPageAtOffsetStatement = pageStatement(
+        "select_statement",
+        listOfNotNull(table.notDeletedPredicate),
+        1,
+        " OFFSET ${'$'}2"
+    )
+
+    private val limitFnParam = FnParam("limit", "i64".asType, "Maximum number of rows in the page")
+
+    /** Statements decoding the selected `rows` into the page with cursor `next` */
+    private fun pageConstruction(next: String) = """
+let rows = rows.iter().map(Self::from_row).collect::<Result<Vec<_>, _>>()?;
+let next = if rows.len() as i64 == limit { $next } else { None };
+tracing::debug!("Selected page of size({}) in `${table.nameId}`", rows.len());
+Ok(Page { rows, next })
+    """.trimIndent()
+
+    val selectPageAfterFn
+        get() = if (isPageable) {
+            Fn(
+                "select_page_after",
+                """Select the page of rows of `${id.snake}` following `after` in the order of [$cursorType].
+                    |Pass `None` for the first page, then the `next` cursor of each page for the page following.
+                """.trimMargin(),
+                clientFnParam,
+                FnParam("after", "Option<&$cursorType>".asType, "Key of the last row of the previous page"),
+                limitFnParam,
+                genericParamSet = genericClientParamSet,
+                isAsync = true,
+                hasUnitTest = false,
+                returnType = "Result<Page<$returnType, $cursorType>, tokio_postgres::Error>".asType,
+                returnDoc = "The page with the key of its last row as cursor, if full, or the `tokio_postgres::Error`",
+                body = FnBody(
+                    """
+let rows = match after {
+    None => {
+        ${rustFirstPageStatement.letStatement}
+        client.query(${rustFirstPageStatement.asStr}, &[&limit]).await?
+    }
+    Some(key) => {
+        ${rustPageAfterStatement.letStatement}
+        client.query(${rustPageAfterStatement.asStr}, &[$cursorParams, &limit]).await?
+    }
+};
+${pageConstruction("rows.last().map(|last| $lastCursor)")}
+                    """.trimIndent()
+                )
+            )
+        } else {
+            null
+        }
+
+    val selectPageAtOffsetFn
+        get() = if (isPageable) {
+            Fn(
+                "select_page_at_offset",
+                """Select the page of rows of `${id.snake}` at `offset` in the order of [$cursorType].
+                    |Deep offsets scan the rows skipped, so prefer `select_page_after` to page through a table.
+                """.trimMargin(),
+                clientFnParam,
+                FnParam("offset", "i64".asType, "Number of rows preceding the page"),
+                limitFnParam,
+                genericParamSet = genericClientParamSet,
+                isAsync = true,
+                hasUnitTest = false,
+                returnType = "Result<Page<$returnType, i64>, tokio_postgres::Error>".asType,
+                returnDoc = "The page with the offset of the page following as cursor, if full, or the `tokio_postgres::Error`",
+                body = FnBody(
+                    """
+${rustPageAtOffsetStatement.letStatement}
+let rows = client.query(${rustPageAtOffsetStatement.asStr}, &[&limit, &offset]).await?;
+${pageConstruction("Some(offset + limit)")}
+                    """.trimIndent()
+                )
+            )
+        } else {
+            null
+        }
+}
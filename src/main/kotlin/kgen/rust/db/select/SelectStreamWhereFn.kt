@@ -0,0 +1,111 @@
+package kgen.rust.db.select
+
+import kgen.rust.*
+import kgen.rust.db.TableGateway
+import kgen.rust.db.clientFnParam
+import kgen.rust.db.genericClientParamSet
+
+/**
+ * Generates the Rust functions selecting rows with a `WHERE` clause without collecting the full
+ * result set in memory, for tables too large for `select_all_where`.
+ *
+ * - `select_stream_where` streams rows decoded one at a time from `query_raw`.
+ * - `select_chunks_where` binds a portal (cursor) in a transaction and streams chunks of rows.
+ *
+ * Both share the statement and row decoding (`from_row`) of [SelectAllWhereFn].
+ *
+ * @property selectAllWhere The select function providing the statement and return type.
+ */
+data class SelectStreamWhereFn(
+    val selectAllWhere: SelectAllWhereFn,
+) {
+
+    /** The identifier for the table gateway. */
+    val id = selectAllWhere.id
+
+    /** The database table associated with the query. */
+    val table = selectAllWhere.table
+
+    /** The statement shared with `select_all_where` */
+    val rustSqlStatement = selectAllWhere.rustSqlStatement
+
+    /** The return type for each row streamed */
+    val returnType = selectAllWhere.returnType
+
+    /**
+     * The generated Rust function streaming the rows matching the `WHERE` clause.
+     *
+     * Rows are decoded as they arrive from `query_raw`, so memory use is independent of the
+     * number of rows selected. Decode failures are yielded as `tokio_postgres::Error` items.
+     */
+    val selectStreamWhereFn
+        get() = Fn(
+            "select_stream_where",
+            """Select rows of `${id.snake}` with provided where clause as a stream of rows.
+                |The stream is not `Unpin`, so pin it (e.g. `std::pin::pin!`) to iterate with `next`.
+            """.trimMargin(),
+            clientFnParam,
+            FnParam("where_clause", "&str".asType, "The where clause (sans `where` keyword)"),
+            FnParam("params", "&[&(dyn ToSql + Sync)]".asType, "Any clause parameters"),
+            genericParamSet = genericClientParamSet,
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                """
+${rustSqlStatement.letStatement}
+let rows = match client.query_raw(${rustSqlStatement.asStr}, params.iter().copied()).await {
+    Ok(rows) => rows,
+    Err(err) => {
+        tracing::error!("Failed select_stream_where `${table.nameId}` -> {err}");
+        return Err(err);
+    }
+};
+Ok(rows.map(|row| row.and_then(|row| Self::from_row(&row))))
+                """.trimIndent()
+            ),
+            returnType = "Result<impl futures::Stream<Item = Result<$returnType, tokio_postgres::Error>>, tokio_postgres::Error>".asType,
+            returnDoc = "Stream of selected rows or the `tokio_postgres::Error`",
+        )
+
+    /**
+     * The generated Rust function streaming chunks of the rows matching the `WHERE` clause
+     * through a portal, which postgres only supports inside a transaction.
+     */
+    val selectChunksWhereFn
+        get() = Fn(
+            "select_chunks_where",
+            """Select rows of `${id.snake}` with provided where clause as a stream of chunks,
+                |fetched through a portal (cursor) bound in `transaction`.
+            """.trimMargin(),
+            FnParam("transaction", "&'a tokio_postgres::Transaction<'a>".asType, "The transaction binding the portal"),
+            FnParam("where_clause", "&str".asType, "The where clause (sans `where` keyword)"),
+            FnParam("params", "&[&(dyn ToSql + Sync)]".asType, "Any clause parameters"),
+            FnParam("chunk_size", "i32".asType, "Maximum number of rows fetched per chunk"),
+            genericParamSet = tickA,
+            isAsync = true,
+            hasUnitTest = false,
+            body = FnBody(
+                """
+${rustSqlStatement.letStatement}
+let portal = match transaction.bind(${rustSqlStatement.asStr}, params).await {
+    Ok(portal) => portal,
+    Err(err) => {
+        tracing::error!("Failed select_chunks_where `${table.nameId}` -> {err}");
+        return Err(err);
+    }
+};
+Ok(futures::stream::try_unfold(portal, move |portal| async move {
+    let rows = transaction.query_portal(&portal, chunk_size).await?;
+    if rows.is_empty() {
+        Ok(None)
+    } else {
+        let chunk = rows.iter().map(Self::from_row).collect::<Result<Vec<_>, _>>()?;
+        Ok(Some((chunk, portal)))
+    }
+}))
+                """.trimIndent()
+            ),
+            returnType = "Result<impl futures::Stream<Item = Result<Vec<$returnType>, tokio_postgres::Error>> + 'a, tokio_postgres::Error>".asType,
+            returnDoc = "Stream of chunks of selected rows or the `tokio_postgres::Error`",
+        )
+}
@@ -143,7 +143,7 @@ data class CrateGenerator(
         val shouldAnnounce = announceUpdates && !srcPathExists
         val targetSrcPath: Path = getTargetPath(srcPathExists)
         val targetBinPath = targetSrcPath.resolve("bin").toAbsolutePath()
-        targetSrcPath.resolve("../benches").toAbsolutePath()
+        val targetBenchesPath = targetSrcPath.resolve("../benches").toAbsolutePath()
         val targetIntegrationTestsPath = targetSrcPath.resolve("../tests").toAbsolutePath()
         targetSrcPath.resolve("../examples").toAbsolutePath()
         val targetSrcPathString = targetSrcPath.pathString
@@ -152,6 +152,10 @@ data class CrateGenerator(
             generateTo(module, targetIntegrationTestsPath.pathString, announceUpdates, includeTypeSizes = false)
         }.flatten()
 
+        val benchResults = crate.benchModules.map { module ->
+            generateTo(module, targetBenchesPath.pathString, announceUpdates, includeTypeSizes = false)
+        }.flatten()
+
         val binaryModuleResults = crate.binaries.map { clapBinary ->
             val targetDir = if (clapBinary.inSeparateDirectory) {
                 targetBinPath.resolve(clapBinary.nameId)
@@ -173,7 +177,7 @@ data class CrateGenerator(
                 announceUpdates = shouldAnnounce,
                 crate.includeTypeSizes
             ) +
-                    binaryModuleResults + integrationTestsResults
+                    binaryModuleResults + integrationTestsResults + benchResults
 
         "cargo fmt".runSimpleCommand(targetSrcPath.toFile(), echoOutputs = true)
 
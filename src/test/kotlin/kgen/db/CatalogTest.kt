@@ -0,0 +1,63 @@
+package kgen.db
+
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Test
+import kotlin.test.assertFailsWith
+
+internal class CatalogTest {
+
+    private val catalog = Catalog(
+        columns = listOf(
+            CatalogColumn("sample_with_id", "auto_id", "int4", false, numericPrecision = 32, isAutoIncrement = true),
+            CatalogColumn("sample_with_id", "the_name", "varchar", false, characterMaximumLength = 255),
+            CatalogColumn("sample_with_id", "the_small_int", "int2", false),
+            CatalogColumn("sample_with_id", "the_numeric", "numeric", true, numericPrecision = 12, numericScale = 4),
+            CatalogColumn("sample_with_id", "the_color", "sample_color", false),
+            CatalogColumn("sample_with_id", "the_tags", "_text", true),
+            CatalogColumn("keyless", "theValue", "timestamptz", true),
+        ),
+        indices = listOf(
+            CatalogIndex("sample_with_id", "sample_with_id_pkey", true, listOf("auto_id")),
+            CatalogIndex("sample_with_id", "sample_with_id_name_small", false, listOf("the_name", "the_small_int")),
+        ),
+        enums = mapOf("sample_color" to listOf("red", "green", "blue"))
+    )
+
+    @Test
+    fun asDbTables() {
+        val (sampleWithId, keyless) = catalog.asDbTables
+        val columns = sampleWithId.columns.associateBy { it.nameId }
+
+        assertEquals("sample_with_id", sampleWithId.tableName)
+        assertEquals(
+            listOf(
+                DbType.IntegerAutoInc,
+                DbType.VarChar(255),
+                DbType.SmallInteger,
+                DbType.NullableNumeric(12, 4),
+                DbType.PgEnum("sample_color", listOf("red", "green", "blue")),
+                DbType.NullableArray(DbType.Text)
+            ),
+            sampleWithId.columns.map { it.type }
+        )
+        assertEquals(DbTableClassifier.AutoIdWithPkey, sampleWithId.classifier)
+        assertEquals(listOf(columns.getValue("auto_id")), sampleWithId.primaryKeyColumns)
+        assertEquals(
+            mapOf("sample_with_id_name_small" to listOf(columns.getValue("the_name"), columns.getValue("the_small_int"))),
+            sampleWithId.uniqueIndices
+        )
+
+        val theValue = keyless.columns.single()
+        assertEquals(DbTableClassifier.Keyless, keyless.classifier)
+        assertEquals("the_value", theValue.nameId)
+        assertEquals("theValue", theValue.columnName)
+        assertEquals(DbType.NullableTimestamp, theValue.type)
+    }
+
+    @Test
+    fun unsupportedType() {
+        assertFailsWith<Exception> {
+            Catalog(listOf(CatalogColumn("t", "c", "point", false))).asDbTables
+        }
+    }
+}
@@ -1,12 +1,18 @@
 package kgen.rust.db
 
+import kgen.db.ColumnRole
+import kgen.db.PgEnumColumnType
 import kgen.db.intoDbTable
+import kgen.db.pgEnum
 import kgen.meta.MetaPaths
+import kgen.missingDoc
 import kgen.rust.*
 import kgen.rust.generator.CrateGenerator
 import org.jetbrains.exposed.sql.*
 import org.jetbrains.exposed.sql.javatime.date
 import org.jetbrains.exposed.sql.javatime.datetime
+import org.jetbrains.exposed.sql.javatime.time
+import org.jetbrains.exposed.sql.javatime.timestampWithTimeZone
 import org.jetbrains.exposed.sql.transactions.transaction
 import org.postgresql.util.PGobject
 
@@ -31,6 +37,17 @@ class JsonBColumnType<T : Any> : ColumnType<T>() {
     }
 }
 
+class IntervalColumnType<T : Any> : ColumnType<T>() {
+    override fun sqlType(): String = "INTERVAL"
+    override fun valueFromDB(value: Any): T {
+        return when (value) {
+            is PGobject -> value.value as T
+            is String -> value as T
+            else -> throw IllegalArgumentException("Unexpected value type: ${value::class.java}")
+        }
+    }
+}
+
 class JsonColumnType<T : Any> : ColumnType<T>() {
     override fun sqlType(): String = "JSON"
     override fun valueFromDB(value: Any): T {
@@ -57,6 +74,8 @@ object TableSampleWithId : Table("sample_with_id") {
     val json = registerColumn<Any>("the_json", JsonColumnType())
     val jsonb = registerColumn<Any>("the_jsonb", JsonBColumnType())
 
+    val version = integer("the_version")
+
     val nullableName = varchar("nullable_name", 255).nullable()
     val nullableBoolean = bool("nullable_boolean").nullable()
     val nullableSmallInt = short("nullable_small_int").nullable()
@@ -77,6 +96,21 @@ object TableSampleWithId : Table("sample_with_id") {
     }
 }
 
+object TableSampleAutoId : Table("sample_auto_id") {
+    val id = long("auto_id").autoIncrement()
+    val name = varchar("the_name", 255)
+    val largeInt = long("the_large_int")
+    val date = date("the_date")
+    val nullableName = varchar("nullable_name", 255).nullable()
+
+    init {
+        uniqueIndex(name)
+    }
+}
+
+/** Labels of the `sample_color` postgres enum */
+val sampleColors = listOf("red", "green", "blue")
+
 object TableSample : Table("sample") {
     val name = varchar("the_name", 255)
     val smallInt = short("the_small_int")
@@ -89,6 +123,16 @@ object TableSample : Table("sample") {
     val ulong = ulong("the_ulong")
     val json = registerColumn<Any>("the_json", JsonColumnType())
     val jsonb = registerColumn<Any>("the_jsonb", JsonBColumnType())
+    val double = double("the_double")
+    val bytes = binary("the_bytes")
+    val numeric = decimal("the_numeric", 12, 4)
+    val time = time("the_time")
+    val timestamp = timestampWithTimeZone("the_timestamp")
+    val interval = registerColumn<Any>("the_interval", IntervalColumnType())
+    val color = pgEnum("the_color", "sample_color", sampleColors)
+    val tags = array<String>("the_tags")
+    val scores = array<Int>("the_scores")
+    val uuids = array<java.util.UUID>("the_uuids")
 
     val nullableName = varchar("nullable_name", 255).nullable()
     val nullableSmallInt = short("nullable_small_int").nullable()
@@ -101,6 +145,15 @@ object TableSample : Table("sample") {
     val nullableUlong = ulong("nullable_ulong").nullable()
     val nullableJson = registerColumn<Any>("nullable_json", JsonColumnType()).nullable()
     val nullableJsonb = registerColumn<Any>("nullable_jsonb", JsonBColumnType()).nullable()
+    val nullableDouble = double("nullable_double").nullable()
+    val nullableBytes = binary("nullable_bytes").nullable()
+    val nullableNumeric = decimal("nullable_numeric", 12, 4).nullable()
+    val nullableTime = time("nullable_time").nullable()
+    val nullableTimestamp = timestampWithTimeZone("nullable_timestamp").nullable()
+    val nullableInterval = registerColumn<Any>("nullable_interval", IntervalColumnType()).nullable()
+    val nullableColor = pgEnum("nullable_color", "sample_color", sampleColors).nullable()
+    val nullableTags = array<String>("nullable_tags").nullable()
+    val nullableScores = array<Int>("nullable_scores").nullable()
 
     override val primaryKey = PrimaryKey(name, smallInt)
 }
@@ -129,9 +182,31 @@ object TableKeyless : Table("keyless") {
     val nullableUlong = ulong("nullable_ulong").nullable()
     val nullableJson = registerColumn<Any>("nullable_json", JsonColumnType()).nullable()
     val nullableJsonb = registerColumn<Any>("nullable_jsonb", JsonBColumnType()).nullable()
+
+    val createdAt = datetime("created_at")
+    val updatedAt = datetime("updated_at")
+    val deletedAt = datetime("deleted_at").nullable()
 }
 
 
+/** Payload of the typed json columns of the sample tables */
+val samplePayloadModule = Module(
+    "payload",
+    "Serde payloads of the typed json columns",
+    structs = listOf(
+        Struct(
+            "sample_payload",
+            "Payload deserialized from a json column",
+            listOf(
+                Field("label", "A label", RustString),
+                Field("count", "A count", I64),
+                Field("tags", "Any tags", "Vec<String>".asType),
+            ),
+            attrs = derive("Debug", "Clone", "Default", "PartialEq", "serde::Serialize", "serde::Deserialize"),
+        )
+    )
+)
+
 fun main() {
     /*
     CREATE USER kgen WITH PASSWORD 'kgen';
@@ -143,15 +218,39 @@ fun main() {
     val tables = mapOf(
         TableKeyless to "Table with no primary key or auto id",
         TableSample to "Table with primary key",
-        TableSampleWithId to "Table with auto-id"
+        TableSampleWithId to "Table with auto-id",
+        TableSampleAutoId to "Table with auto-id and no primary key, paged by the auto-id"
+    )
+
+    val versionColumns = mapOf<Table, Column<*>>(TableSampleWithId to TableSampleWithId.version)
+
+    val columnRoles = mapOf<Table, Map<Column<*>, ColumnRole>>(
+        TableKeyless to mapOf(
+            TableKeyless.createdAt to ColumnRole.CreatedAt,
+            TableKeyless.updatedAt to ColumnRole.UpdatedAt,
+            TableKeyless.deletedAt to ColumnRole.DeletedAt,
+        )
+    )
+
+    val samplePayload = "crate::payload::SamplePayload"
+    val jsonPayloads = mapOf<Table, Map<Column<*>, String>>(
+        TableSample to mapOf(TableSample.json to samplePayload, TableSample.nullableJsonb to samplePayload),
+        TableSampleWithId to mapOf(TableSampleWithId.jsonb to samplePayload),
     )
 
     transaction {
         addLogger(StdOutSqlLogger)
+        val sampleColorType = TableSample.color.columnType as PgEnumColumnType
         tables.keys.forEach { table -> SchemaUtils.drop(table) }
+        exec(sampleColorType.dropTypeStatement)
+        exec(sampleColorType.createTypeStatement)
         tables.keys.forEach { table -> SchemaUtils.create(table) }
 
-        val dbTables = tables.entries.map { (table, doc) -> table.intoDbTable(doc) }
+        val dbTables = tables.entries.map { (table, doc) ->
+            table.intoDbTable(
+                doc, versionColumns[table], columnRoles[table] ?: emptyMap(), jsonPayloads[table] ?: emptyMap()
+            )
+        }
         val tableGateways = dbTables.map {
             if (it.nameId == "keyless") {
                 TableGateway(it, backdoorTable = true)
@@ -163,7 +262,8 @@ fun main() {
         val libModule = Module(
             "lib",
             moduleRootType = ModuleRootType.LibraryRoot,
-            modules = tableGateways.map { it.asModule },
+            modules = tableGateways.map { it.asModule } + filterModule + schemaModule + batchModule +
+                    versionModule + pageModule + unsignedModule + intervalModule + pgEnumModule(dbTables) + samplePayloadModule,
         )
 
         val targetPath = MetaPaths.tempPath.resolve("kgen_db")
@@ -171,6 +271,11 @@ fun main() {
             Crate(
                 "kgen_db",
                 rootModule = libModule,
+                cargoToml = CargoToml(
+                    "kgen_db",
+                    description = missingDoc("kgen_db", "Crate"),
+                    benches = tableGateways.map { it.preparedStatements.benchModule.nameId }
+                ),
                 integrationTestModules = listOf(
                     Module("db", "Pulls in the db tests", customModDecls = listOf(ModDecl("db_tests"))),
                     Module(
@@ -178,9 +283,10 @@ fun main() {
                         "Tests for generated db code",
                         ModuleType.Directory,
                         modules = tableGateways.map { it.testModule } +
-                                TableGateway.testSupportModule
+                                TableGateway.testSupportModule(dbTables.pgEnums)
                     )
-                )
+                ),
+                benchModules = tableGateways.map { it.preparedStatements.benchModule }
             ),
             targetPath.toString()
         )
@@ -0,0 +1,508 @@
+//! Bench of the gateway functions of `keyless` against their `_prepared` variants
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use deadpool_postgres::Config;
+use deadpool_postgres::Runtime;
+use kgen_db::keyless::*;
+use kgen_db::unsigned::U64;
+use std::time::Duration;
+use std::time::Instant;
+use tokio_postgres::GenericClient;
+use tokio_postgres::NoTls;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Iterations of each bench loop
+pub const ITERATIONS: u32 = 100;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Get a set of sample rows for testing
+///
+///   * _return_ - Set of sample rows to test CRUD methods
+pub fn get_sample_rows() -> Vec<KeylessRowData> {
+    vec![
+        KeylessRowData {
+            the_name: "a".into(),
+            the_small_int: -32768,
+            the_large_int: -2147483648,
+            the_big_int: U64(9223372036854774807),
+            the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483648,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-01-01T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
+            the_ulong: U64(9223372036854774807),
+            the_json: "{ value: 1 }".into(),
+            the_jsonb: "{ value: 1 }".into(),
+            nullable_name: Some("a".into()),
+            nullable_small_int: Some(-32768),
+            nullable_large_int: Some(-2147483648),
+            nullable_big_int: Some(U64(9223372036854774807)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483648),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774807)),
+            nullable_json: Some("{ value: 1 }".into()),
+            nullable_jsonb: Some("{ value: 1 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "b".into(),
+            the_small_int: -32767,
+            the_large_int: -2147483647,
+            the_big_int: U64(9223372036854774808),
+            the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483647,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-02-03T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+            the_ulong: U64(9223372036854774808),
+            the_json: "{ value: 2 }".into(),
+            the_jsonb: "{ value: 2 }".into(),
+            nullable_name: Some("b".into()),
+            nullable_small_int: Some(-32767),
+            nullable_large_int: Some(-2147483647),
+            nullable_big_int: Some(U64(9223372036854774808)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483647),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774808)),
+            nullable_json: Some("{ value: 2 }".into()),
+            nullable_jsonb: Some("{ value: 2 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "c".into(),
+            the_small_int: -32766,
+            the_large_int: -2147483646,
+            the_big_int: U64(9223372036854774809),
+            the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483646,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-03-05T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            the_ulong: U64(9223372036854774809),
+            the_json: "{ value: 3 }".into(),
+            the_jsonb: "{ value: 3 }".into(),
+            nullable_name: Some("c".into()),
+            nullable_small_int: Some(-32766),
+            nullable_large_int: Some(-2147483646),
+            nullable_big_int: Some(U64(9223372036854774809)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483646),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774809)),
+            nullable_json: Some("{ value: 3 }".into()),
+            nullable_jsonb: Some("{ value: 3 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "d".into(),
+            the_small_int: -32765,
+            the_large_int: -2147483645,
+            the_big_int: U64(9223372036854774810),
+            the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483645,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-04-07T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
+            the_ulong: U64(9223372036854774810),
+            the_json: "{ value: 4 }".into(),
+            the_jsonb: "{ value: 4 }".into(),
+            nullable_name: Some("d".into()),
+            nullable_small_int: Some(-32765),
+            nullable_large_int: Some(-2147483645),
+            nullable_big_int: Some(U64(9223372036854774810)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483645),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774810)),
+            nullable_json: Some("{ value: 4 }".into()),
+            nullable_jsonb: Some("{ value: 4 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "e".into(),
+            the_small_int: -32764,
+            the_large_int: -2147483644,
+            the_big_int: U64(9223372036854774811),
+            the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483644,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-05-09T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+            the_ulong: U64(9223372036854774811),
+            the_json: "{ value: 5 }".into(),
+            the_jsonb: "{ value: 5 }".into(),
+            nullable_name: Some("e".into()),
+            nullable_small_int: Some(-32764),
+            nullable_large_int: Some(-2147483644),
+            nullable_big_int: Some(U64(9223372036854774811)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483644),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774811)),
+            nullable_json: Some("{ value: 5 }".into()),
+            nullable_jsonb: Some("{ value: 5 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "f".into(),
+            the_small_int: -32763,
+            the_large_int: -2147483643,
+            the_big_int: U64(9223372036854774812),
+            the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483643,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-06-11T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            the_ulong: U64(9223372036854774812),
+            the_json: "{ value: 6 }".into(),
+            the_jsonb: "{ value: 6 }".into(),
+            nullable_name: Some("f".into()),
+            nullable_small_int: Some(-32763),
+            nullable_large_int: Some(-2147483643),
+            nullable_big_int: Some(U64(9223372036854774812)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483643),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774812)),
+            nullable_json: Some("{ value: 6 }".into()),
+            nullable_jsonb: Some("{ value: 6 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "g".into(),
+            the_small_int: -32762,
+            the_large_int: -2147483642,
+            the_big_int: U64(9223372036854774813),
+            the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483642,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-07-13T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
+            the_ulong: U64(9223372036854774813),
+            the_json: "{ value: 7 }".into(),
+            the_jsonb: "{ value: 7 }".into(),
+            nullable_name: Some("g".into()),
+            nullable_small_int: Some(-32762),
+            nullable_large_int: Some(-2147483642),
+            nullable_big_int: Some(U64(9223372036854774813)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483642),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774813)),
+            nullable_json: Some("{ value: 7 }".into()),
+            nullable_jsonb: Some("{ value: 7 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "h".into(),
+            the_small_int: -32761,
+            the_large_int: -2147483641,
+            the_big_int: U64(9223372036854774814),
+            the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483641,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-08-15T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+            the_ulong: U64(9223372036854774814),
+            the_json: "{ value: 8 }".into(),
+            the_jsonb: "{ value: 8 }".into(),
+            nullable_name: Some("h".into()),
+            nullable_small_int: Some(-32761),
+            nullable_large_int: Some(-2147483641),
+            nullable_big_int: Some(U64(9223372036854774814)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483641),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774814)),
+            nullable_json: Some("{ value: 8 }".into()),
+            nullable_jsonb: Some("{ value: 8 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "i".into(),
+            the_small_int: -32760,
+            the_large_int: -2147483640,
+            the_big_int: U64(9223372036854774815),
+            the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483640,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-09-17T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            the_ulong: U64(9223372036854774815),
+            the_json: "{ value: 9 }".into(),
+            the_jsonb: "{ value: 9 }".into(),
+            nullable_name: Some("i".into()),
+            nullable_small_int: Some(-32760),
+            nullable_large_int: Some(-2147483640),
+            nullable_big_int: Some(U64(9223372036854774815)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483640),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774815)),
+            nullable_json: Some("{ value: 9 }".into()),
+            nullable_jsonb: Some("{ value: 9 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+        KeylessRowData {
+            the_name: "j".into(),
+            the_small_int: -32759,
+            the_large_int: -2147483639,
+            the_big_int: U64(9223372036854774816),
+            the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483639,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-10-19T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
+            the_ulong: U64(9223372036854774816),
+            the_json: "{ value: 10 }".into(),
+            the_jsonb: "{ value: 10 }".into(),
+            nullable_name: Some("j".into()),
+            nullable_small_int: Some(-32759),
+            nullable_large_int: Some(-2147483639),
+            nullable_big_int: Some(U64(9223372036854774816)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483639),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774816)),
+            nullable_json: Some("{ value: 10 }".into()),
+            nullable_jsonb: Some("{ value: 10 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+        },
+    ]
+}
+
+/// Print the time per iteration of a bench loop
+///
+///   * **label** - The function timed
+///   * **elapsed** - Time of all iterations
+pub fn report(label: &str, elapsed: Duration) {
+    println!("keyless {label:<32} {:?}/iteration", elapsed / ITERATIONS);
+}
+
+/// Time the gateway functions of `keyless` sending the statement text against the prepared statements
+pub fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut cfg = Config::new();
+        cfg.dbname = Some("kgen".to_string());
+        cfg.user = Some("kgen".to_string());
+        cfg.password = Some("kgen".to_string());
+        let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+        let resource = pool.get().await.unwrap();
+        let client = resource.client();
+        TableKeyless::drop_table(client).await.unwrap();
+        TableKeyless::create_table(client).await.unwrap();
+        let samples = get_sample_rows();
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableKeyless::bulk_insert(client, &samples, 4)
+                .await
+                .unwrap();
+            TableKeyless::delete_all(client).await.unwrap();
+        }
+        report("bulk_insert", start.elapsed());
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableKeyless::bulk_insert_prepared(&resource, &samples, 4)
+                .await
+                .unwrap();
+            TableKeyless::delete_all_prepared(&resource).await.unwrap();
+        }
+        report("bulk_insert_prepared", start.elapsed());
+        TableKeyless::drop_table(client).await.unwrap();
+    });
+}
+
+// α <mod-def prepared_keyless>
+// ω <mod-def prepared_keyless>
@@ -0,0 +1,783 @@
+//! Bench of the gateway functions of `sample` against their `_prepared` variants
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use deadpool_postgres::Config;
+use deadpool_postgres::Runtime;
+use kgen_db::interval::Interval;
+use kgen_db::sample::*;
+use kgen_db::unsigned::U64;
+use std::time::Duration;
+use std::time::Instant;
+use tokio_postgres::GenericClient;
+use tokio_postgres::NoTls;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Iterations of each bench loop
+pub const ITERATIONS: u32 = 100;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Get a set of sample rows for testing
+///
+///   * _return_ - Set of sample rows to test CRUD methods
+pub fn get_sample_rows() -> Vec<SampleRowData> {
+    vec![
+        SampleRowData {
+            the_name: "a".into(),
+            the_small_int: -32768,
+            the_large_int: -2147483648,
+            the_big_int: U64(9223372036854774807),
+            the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483648,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-01-01T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
+            the_ulong: U64(9223372036854774807),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 1 }".into(),
+            the_double: 0.0,
+            the_bytes: vec![
+                26, 204, 239, 57, 64, 32, 191, 103, 202, 63, 171, 231, 220, 49, 71, 249,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("1.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("01:01", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-01-01T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 1, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("a".into()),
+            nullable_small_int: Some(-32768),
+            nullable_large_int: Some(-2147483648),
+            nullable_big_int: Some(U64(9223372036854774807)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483648),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774807)),
+            nullable_json: Some("{ value: 1 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(0.0),
+            nullable_bytes: Some(vec![
+                26, 204, 239, 57, 64, 32, 191, 103, 202, 63, 171, 231, 220, 49, 71, 249,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("1.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("01:01", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 1, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
+        },
+        SampleRowData {
+            the_name: "b".into(),
+            the_small_int: -32767,
+            the_large_int: -2147483647,
+            the_big_int: U64(9223372036854774808),
+            the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483647,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-02-03T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+            the_ulong: U64(9223372036854774808),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 2 }".into(),
+            the_double: 1.0,
+            the_bytes: vec![
+                32, 170, 189, 38, 66, 9, 114, 248, 216, 12, 72, 250, 123, 169, 0, 47,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("2.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("02:02", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-02-03T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 2, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["a".into()],
+            the_scores: vec![-2147483648],
+            the_uuids: vec![uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap()],
+            nullable_name: Some("b".into()),
+            nullable_small_int: Some(-32767),
+            nullable_large_int: Some(-2147483647),
+            nullable_big_int: Some(U64(9223372036854774808)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483647),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774808)),
+            nullable_json: Some("{ value: 2 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(1.0),
+            nullable_bytes: Some(vec![
+                32, 170, 189, 38, 66, 9, 114, 248, 216, 12, 72, 250, 123, 169, 0, 47,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("2.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("02:02", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 2, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["a".into()]),
+            nullable_scores: Some(vec![-2147483648]),
+        },
+        SampleRowData {
+            the_name: "c".into(),
+            the_small_int: -32766,
+            the_large_int: -2147483646,
+            the_big_int: U64(9223372036854774809),
+            the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483646,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-03-05T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            the_ulong: U64(9223372036854774809),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 3 }".into(),
+            the_double: 2.0,
+            the_bytes: vec![
+                25, 2, 182, 151, 198, 19, 45, 172, 223, 191, 90, 75, 31, 183, 157, 186,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("3.7500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("03:03", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-03-05T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 3, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["b".into(), "c".into()],
+            the_scores: vec![-2147483647, -2147483646],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+                uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            ],
+            nullable_name: Some("c".into()),
+            nullable_small_int: Some(-32766),
+            nullable_large_int: Some(-2147483646),
+            nullable_big_int: Some(U64(9223372036854774809)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483646),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774809)),
+            nullable_json: Some("{ value: 3 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(2.0),
+            nullable_bytes: Some(vec![
+                25, 2, 182, 151, 198, 19, 45, 172, 223, 191, 90, 75, 31, 183, 157, 186,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("3.7500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("03:03", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 3, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["b".into(), "c".into()]),
+            nullable_scores: Some(vec![-2147483647, -2147483646]),
+        },
+        SampleRowData {
+            the_name: "d".into(),
+            the_small_int: -32765,
+            the_large_int: -2147483645,
+            the_big_int: U64(9223372036854774810),
+            the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483645,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-04-07T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
+            the_ulong: U64(9223372036854774810),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 4 }".into(),
+            the_double: 3.0,
+            the_bytes: vec![
+                116, 59, 90, 241, 225, 242, 211, 151, 97, 36, 9, 29, 181, 25, 226, 21,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("5.0000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("04:04", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-04-07T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 4, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("d".into()),
+            nullable_small_int: Some(-32765),
+            nullable_large_int: Some(-2147483645),
+            nullable_big_int: Some(U64(9223372036854774810)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483645),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774810)),
+            nullable_json: Some("{ value: 4 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(3.0),
+            nullable_bytes: Some(vec![
+                116, 59, 90, 241, 225, 242, 211, 151, 97, 36, 9, 29, 181, 25, 226, 21,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("5.0000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("04:04", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 4, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
+        },
+        SampleRowData {
+            the_name: "e".into(),
+            the_small_int: -32764,
+            the_large_int: -2147483644,
+            the_big_int: U64(9223372036854774811),
+            the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483644,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-05-09T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+            the_ulong: U64(9223372036854774811),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 5 }".into(),
+            the_double: 4.0,
+            the_bytes: vec![
+                50, 102, 169, 162, 150, 248, 28, 123, 69, 255, 222, 205, 15, 114, 1, 73,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("6.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("05:05", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-05-09T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 5, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["d".into()],
+            the_scores: vec![-2147483645],
+            the_uuids: vec![uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap()],
+            nullable_name: Some("e".into()),
+            nullable_small_int: Some(-32764),
+            nullable_large_int: Some(-2147483644),
+            nullable_big_int: Some(U64(9223372036854774811)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483644),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774811)),
+            nullable_json: Some("{ value: 5 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(4.0),
+            nullable_bytes: Some(vec![
+                50, 102, 169, 162, 150, 248, 28, 123, 69, 255, 222, 205, 15, 114, 1, 73,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("6.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("05:05", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 5, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["d".into()]),
+            nullable_scores: Some(vec![-2147483645]),
+        },
+        SampleRowData {
+            the_name: "f".into(),
+            the_small_int: -32763,
+            the_large_int: -2147483643,
+            the_big_int: U64(9223372036854774812),
+            the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483643,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-06-11T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            the_ulong: U64(9223372036854774812),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 6 }".into(),
+            the_double: 5.0,
+            the_bytes: vec![
+                95, 35, 236, 123, 98, 85, 76, 81, 45, 194, 17, 179, 200, 13, 56, 93,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("7.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("06:06", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-06-11T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 6, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["e".into(), "f".into()],
+            the_scores: vec![-2147483644, -2147483643],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+                uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            ],
+            nullable_name: Some("f".into()),
+            nullable_small_int: Some(-32763),
+            nullable_large_int: Some(-2147483643),
+            nullable_big_int: Some(U64(9223372036854774812)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483643),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774812)),
+            nullable_json: Some("{ value: 6 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(5.0),
+            nullable_bytes: Some(vec![
+                95, 35, 236, 123, 98, 85, 76, 81, 45, 194, 17, 179, 200, 13, 56, 93,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("7.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("06:06", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 6, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["e".into(), "f".into()]),
+            nullable_scores: Some(vec![-2147483644, -2147483643]),
+        },
+        SampleRowData {
+            the_name: "g".into(),
+            the_small_int: -32762,
+            the_large_int: -2147483642,
+            the_big_int: U64(9223372036854774813),
+            the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483642,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-07-13T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
+            the_ulong: U64(9223372036854774813),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 7 }".into(),
+            the_double: 6.0,
+            the_bytes: vec![
+                80, 180, 149, 113, 18, 42, 146, 116, 126, 24, 126, 244, 177, 140, 66, 4,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("8.7500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("07:07", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-07-13T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 7, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("g".into()),
+            nullable_small_int: Some(-32762),
+            nullable_large_int: Some(-2147483642),
+            nullable_big_int: Some(U64(9223372036854774813)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483642),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774813)),
+            nullable_json: Some("{ value: 7 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(6.0),
+            nullable_bytes: Some(vec![
+                80, 180, 149, 113, 18, 42, 146, 116, 126, 24, 126, 244, 177, 140, 66, 4,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("8.7500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("07:07", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 7, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
+        },
+        SampleRowData {
+            the_name: "h".into(),
+            the_small_int: -32761,
+            the_large_int: -2147483641,
+            the_big_int: U64(9223372036854774814),
+            the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483641,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-08-15T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+            the_ulong: U64(9223372036854774814),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 8 }".into(),
+            the_double: 7.0,
+            the_bytes: vec![
+                48, 49, 97, 232, 119, 250, 155, 56, 109, 90, 219, 5, 232, 125, 108, 64,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("10.0000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("08:08", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-08-15T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 8, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["g".into()],
+            the_scores: vec![-2147483642],
+            the_uuids: vec![uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap()],
+            nullable_name: Some("h".into()),
+            nullable_small_int: Some(-32761),
+            nullable_large_int: Some(-2147483641),
+            nullable_big_int: Some(U64(9223372036854774814)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483641),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774814)),
+            nullable_json: Some("{ value: 8 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(7.0),
+            nullable_bytes: Some(vec![
+                48, 49, 97, 232, 119, 250, 155, 56, 109, 90, 219, 5, 232, 125, 108, 64,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("10.0000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("08:08", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 8, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["g".into()]),
+            nullable_scores: Some(vec![-2147483642]),
+        },
+        SampleRowData {
+            the_name: "i".into(),
+            the_small_int: -32760,
+            the_large_int: -2147483640,
+            the_big_int: U64(9223372036854774815),
+            the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483640,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-09-17T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            the_ulong: U64(9223372036854774815),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 9 }".into(),
+            the_double: 8.0,
+            the_bytes: vec![
+                56, 90, 204, 130, 58, 22, 58, 199, 235, 131, 29, 146, 156, 11, 53, 36,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("11.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("09:09", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-09-17T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 9, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["h".into(), "i".into()],
+            the_scores: vec![-2147483641, -2147483640],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+                uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            ],
+            nullable_name: Some("i".into()),
+            nullable_small_int: Some(-32760),
+            nullable_large_int: Some(-2147483640),
+            nullable_big_int: Some(U64(9223372036854774815)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483640),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774815)),
+            nullable_json: Some("{ value: 9 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(8.0),
+            nullable_bytes: Some(vec![
+                56, 90, 204, 130, 58, 22, 58, 199, 235, 131, 29, 146, 156, 11, 53, 36,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("11.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("09:09", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 9, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["h".into(), "i".into()]),
+            nullable_scores: Some(vec![-2147483641, -2147483640]),
+        },
+        SampleRowData {
+            the_name: "j".into(),
+            the_small_int: -32759,
+            the_large_int: -2147483639,
+            the_big_int: U64(9223372036854774816),
+            the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            the_general_int: -2147483639,
+            the_date_time: chrono::NaiveDateTime::parse_from_str(
+                "2000-10-19T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap(),
+            the_uuid: uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
+            the_ulong: U64(9223372036854774816),
+            the_json: Default::default(),
+            the_jsonb: "{ value: 10 }".into(),
+            the_double: 9.0,
+            the_bytes: vec![
+                254, 130, 37, 250, 73, 83, 88, 152, 29, 252, 27, 65, 210, 245, 25, 177,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("12.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("10:10", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-10-19T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 10, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("j".into()),
+            nullable_small_int: Some(-32759),
+            nullable_large_int: Some(-2147483639),
+            nullable_big_int: Some(U64(9223372036854774816)),
+            nullable_date: Some(
+                chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            ),
+            nullable_general_int: Some(-2147483639),
+            nullable_date_time: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
+            nullable_uuid: Some(
+                uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
+            ),
+            nullable_ulong: Some(U64(9223372036854774816)),
+            nullable_json: Some("{ value: 10 }".into()),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(9.0),
+            nullable_bytes: Some(vec![
+                254, 130, 37, 250, 73, 83, 88, 152, 29, 252, 27, 65, 210, 245, 25, 177,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("12.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("10:10", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 10, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
+        },
+    ]
+}
+
+/// Print the time per iteration of a bench loop
+///
+///   * **label** - The function timed
+///   * **elapsed** - Time of all iterations
+pub fn report(label: &str, elapsed: Duration) {
+    println!("sample {label:<32} {:?}/iteration", elapsed / ITERATIONS);
+}
+
+/// Time the gateway functions of `sample` sending the statement text against the prepared statements
+pub fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut cfg = Config::new();
+        cfg.dbname = Some("kgen".to_string());
+        cfg.user = Some("kgen".to_string());
+        cfg.password = Some("kgen".to_string());
+        let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+        let resource = pool.get().await.unwrap();
+        let client = resource.client();
+        TableSample::drop_table(client).await.unwrap();
+        TableSample::create_table(client).await.unwrap();
+        let samples = get_sample_rows();
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableSample::bulk_insert(client, &samples, 4).await.unwrap();
+            TableSample::delete_all(client).await.unwrap();
+        }
+        report("bulk_insert", start.elapsed());
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableSample::bulk_insert_prepared(&resource, &samples, 4)
+                .await
+                .unwrap();
+            TableSample::delete_all_prepared(&resource).await.unwrap();
+        }
+        report("bulk_insert_prepared", start.elapsed());
+        TableSample::bulk_insert(client, &samples, 4).await.unwrap();
+        let keys = TableSample::select_all(client)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.pkey())
+            .collect::<Vec<_>>();
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            for key in keys.iter() {
+                TableSample::select_by_pkey(client, key)
+                    .await
+                    .unwrap()
+                    .unwrap();
+            }
+        }
+        report("select_by_pkey", start.elapsed());
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            for key in keys.iter() {
+                TableSample::select_by_pkey_prepared(&resource, key)
+                    .await
+                    .unwrap()
+                    .unwrap();
+            }
+        }
+        report("select_by_pkey_prepared", start.elapsed());
+        TableSample::drop_table(client).await.unwrap();
+    });
+}
+
+// α <mod-def prepared_sample>
+// ω <mod-def prepared_sample>
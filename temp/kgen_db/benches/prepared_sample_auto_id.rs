@@ -0,0 +1,142 @@
+//! Bench of the gateway functions of `sample_auto_id` against their `_prepared` variants
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use deadpool_postgres::Config;
+use deadpool_postgres::Runtime;
+use kgen_db::sample_auto_id::*;
+use std::time::Duration;
+use std::time::Instant;
+use tokio_postgres::GenericClient;
+use tokio_postgres::NoTls;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- constants ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Iterations of each bench loop
+pub const ITERATIONS: u32 = 100;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Get a set of sample rows for testing
+///
+///   * _return_ - Set of sample rows to test CRUD methods
+pub fn get_sample_rows() -> Vec<SampleAutoIdRowData> {
+    vec![
+        SampleAutoIdRowData {
+            the_name: "a".into(),
+            the_large_int: -2147483648,
+            the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("a".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "b".into(),
+            the_large_int: -2147483647,
+            the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("b".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "c".into(),
+            the_large_int: -2147483646,
+            the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("c".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "d".into(),
+            the_large_int: -2147483645,
+            the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("d".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "e".into(),
+            the_large_int: -2147483644,
+            the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("e".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "f".into(),
+            the_large_int: -2147483643,
+            the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("f".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "g".into(),
+            the_large_int: -2147483642,
+            the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("g".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "h".into(),
+            the_large_int: -2147483641,
+            the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("h".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "i".into(),
+            the_large_int: -2147483640,
+            the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("i".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "j".into(),
+            the_large_int: -2147483639,
+            the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("j".into()),
+        },
+    ]
+}
+
+/// Print the time per iteration of a bench loop
+///
+///   * **label** - The function timed
+///   * **elapsed** - Time of all iterations
+pub fn report(label: &str, elapsed: Duration) {
+    println!(
+        "sample_auto_id {label:<32} {:?}/iteration",
+        elapsed / ITERATIONS
+    );
+}
+
+/// Time the gateway functions of `sample_auto_id` sending the statement text against the prepared statements
+pub fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut cfg = Config::new();
+        cfg.dbname = Some("kgen".to_string());
+        cfg.user = Some("kgen".to_string());
+        cfg.password = Some("kgen".to_string());
+        let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+        let resource = pool.get().await.unwrap();
+        let client = resource.client();
+        TableSampleAutoId::drop_table(client).await.unwrap();
+        TableSampleAutoId::create_table(client).await.unwrap();
+        let samples = get_sample_rows();
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableSampleAutoId::bulk_insert(client, samples.clone(), 4)
+                .await
+                .unwrap();
+            TableSampleAutoId::delete_all(client).await.unwrap();
+        }
+        report("bulk_insert", start.elapsed());
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            TableSampleAutoId::bulk_insert_prepared(&resource, samples.clone(), 4)
+                .await
+                .unwrap();
+            TableSampleAutoId::delete_all_prepared(&resource)
+                .await
+                .unwrap();
+        }
+        report("bulk_insert_prepared", start.elapsed());
+        TableSampleAutoId::drop_table(client).await.unwrap();
+    });
+}
+
+// α <mod-def prepared_sample_auto_id>
+// ω <mod-def prepared_sample_auto_id>
@@ -0,0 +1,76 @@
+//! Modes and reports of the transactional batch and synchronization functions of the table gateways
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// How the chunks of a transactional batch are committed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchMode {
+    /// All chunks commit together, or none do if any chunk fails
+    AllOrNothing,
+    /// Each chunk runs under its own savepoint, so a failed chunk is rolled back alone
+    ChunkSavepoints,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A chunk of a batch that was committed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkSuccess<T> {
+    /// Index of the chunk
+    pub chunk: usize,
+    /// Range of the rows of the chunk in the batch
+    pub rows: std::ops::Range<usize>,
+    /// Result of the chunk statement
+    pub result: T,
+}
+
+/// A chunk of a batch that was not committed
+#[derive(Debug)]
+pub struct ChunkFailure {
+    /// Index of the chunk
+    pub chunk: usize,
+    /// Range of the rows of the chunk in the batch
+    pub rows: std::ops::Range<usize>,
+    /// The error of the chunk, `None` if rolled back for the failure of another chunk
+    pub error: Option<tokio_postgres::Error>,
+}
+
+/// The committed and failed chunks of a transactional batch, in chunk order
+#[derive(Debug)]
+pub struct BatchReport<T> {
+    /// The chunks committed
+    pub succeeded: Vec<ChunkSuccess<T>>,
+    /// The chunks not committed
+    pub failed: Vec<ChunkFailure>,
+}
+
+/// The changes applied to make a table match the rows it was synchronized to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SyncSummary {
+    /// Number of rows inserted, their key absent from the table
+    pub inserted: u64,
+    /// Number of rows updated, their key present with other values
+    pub updated: u64,
+    /// Number of rows deleted, their key absent from the rows
+    pub deleted: u64,
+    /// Number of rows already matching
+    pub unchanged: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl<T> BatchReport<T> {
+    /// True if every chunk was committed
+    ///
+    ///   * _return_ - True if no chunk failed
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+// α <mod-def batch>
+// ω <mod-def batch>
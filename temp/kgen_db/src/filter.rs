@@ -0,0 +1,267 @@
+//! Typed, composable filters rendered into where clauses for the table gateways
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use tokio_postgres::types::ToSql;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A typed, composable filter on the columns `C` of a table.
+/// Rendered into a where clause and parameters suitable for `select_all_where`.
+pub enum Filter<C> {
+    /// Compare column to value with the operator
+    Compare(C, &'static str, Box<dyn ToSql + Sync>),
+    /// Column matches one of the values
+    In(C, Vec<Box<dyn ToSql + Sync>>),
+    /// Column is null
+    IsNull(C),
+    /// Column is not null
+    IsNotNull(C),
+    /// Column matches the `LIKE` pattern
+    Like(C, String),
+    /// All filters match
+    And(Vec<Filter<C>>),
+    /// Any filter matches
+    Or(Vec<Filter<C>>),
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- traits ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A column of a table that may be referenced in a [Filter]
+pub trait SqlColumn {
+    /// The name of the column as it appears in sql
+    ///
+    ///   * _return_ - The column name
+    fn column_name(&self) -> &'static str;
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl<C> Filter<C>
+where
+    C: SqlColumn,
+{
+    /// Filter rows where column equals `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn eq<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, "=", Box::new(value))
+    }
+
+    /// Filter rows where column does not equal `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn ne<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, "<>", Box::new(value))
+    }
+
+    /// Filter rows where column is less than `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn lt<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, "<", Box::new(value))
+    }
+
+    /// Filter rows where column is less than or equal to `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn le<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, "<=", Box::new(value))
+    }
+
+    /// Filter rows where column is greater than `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn gt<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, ">", Box::new(value))
+    }
+
+    /// Filter rows where column is greater than or equal to `value`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **value** - The value to compare to
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn ge<V>(column: C, value: V) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::Compare(column, ">=", Box::new(value))
+    }
+
+    /// Filter rows where column matches one of `values`
+    ///
+    ///   * **column** - The column to compare
+    ///   * **values** - The values to match
+    ///   * _return_ - The filter
+    pub fn is_in<V>(column: C, values: impl IntoIterator<Item = V>) -> Self
+    where
+        V: 'static + ToSql + Sync,
+    {
+        Filter::In(
+            column,
+            values
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn ToSql + Sync>)
+                .collect(),
+        )
+    }
+
+    /// Filter rows where column is null
+    ///
+    ///   * **column** - The column to check
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn is_null(column: C) -> Self {
+        Filter::IsNull(column)
+    }
+
+    /// Filter rows where column is not null
+    ///
+    ///   * **column** - The column to check
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn is_not_null(column: C) -> Self {
+        Filter::IsNotNull(column)
+    }
+
+    /// Filter rows where column matches the `LIKE` pattern
+    ///
+    ///   * **column** - The column to match
+    ///   * **pattern** - The `LIKE` pattern
+    ///   * _return_ - The filter
+    #[inline]
+    pub fn like(column: C, pattern: impl Into<String>) -> Self {
+        Filter::Like(column, pattern.into())
+    }
+
+    /// Combine with `other` requiring both to match
+    ///
+    ///   * **other** - The other filter
+    ///   * _return_ - The combined filter
+    pub fn and(self, other: Filter<C>) -> Self {
+        match self {
+            Filter::And(mut filters) => {
+                filters.push(other);
+                Filter::And(filters)
+            }
+            filter => Filter::And(vec![filter, other]),
+        }
+    }
+
+    /// Combine with `other` requiring either to match
+    ///
+    ///   * **other** - The other filter
+    ///   * _return_ - The combined filter
+    pub fn or(self, other: Filter<C>) -> Self {
+        match self {
+            Filter::Or(mut filters) => {
+                filters.push(other);
+                Filter::Or(filters)
+            }
+            filter => Filter::Or(vec![filter, other]),
+        }
+    }
+
+    /// Render the filter as a where clause (sans `where` keyword) and its parameters
+    ///
+    ///   * _return_ - The where clause and parameters numbered to match
+    pub fn render(&self) -> (String, Vec<&(dyn ToSql + Sync)>) {
+        let mut sql = String::new();
+        let mut params = Vec::new();
+        self.render_into(&mut sql, &mut params);
+        (sql, params)
+    }
+
+    /// Render the filter into `sql`, numbering parameters from those already in `params`
+    ///
+    ///   * **sql** - The sql being rendered
+    ///   * **params** - The parameters being collected
+    fn render_into<'a>(&'a self, sql: &mut String, params: &mut Vec<&'a (dyn ToSql + Sync)>) {
+        match self {
+            Filter::Compare(column, op, value) => {
+                params.push(value.as_ref());
+                sql.push_str(&format!("{} {op} ${}", column.column_name(), params.len()));
+            }
+            Filter::In(column, values) => {
+                if values.is_empty() {
+                    sql.push_str("FALSE");
+                } else {
+                    let placeholders = values
+                        .iter()
+                        .map(|value| {
+                            params.push(value.as_ref());
+                            format!("${}", params.len())
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    sql.push_str(&format!("{} IN ({placeholders})", column.column_name()));
+                }
+            }
+            Filter::IsNull(column) => sql.push_str(&format!("{} IS NULL", column.column_name())),
+            Filter::IsNotNull(column) => {
+                sql.push_str(&format!("{} IS NOT NULL", column.column_name()))
+            }
+            Filter::Like(column, pattern) => {
+                params.push(pattern);
+                sql.push_str(&format!("{} LIKE ${}", column.column_name(), params.len()));
+            }
+            Filter::And(filters) | Filter::Or(filters) => {
+                let (separator, empty) = if matches!(self, Filter::And(_)) {
+                    (" AND ", "TRUE")
+                } else {
+                    (" OR ", "FALSE")
+                };
+                if filters.is_empty() {
+                    sql.push_str(empty);
+                } else {
+                    sql.push('(');
+                    for (i, filter) in filters.iter().enumerate() {
+                        if i > 0 {
+                            sql.push_str(separator);
+                        }
+                        filter.render_into(sql, params);
+                    }
+                    sql.push(')');
+                }
+            }
+        }
+    }
+}
+
+// α <mod-def filter>
+// ω <mod-def filter>
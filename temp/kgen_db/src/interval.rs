@@ -0,0 +1,79 @@
+//! The newtype modeling interval columns
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use bytes::BytesMut;
+use tokio_postgres::types::FromSql;
+use tokio_postgres::types::IsNull;
+use tokio_postgres::types::ToSql;
+use tokio_postgres::types::Type;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A postgres `interval`, wrapping `pg_interval::Interval`.
+/// `pg_interval::Interval` implements neither `Default` nor `Hash`, both required of the row data,
+/// so interval columns are modeled by this newtype. The default is the zero interval and the hash
+/// is that of the months, days and microseconds, consistent with the derived equality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval(
+    /// The wrapped interval
+    pub pg_interval::Interval,
+);
+
+// α <mod-def interval>
+// ω <mod-def interval>
+
+impl Default for Interval {
+    fn default() -> Self {
+        Interval(pg_interval::Interval::new(0, 0, 0))
+    }
+}
+
+impl std::hash::Hash for Interval {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.0.months, self.0.days, self.0.microseconds).hash(state);
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        <pg_interval::Interval as ToSql>::to_sql(&self.0, ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <pg_interval::Interval as ToSql>::accepts(ty)
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Interval(pg_interval::Interval::from_sql(ty, raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <pg_interval::Interval as FromSql>::accepts(ty)
+    }
+}
+
+impl From<pg_interval::Interval> for Interval {
+    fn from(value: pg_interval::Interval) -> Self {
+        Interval(value)
+    }
+}
+
+impl From<Interval> for pg_interval::Interval {
+    fn from(value: Interval) -> Self {
+        value.0
+    }
+}
@@ -5,9 +5,23 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::batch::BatchMode;
+use crate::batch::BatchReport;
+use crate::batch::ChunkFailure;
+use crate::batch::ChunkSuccess;
+use crate::filter::Filter;
+use crate::filter::SqlColumn;
+use crate::schema::verify_table_schema;
+use crate::schema::ColumnSchema;
+use crate::schema::SchemaReport;
+use crate::unsigned::U64;
+use futures::StreamExt;
 #[allow(unused)]
 use std::sync::LazyLock;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::binary_copy::BinaryCopyOutStream;
 use tokio_postgres::types::ToSql;
+use tokio_postgres::types::Type;
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- statics ---
@@ -16,6 +30,64 @@ use tokio_postgres::types::ToSql;
 pub static KEYLESS_TABLE_NAME: LazyLock<String> =
     std::sync::LazyLock::new(|| std::env::var("KEYLESS_TABLE_NAME").unwrap_or("keyless".into()));
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The columns of `keyless`, with variants in the same order as the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeylessColumn {
+    /// The `the_name` column
+    TheName,
+    /// The `the_small_int` column
+    TheSmallInt,
+    /// The `the_large_int` column
+    TheLargeInt,
+    /// The `the_big_int` column
+    TheBigInt,
+    /// The `the_date` column
+    TheDate,
+    /// The `the_general_int` column
+    TheGeneralInt,
+    /// The `the_date_time` column
+    TheDateTime,
+    /// The `the_uuid` column
+    TheUuid,
+    /// The `the_ulong` column
+    TheUlong,
+    /// The `the_json` column
+    TheJson,
+    /// The `the_jsonb` column
+    TheJsonb,
+    /// The `nullable_name` column
+    NullableName,
+    /// The `nullable_small_int` column
+    NullableSmallInt,
+    /// The `nullable_large_int` column
+    NullableLargeInt,
+    /// The `nullable_big_int` column
+    NullableBigInt,
+    /// The `nullable_date` column
+    NullableDate,
+    /// The `nullable_general_int` column
+    NullableGeneralInt,
+    /// The `nullable_date_time` column
+    NullableDateTime,
+    /// The `nullable_uuid` column
+    NullableUuid,
+    /// The `nullable_ulong` column
+    NullableUlong,
+    /// The `nullable_json` column
+    NullableJson,
+    /// The `nullable_jsonb` column
+    NullableJsonb,
+    /// The `created_at` column
+    CreatedAt,
+    /// The `updated_at` column
+    UpdatedAt,
+    /// The `deleted_at` column
+    DeletedAt,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- structs ---
 ////////////////////////////////////////////////////////////////////////////////////
@@ -29,7 +101,7 @@ pub struct KeylessRowData {
     /// Field for column `the_large_int`
     pub the_large_int: i64,
     /// Field for column `the_big_int`
-    pub the_big_int: i64,
+    pub the_big_int: U64,
     /// Field for column `the_date`
     pub the_date: chrono::NaiveDate,
     /// Field for column `the_general_int`
@@ -39,19 +111,19 @@ pub struct KeylessRowData {
     /// Field for column `the_uuid`
     pub the_uuid: uuid::Uuid,
     /// Field for column `the_ulong`
-    pub the_ulong: i64,
+    pub the_ulong: U64,
     /// Field for column `the_json`
     pub the_json: serde_json::Value,
     /// Field for column `the_jsonb`
     pub the_jsonb: serde_json::Value,
     /// Field for column `nullable_name`
-    pub nullable_name: String,
+    pub nullable_name: Option<String>,
     /// Field for column `nullable_small_int`
     pub nullable_small_int: Option<i16>,
     /// Field for column `nullable_large_int`
     pub nullable_large_int: Option<i64>,
     /// Field for column `nullable_big_int`
-    pub nullable_big_int: Option<i64>,
+    pub nullable_big_int: Option<U64>,
     /// Field for column `nullable_date`
     pub nullable_date: Option<chrono::NaiveDate>,
     /// Field for column `nullable_general_int`
@@ -61,11 +133,17 @@ pub struct KeylessRowData {
     /// Field for column `nullable_uuid`
     pub nullable_uuid: Option<uuid::Uuid>,
     /// Field for column `nullable_ulong`
-    pub nullable_ulong: Option<i64>,
+    pub nullable_ulong: Option<U64>,
     /// Field for column `nullable_json`
     pub nullable_json: Option<serde_json::Value>,
     /// Field for column `nullable_jsonb`
-    pub nullable_jsonb: serde_json::Value,
+    pub nullable_jsonb: Option<serde_json::Value>,
+    /// Field for column `created_at`
+    pub created_at: chrono::NaiveDateTime,
+    /// Field for column `updated_at`
+    pub updated_at: chrono::NaiveDateTime,
+    /// Field for column `deleted_at`
+    pub deleted_at: Option<chrono::NaiveDateTime>,
 }
 
 /// Table Gateway Support for table `keyless`.
@@ -77,17 +155,51 @@ pub struct TableKeyless {}
 // --- type impls ---
 ////////////////////////////////////////////////////////////////////////////////////
 impl TableKeyless {
-    /// Select rows of `keyless` with provided where clause
+    /// Decode a row of `keyless` selected with all columns in table order
+    ///
+    ///   * **row** - The selected row
+    ///   * _return_ - The decoded row or the `tokio_postgres::Error`
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<KeylessRowData, tokio_postgres::Error> {
+        Ok(KeylessRowData {
+            the_name: row.try_get(0)?,
+            the_small_int: row.try_get(1)?,
+            the_large_int: row.try_get(2)?,
+            the_big_int: row.try_get(3)?,
+            the_date: row.try_get(4)?,
+            the_general_int: row.try_get(5)?,
+            the_date_time: row.try_get(6)?,
+            the_uuid: row.try_get(7)?,
+            the_ulong: row.try_get(8)?,
+            the_json: row.try_get(9)?,
+            the_jsonb: row.try_get(10)?,
+            nullable_name: row.try_get(11)?,
+            nullable_small_int: row.try_get(12)?,
+            nullable_large_int: row.try_get(13)?,
+            nullable_big_int: row.try_get(14)?,
+            nullable_date: row.try_get(15)?,
+            nullable_general_int: row.try_get(16)?,
+            nullable_date_time: row.try_get(17)?,
+            nullable_uuid: row.try_get(18)?,
+            nullable_ulong: row.try_get(19)?,
+            nullable_json: row.try_get(20)?,
+            nullable_jsonb: row.try_get(21)?,
+            created_at: row.try_get(22)?,
+            updated_at: row.try_get(23)?,
+            deleted_at: row.try_get(24)?,
+        })
+    }
+
+    /// Select rows of `keyless` with provided where clause, skipping soft deleted rows
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **where_clause** - The where clause (sans `where` keyword)
     ///   * **params** - Any clause parameters
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     pub async fn select_all_where<C>(
         client: &C,
         where_clause: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Vec<KeylessRowData>
+    ) -> Result<Vec<KeylessRowData>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
@@ -96,62 +208,193 @@ impl TableKeyless {
     the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
     	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
     	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
-    FROM {}
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at,
+    	deleted_at
+    FROM {0}
+    WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        let rows = match client.query(&select_where_statement, params).await {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_all_where `keyless` -> {err}");
+                return Err(err);
+            }
+        };
+
+        let mut results = Vec::<KeylessRowData>::with_capacity(rows.len());
+
+        for row in rows {
+            results.push(Self::from_row(&row)?);
+            tracing::trace!("{:?}", results.last().unwrap());
+        }
+        Ok(results)
+    }
+
+    /// Select rows of `keyless` with provided where clause, including soft deleted rows
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_where_including_deleted<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<KeylessRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at,
+    	deleted_at
+    FROM {0}
     WHERE {where_clause}"#,
             *KEYLESS_TABLE_NAME
         );
         let rows = match client.query(&select_where_statement, params).await {
-            Ok(stmt) => stmt,
-            Err(e) => {
-                panic!("Error preparing statement: {e}");
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_all_where_including_deleted `keyless` -> {err}");
+                return Err(err);
             }
         };
 
         let mut results = Vec::<KeylessRowData>::with_capacity(rows.len());
 
         for row in rows {
-            results.push(KeylessRowData {
-                the_name: row.get(0),
-                the_small_int: row.get(1),
-                the_large_int: row.get(2),
-                the_big_int: row.get(3),
-                the_date: row.get(4),
-                the_general_int: row.get(5),
-                the_date_time: row.get(6),
-                the_uuid: row.get(7),
-                the_ulong: row.get(8),
-                the_json: row.get(9),
-                the_jsonb: row.get(10),
-                nullable_name: row.get(11),
-                nullable_small_int: row.get(12),
-                nullable_large_int: row.get(13),
-                nullable_big_int: row.get(14),
-                nullable_date: row.get(15),
-                nullable_general_int: row.get(16),
-                nullable_date_time: row.get(17),
-                nullable_uuid: row.get(18),
-                nullable_ulong: row.get(19),
-                nullable_json: row.get(20),
-                nullable_jsonb: row.get(21),
-            });
+            results.push(Self::from_row(&row)?);
             tracing::trace!("{:?}", results.last().unwrap());
         }
-        results
+        Ok(results)
     }
 
     /// Select rows of `keyless`
     ///
     ///   * **client** - The tokio postgresql client
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     #[inline]
-    pub async fn select_all<C>(client: &C) -> Vec<KeylessRowData>
+    pub async fn select_all<C>(client: &C) -> Result<Vec<KeylessRowData>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
         Self::select_all_where(client, "1=1", &[]).await
     }
 
+    /// Select rows of `keyless` with provided where clause as a stream of rows.
+    /// The stream is not `Unpin`, so pin it (e.g. `std::pin::pin!`) to iterate with `next`.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Stream of selected rows or the `tokio_postgres::Error`
+    pub async fn select_stream_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<
+        impl futures::Stream<Item = Result<KeylessRowData, tokio_postgres::Error>>,
+        tokio_postgres::Error,
+    >
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at,
+    	deleted_at
+    FROM {0}
+    WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        let rows = match client
+            .query_raw(&select_where_statement, params.iter().copied())
+            .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_stream_where `keyless` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(rows.map(|row| row.and_then(|row| Self::from_row(&row))))
+    }
+
+    /// Select rows of `keyless` with provided where clause as a stream of chunks,
+    /// fetched through a portal (cursor) bound in `transaction`.
+    ///
+    ///   * **transaction** - The transaction binding the portal
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * **chunk_size** - Maximum number of rows fetched per chunk
+    ///   * _return_ - Stream of chunks of selected rows or the `tokio_postgres::Error`
+    pub async fn select_chunks_where<'a>(
+        transaction: &'a tokio_postgres::Transaction<'a>,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+        chunk_size: i32,
+    ) -> Result<
+        impl futures::Stream<Item = Result<Vec<KeylessRowData>, tokio_postgres::Error>> + 'a,
+        tokio_postgres::Error,
+    > {
+        let select_where_statement = format!(
+            r#"SELECT 
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at,
+    	deleted_at
+    FROM {0}
+    WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        let portal = match transaction.bind(&select_where_statement, params).await {
+            Ok(portal) => portal,
+            Err(err) => {
+                tracing::error!("Failed select_chunks_where `keyless` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(futures::stream::try_unfold(
+            portal,
+            move |portal| async move {
+                let rows = transaction.query_portal(&portal, chunk_size).await?;
+                if rows.is_empty() {
+                    Ok(None)
+                } else {
+                    let chunk = rows
+                        .iter()
+                        .map(Self::from_row)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(Some((chunk, portal)))
+                }
+            },
+        ))
+    }
+
+    /// Select rows of `keyless` matching the typed `filter`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **filter** - The filter on the columns of the table
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_filtered<C>(
+        client: &C,
+        filter: &Filter<KeylessColumn>,
+    ) -> Result<Vec<KeylessRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let (where_clause, params) = filter.render();
+        Self::select_all_where(client, &where_clause, &params).await
+    }
+
     /// Insert rows of `keyless` by building parameterized statement.
     /// For large insertions prefer [bulk_insert]
     ///
@@ -167,17 +410,19 @@ impl TableKeyless {
     {
         use itertools::Itertools;
         let mut param_id = 0;
+
         let mut params: Vec<&(dyn ToSql + Sync)> =
             Vec::with_capacity(rows.len() * KeylessRowData::NUM_FIELDS);
         let value_params = rows
             .iter()
             .map(|row| {
-                let row_params = KeylessRowData::FIELD_NAMES
+                let row_params = (0..22)
                     .map(|_| {
                         param_id += 1;
                         format!("${param_id}")
                     })
-                    .join(", ");
+                    .join(", ")
+                    + ", now(), now()";
 
                 params.push(&row.the_name);
                 params.push(&row.the_small_int);
@@ -207,12 +452,12 @@ impl TableKeyless {
             .join(",\n");
 
         let insert_statement = format!(
-            r#"insert into {} 
+            r#"insert into {0} 
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
     	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
     	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at
     )
     VALUES
     {value_params}
@@ -274,19 +519,19 @@ impl TableKeyless {
         let mut nullable_jsonb = Vec::with_capacity(chunk_size);
 
         let bulk_insert_statement = format!(
-            r#"insert into {}
+            r#"insert into {0}
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
     	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
     	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at
     )
-    SELECT * FROM UNNEST
+    SELECT *, now(), now() FROM UNNEST
     (
     	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
-    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::json[], $12::varchar[],
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::varchar[],
     	$13::smallint[], $14::bigint[], $15::bigint[], $16::date[], $17::int[], $18::timestamp[],
-    	$19::uuid[], $20::bigint[], $21::json[], $22::json[]
+    	$19::uuid[], $20::bigint[], $21::json[], $22::jsonb[]
     )
     "#,
             *KEYLESS_TABLE_NAME
@@ -386,7 +631,196 @@ impl TableKeyless {
         Ok(())
     }
 
-    /// Upsert large batch of [Keyless] rows.
+    /// Insert large batch of [Keyless] rows with `COPY ... FROM STDIN BINARY`.
+    /// Prefer over [bulk_insert] when the _auto id_ of inserted rows is not needed.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data to insert
+    ///   * _return_ - Number of rows copied in or tokio_postgres::Error
+    pub async fn copy_in<C>(
+        client: &C,
+        rows: &[KeylessRowData],
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [
+            Type::VARCHAR,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+            Type::VARCHAR,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+        ];
+        let copy_in_statement = format!(
+            r#"COPY {0}
+    (
+    	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    )
+    FROM STDIN BINARY"#,
+            *KEYLESS_TABLE_NAME
+        );
+        let sink = match client.client().copy_in(&copy_in_statement).await {
+            Ok(sink) => sink,
+            Err(err) => {
+                tracing::error!("Failed copy_in `keyless` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut writer = std::pin::pin!(BinaryCopyInWriter::new(sink, &types));
+        for row in rows {
+            writer
+                .as_mut()
+                .write(&[
+                    &row.the_name,
+                    &row.the_small_int,
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &row.the_json,
+                    &row.the_jsonb,
+                    &row.nullable_name,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb,
+                ])
+                .await?;
+        }
+        let copied = writer.finish().await?;
+        tracing::debug!("Finished copy_in of size({copied}) in `keyless`");
+        Ok(copied)
+    }
+
+    /// Export rows of `keyless` with provided where clause with `COPY ... TO STDOUT BINARY`.
+    /// `COPY` does not support parameters so the where clause is embedded as is and
+    /// must not contain untrusted input.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * _return_ - Copied out rows or the `tokio_postgres::Error`
+    pub async fn copy_out_where<C>(
+        client: &C,
+        where_clause: &str,
+    ) -> Result<Vec<KeylessRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [
+            Type::VARCHAR,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+            Type::VARCHAR,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+            Type::TIMESTAMP,
+            Type::TIMESTAMP,
+            Type::TIMESTAMP,
+        ];
+        let copy_out_statement = format!(
+            r#"COPY (SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at,
+    	deleted_at
+    FROM {0}
+    WHERE deleted_at IS NULL AND ({where_clause})) TO STDOUT BINARY"#,
+            *KEYLESS_TABLE_NAME
+        );
+        let stream = match client.client().copy_out(&copy_out_statement).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("Failed copy_out_where `keyless` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut rows = std::pin::pin!(BinaryCopyOutStream::new(stream, &types));
+
+        let mut results = Vec::<KeylessRowData>::new();
+        while let Some(row) = rows.next().await {
+            let row = row?;
+            results.push(KeylessRowData {
+                the_name: row.try_get(0)?,
+                the_small_int: row.try_get(1)?,
+                the_large_int: row.try_get(2)?,
+                the_big_int: row.try_get(3)?,
+                the_date: row.try_get(4)?,
+                the_general_int: row.try_get(5)?,
+                the_date_time: row.try_get(6)?,
+                the_uuid: row.try_get(7)?,
+                the_ulong: row.try_get(8)?,
+                the_json: row.try_get(9)?,
+                the_jsonb: row.try_get(10)?,
+                nullable_name: row.try_get(11)?,
+                nullable_small_int: row.try_get(12)?,
+                nullable_large_int: row.try_get(13)?,
+                nullable_big_int: row.try_get(14)?,
+                nullable_date: row.try_get(15)?,
+                nullable_general_int: row.try_get(16)?,
+                nullable_date_time: row.try_get(17)?,
+                nullable_uuid: row.try_get(18)?,
+                nullable_ulong: row.try_get(19)?,
+                nullable_json: row.try_get(20)?,
+                nullable_jsonb: row.try_get(21)?,
+                created_at: row.try_get(22)?,
+                updated_at: row.try_get(23)?,
+                deleted_at: row.try_get(24)?,
+            });
+        }
+        tracing::debug!(
+            "Finished copy_out_where of size({}) in `keyless`",
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Upsert large batch of [Keyless] rows, resolving conflicts on ().
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **rows** - Row data to insert
@@ -423,19 +857,19 @@ impl TableKeyless {
         let mut nullable_json = Vec::with_capacity(chunk_size);
         let mut nullable_jsonb = Vec::with_capacity(chunk_size);
         let bulk_upsert_statement = format!(
-            r#"insert into {}
+            r#"insert into {0}
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
     	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
     	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at
     )
-    SELECT * FROM UNNEST
+    SELECT *, now(), now() FROM UNNEST
     (
     	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
-    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::json[], $12::varchar[],
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::varchar[],
     	$13::smallint[], $14::bigint[], $15::bigint[], $16::date[], $17::int[], $18::timestamp[],
-    	$19::uuid[], $20::bigint[], $21::json[], $22::json[]
+    	$19::uuid[], $20::bigint[], $21::json[], $22::jsonb[]
     )
     ON CONFLICT ()
     DO UPDATE SET
@@ -460,7 +894,9 @@ impl TableKeyless {
     	nullable_uuid = EXCLUDED.nullable_uuid,
     	nullable_ulong = EXCLUDED.nullable_ulong,
     	nullable_json = EXCLUDED.nullable_json,
-    	nullable_jsonb = EXCLUDED.nullable_jsonb
+    	nullable_jsonb = EXCLUDED.nullable_jsonb,
+    	updated_at = now(),
+    	deleted_at = NULL
     "#,
             *KEYLESS_TABLE_NAME
         );
@@ -557,54 +993,1706 @@ impl TableKeyless {
         Ok(())
     }
 
-    /// Delete all rows of `keyless`
+    /// Insert large batch of [Keyless] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_insert_transactional<C>(
+        client: &mut C,
+        rows: &[KeylessRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<()>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_insert(&transaction, chunk_rows, chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_insert(&savepoint, chunk_rows, chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_insert_transactional chunk({chunk}) `keyless` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
+    /// Soft delete all live rows of `keyless`, setting `deleted_at`
     ///
     ///   * **client** - The tokio postgresql client
-    ///   * _return_ - Number of rows deleted
+    ///   * _return_ - Number of rows soft deleted
     #[inline]
     pub async fn delete_all<C>(client: &C) -> Result<u64, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
-        let delete_statement = format!("DELETE FROM {}", *KEYLESS_TABLE_NAME);
+        let delete_statement = format!(
+            "UPDATE {0} SET deleted_at = now() WHERE deleted_at IS NULL",
+            *KEYLESS_TABLE_NAME
+        );
         client.execute(&delete_statement, &[]).await
     }
-}
 
-impl KeylessRowData {
-    /// Number of fields
-    pub const NUM_FIELDS: usize = 22;
+    /// Delete all rows of `keyless`, including those soft deleted
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn hard_delete_all<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement = format!("DELETE FROM {0}", *KEYLESS_TABLE_NAME);
+        client.execute(&delete_statement, &[]).await
+    }
 
-    /// Names of fields
-    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
-        "the_name",
-        "the_small_int",
-        "the_large_int",
-        "the_big_int",
-        "the_date",
-        "the_general_int",
-        "the_date_time",
-        "the_uuid",
-        "the_ulong",
-        "the_json",
-        "the_jsonb",
-        "nullable_name",
-        "nullable_small_int",
-        "nullable_large_int",
-        "nullable_big_int",
-        "nullable_date",
-        "nullable_general_int",
-        "nullable_date_time",
-        "nullable_uuid",
-        "nullable_ulong",
-        "nullable_json",
-        "nullable_jsonb",
-    ];
-}
+    /// Create the table `keyless`, with any enum types and unique indices, if not present.
+    /// Runs the statements of [Self::CREATE_DDL], against the backdoor table name if set.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn create_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let create_statement = format!(
+            r#"CREATE TABLE IF NOT EXISTS {0} (
+    	the_name varchar(255) NOT NULL,
+    	the_small_int smallint NOT NULL,
+    	the_large_int bigint NOT NULL,
+    	the_big_int bigint NOT NULL,
+    	the_date date NOT NULL,
+    	the_general_int int NOT NULL,
+    	the_date_time timestamp NOT NULL,
+    	the_uuid uuid NOT NULL,
+    	the_ulong bigint NOT NULL,
+    	the_json json NOT NULL,
+    	the_jsonb jsonb NOT NULL,
+    	nullable_name varchar(255),
+    	nullable_small_int smallint,
+    	nullable_large_int bigint,
+    	nullable_big_int bigint,
+    	nullable_date date,
+    	nullable_general_int int,
+    	nullable_date_time timestamp,
+    	nullable_uuid uuid,
+    	nullable_ulong bigint,
+    	nullable_json json,
+    	nullable_jsonb jsonb,
+    	created_at timestamp NOT NULL DEFAULT now(),
+    	updated_at timestamp NOT NULL DEFAULT now(),
+    	deleted_at timestamp
+    );"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.batch_execute(&create_statement).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                tracing::error!("Failed create_table `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
 
-impl TableKeyless {
-    /// The total number of key and value columns
-    pub const COLUMN_COUNT: usize = 22;
+    /// Drop the table `keyless` if present, leaving any enum types in place
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn drop_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let drop_statement = format!("DROP TABLE IF EXISTS {0}", *KEYLESS_TABLE_NAME);
+        client.execute(&drop_statement, &[]).await.map(|_| ())
+    }
+
+    /// Verify the live table matches the columns assumed in [Self::COLUMN_SCHEMA].
+    /// Drift is returned in the report rather than as an error.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - The report of any drift or the `tokio_postgres::Error` reading the schema
+    #[inline]
+    pub async fn verify_schema<C>(client: &C) -> Result<SchemaReport, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        verify_table_schema(client, &KEYLESS_TABLE_NAME, &Self::COLUMN_SCHEMA).await
+    }
+
+    /// Count rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Number of rows matched or the `tokio_postgres::Error`
+    pub async fn count_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<i64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT count(*) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed count_where `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Check for any row of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - True if any row matched or the `tokio_postgres::Error`
+    pub async fn exists_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<bool, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT EXISTS (SELECT 1 FROM {0} WHERE deleted_at IS NULL AND ({where_clause}))"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed exists_where `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_name` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_name) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_name `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_name` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_name) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_name `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_small_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_small_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_small_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_small_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_small_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_small_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_large_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_large_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_large_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_large_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_large_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_large_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_big_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_big_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_big_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_big_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_big_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_big_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_date) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_date) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_general_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_general_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_general_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_general_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_general_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_general_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date_time` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_date_time) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date_time `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date_time` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_date_time) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date_time `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_ulong` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(the_ulong) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_ulong `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_ulong` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(the_ulong) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_ulong `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_name` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_name) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_name `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_name` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_name) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_name `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_small_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_small_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_small_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_small_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_small_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_small_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_large_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_large_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_large_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_large_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_large_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_large_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_big_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_big_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_big_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_big_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_big_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_big_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_date) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_date) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_general_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_general_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_general_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_general_int` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_general_int) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_general_int `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date_time` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_date_time) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date_time `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date_time` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_date_time) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date_time `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_ulong` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(nullable_ulong) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_ulong `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_ulong` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(nullable_ulong) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_ulong `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `created_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_created_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(created_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_created_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `created_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_created_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(created_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_created_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `updated_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_updated_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(updated_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_updated_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `updated_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_updated_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(updated_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_updated_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `deleted_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_deleted_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT min(deleted_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_deleted_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `deleted_at` of rows of `keyless` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_deleted_at<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(
+            r#"SELECT max(deleted_at) FROM {0} WHERE deleted_at IS NULL AND ({where_clause})"#,
+            *KEYLESS_TABLE_NAME
+        );
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_deleted_at `keyless` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Insert large batch of [Keyless] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ - Success or tokio_postgres::Error
+    pub async fn bulk_insert_prepared<C>(
+        client: &C,
+        rows: &[KeylessRowData],
+        chunk_size: usize,
+    ) -> Result<(), tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+
+        let bulk_insert_statement = format!(
+            r#"insert into {0}
+    (
+    	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
+    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
+    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, created_at, updated_at
+    )
+    SELECT *, now(), now() FROM UNNEST
+    (
+    	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::varchar[],
+    	$13::smallint[], $14::bigint[], $15::bigint[], $16::date[], $17::int[], $18::timestamp[],
+    	$19::uuid[], $20::bigint[], $21::json[], $22::jsonb[]
+    )
+    "#,
+            *KEYLESS_TABLE_NAME
+        );
+        let prepared = client.prepare_cached(&bulk_insert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(&row.the_json);
+                the_jsonb.push(&row.the_jsonb);
+                nullable_name.push(&row.nullable_name);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(&row.nullable_jsonb);
+            }
+
+            let chunk_result = client
+                .execute(
+                    &prepared,
+                    &[
+                        &the_name,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &nullable_name,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_insert `keyless` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk insert of size({}) in `keyless`",
+                        chunk_result
+                    );
+                }
+            }
+            the_name.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            nullable_name.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Soft delete all live rows of `keyless` with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn delete_all_prepared<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let delete_statement = format!(
+            "UPDATE {0} SET deleted_at = now() WHERE deleted_at IS NULL",
+            *KEYLESS_TABLE_NAME
+        );
+        let prepared = client.prepare_cached(&delete_statement).await?;
+        client.execute(&prepared, &[]).await
+    }
+}
+
+impl KeylessColumn {
+    /// All columns of the table
+    pub const ALL: [KeylessColumn; 25] = [
+        KeylessColumn::TheName,
+        KeylessColumn::TheSmallInt,
+        KeylessColumn::TheLargeInt,
+        KeylessColumn::TheBigInt,
+        KeylessColumn::TheDate,
+        KeylessColumn::TheGeneralInt,
+        KeylessColumn::TheDateTime,
+        KeylessColumn::TheUuid,
+        KeylessColumn::TheUlong,
+        KeylessColumn::TheJson,
+        KeylessColumn::TheJsonb,
+        KeylessColumn::NullableName,
+        KeylessColumn::NullableSmallInt,
+        KeylessColumn::NullableLargeInt,
+        KeylessColumn::NullableBigInt,
+        KeylessColumn::NullableDate,
+        KeylessColumn::NullableGeneralInt,
+        KeylessColumn::NullableDateTime,
+        KeylessColumn::NullableUuid,
+        KeylessColumn::NullableUlong,
+        KeylessColumn::NullableJson,
+        KeylessColumn::NullableJsonb,
+        KeylessColumn::CreatedAt,
+        KeylessColumn::UpdatedAt,
+        KeylessColumn::DeletedAt,
+    ];
+}
+
+impl KeylessRowData {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 25;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
+        "the_name",
+        "the_small_int",
+        "the_large_int",
+        "the_big_int",
+        "the_date",
+        "the_general_int",
+        "the_date_time",
+        "the_uuid",
+        "the_ulong",
+        "the_json",
+        "the_jsonb",
+        "nullable_name",
+        "nullable_small_int",
+        "nullable_large_int",
+        "nullable_big_int",
+        "nullable_date",
+        "nullable_general_int",
+        "nullable_date_time",
+        "nullable_uuid",
+        "nullable_ulong",
+        "nullable_json",
+        "nullable_jsonb",
+        "created_at",
+        "updated_at",
+        "deleted_at",
+    ];
+}
+
+impl TableKeyless {
+    /// The total number of key and value columns
+    pub const COLUMN_COUNT: usize = 25;
+
+    /// Statements creating the table `keyless`, its enum types and unique indices
+    pub const CREATE_DDL: &str = r#"CREATE TABLE IF NOT EXISTS keyless (
+  	the_name varchar(255) NOT NULL,
+  	the_small_int smallint NOT NULL,
+  	the_large_int bigint NOT NULL,
+  	the_big_int bigint NOT NULL,
+  	the_date date NOT NULL,
+  	the_general_int int NOT NULL,
+  	the_date_time timestamp NOT NULL,
+  	the_uuid uuid NOT NULL,
+  	the_ulong bigint NOT NULL,
+  	the_json json NOT NULL,
+  	the_jsonb jsonb NOT NULL,
+  	nullable_name varchar(255),
+  	nullable_small_int smallint,
+  	nullable_large_int bigint,
+  	nullable_big_int bigint,
+  	nullable_date date,
+  	nullable_general_int int,
+  	nullable_date_time timestamp,
+  	nullable_uuid uuid,
+  	nullable_ulong bigint,
+  	nullable_json json,
+  	nullable_jsonb jsonb,
+  	created_at timestamp NOT NULL DEFAULT now(),
+  	updated_at timestamp NOT NULL DEFAULT now(),
+  	deleted_at timestamp
+  );"#;
+
+    /// The columns of the table, in order, as assumed by the generated code
+    pub const COLUMN_SCHEMA: [ColumnSchema; Self::COLUMN_COUNT] = [
+        ColumnSchema {
+            name: "the_name",
+            udt_name: "varchar",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_small_int",
+            udt_name: "int2",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_large_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_big_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date",
+            udt_name: "date",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_general_int",
+            udt_name: "int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date_time",
+            udt_name: "timestamp",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_uuid",
+            udt_name: "uuid",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_ulong",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_json",
+            udt_name: "json",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_jsonb",
+            udt_name: "jsonb",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "nullable_name",
+            udt_name: "varchar",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_small_int",
+            udt_name: "int2",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_large_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_big_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date",
+            udt_name: "date",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_general_int",
+            udt_name: "int4",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date_time",
+            udt_name: "timestamp",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_uuid",
+            udt_name: "uuid",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_ulong",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_json",
+            udt_name: "json",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_jsonb",
+            udt_name: "jsonb",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "created_at",
+            udt_name: "timestamp",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "updated_at",
+            udt_name: "timestamp",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "deleted_at",
+            udt_name: "timestamp",
+            is_nullable: true,
+        },
+    ];
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SqlColumn for KeylessColumn {
+    /// The name of the column as it appears in sql
+    ///
+    ///   * _return_ - The column name
+    fn column_name(&self) -> &'static str {
+        match self {
+            KeylessColumn::TheName => "the_name",
+            KeylessColumn::TheSmallInt => "the_small_int",
+            KeylessColumn::TheLargeInt => "the_large_int",
+            KeylessColumn::TheBigInt => "the_big_int",
+            KeylessColumn::TheDate => "the_date",
+            KeylessColumn::TheGeneralInt => "the_general_int",
+            KeylessColumn::TheDateTime => "the_date_time",
+            KeylessColumn::TheUuid => "the_uuid",
+            KeylessColumn::TheUlong => "the_ulong",
+            KeylessColumn::TheJson => "the_json",
+            KeylessColumn::TheJsonb => "the_jsonb",
+            KeylessColumn::NullableName => "nullable_name",
+            KeylessColumn::NullableSmallInt => "nullable_small_int",
+            KeylessColumn::NullableLargeInt => "nullable_large_int",
+            KeylessColumn::NullableBigInt => "nullable_big_int",
+            KeylessColumn::NullableDate => "nullable_date",
+            KeylessColumn::NullableGeneralInt => "nullable_general_int",
+            KeylessColumn::NullableDateTime => "nullable_date_time",
+            KeylessColumn::NullableUuid => "nullable_uuid",
+            KeylessColumn::NullableUlong => "nullable_ulong",
+            KeylessColumn::NullableJson => "nullable_json",
+            KeylessColumn::NullableJsonb => "nullable_jsonb",
+            KeylessColumn::CreatedAt => "created_at",
+            KeylessColumn::UpdatedAt => "updated_at",
+            KeylessColumn::DeletedAt => "deleted_at",
+        }
+    }
 }
 
 // α <mod-def keyless>
@@ -3,9 +3,19 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- mod decls ---
 ////////////////////////////////////////////////////////////////////////////////////
+pub mod batch;
+pub mod filter;
+pub mod interval;
 pub mod keyless;
+pub mod page;
+pub mod payload;
+pub mod pg_enum;
 pub mod sample;
+pub mod sample_auto_id;
 pub mod sample_with_id;
+pub mod schema;
+pub mod unsigned;
+pub mod version;
 
 // α <mod-def lib>
 // ω <mod-def lib>
@@ -0,0 +1,16 @@
+//! Pages returned by the pagination functions of the table gateways
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A page of selected rows with the cursor to select the page following
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T, C> {
+    /// The rows of the page, in page order
+    pub rows: Vec<T>,
+    /// Cursor to select the next page, `None` if this page is the last
+    pub next: Option<C>,
+}
+
+// α <mod-def page>
+// ω <mod-def page>
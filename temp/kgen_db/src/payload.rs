@@ -0,0 +1,18 @@
+//! Serde payloads of the typed json columns
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Payload deserialized from a json column
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SamplePayload {
+    /// A label
+    pub label: String,
+    /// A count
+    pub count: i64,
+    /// Any tags
+    pub tags: Vec<String>,
+}
+
+// α <mod-def payload>
+// ω <mod-def payload>
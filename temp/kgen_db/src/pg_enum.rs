@@ -0,0 +1,25 @@
+//! Rust enums modeling the postgres enum types
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Models postgres enum type `sample_color`
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, postgres_types::ToSql, postgres_types::FromSql,
+)]
+#[postgres(name = "sample_color")]
+pub enum SampleColor {
+    #[postgres(name = "red")]
+    #[default]
+    /// Label `red`
+    Red,
+    #[postgres(name = "green")]
+    /// Label `green`
+    Green,
+    #[postgres(name = "blue")]
+    /// Label `blue`
+    Blue,
+}
+
+// α <mod-def pg_enum>
+// ω <mod-def pg_enum>
@@ -2,18 +2,130 @@
 //!
 //! > Table with primary key
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- pub module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+pub use crate::pg_enum::SampleColor;
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::batch::BatchMode;
+use crate::batch::BatchReport;
+use crate::batch::ChunkFailure;
+use crate::batch::ChunkSuccess;
+use crate::batch::SyncSummary;
+use crate::filter::Filter;
+use crate::filter::SqlColumn;
+use crate::interval::Interval;
+use crate::page::Page;
+use crate::payload::SamplePayload;
+use crate::schema::verify_table_schema;
+use crate::schema::ColumnSchema;
+use crate::schema::SchemaReport;
+use crate::unsigned::U64;
+use futures::StreamExt;
 #[allow(unused)]
 use std::sync::LazyLock;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::binary_copy::BinaryCopyOutStream;
+use tokio_postgres::types::Json;
 use tokio_postgres::types::ToSql;
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The columns of `sample`, with variants in the same order as the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleColumn {
+    /// The `the_name` column
+    TheName,
+    /// The `the_small_int` column
+    TheSmallInt,
+    /// The `the_large_int` column
+    TheLargeInt,
+    /// The `the_big_int` column
+    TheBigInt,
+    /// The `the_date` column
+    TheDate,
+    /// The `the_general_int` column
+    TheGeneralInt,
+    /// The `the_date_time` column
+    TheDateTime,
+    /// The `the_uuid` column
+    TheUuid,
+    /// The `the_ulong` column
+    TheUlong,
+    /// The `the_json` column
+    TheJson,
+    /// The `the_jsonb` column
+    TheJsonb,
+    /// The `the_double` column
+    TheDouble,
+    /// The `the_bytes` column
+    TheBytes,
+    /// The `the_numeric` column
+    TheNumeric,
+    /// The `the_time` column
+    TheTime,
+    /// The `the_timestamp` column
+    TheTimestamp,
+    /// The `the_interval` column
+    TheInterval,
+    /// The `the_color` column
+    TheColor,
+    /// The `the_tags` column
+    TheTags,
+    /// The `the_scores` column
+    TheScores,
+    /// The `the_uuids` column
+    TheUuids,
+    /// The `nullable_name` column
+    NullableName,
+    /// The `nullable_small_int` column
+    NullableSmallInt,
+    /// The `nullable_large_int` column
+    NullableLargeInt,
+    /// The `nullable_big_int` column
+    NullableBigInt,
+    /// The `nullable_date` column
+    NullableDate,
+    /// The `nullable_general_int` column
+    NullableGeneralInt,
+    /// The `nullable_date_time` column
+    NullableDateTime,
+    /// The `nullable_uuid` column
+    NullableUuid,
+    /// The `nullable_ulong` column
+    NullableUlong,
+    /// The `nullable_json` column
+    NullableJson,
+    /// The `nullable_jsonb` column
+    NullableJsonb,
+    /// The `nullable_double` column
+    NullableDouble,
+    /// The `nullable_bytes` column
+    NullableBytes,
+    /// The `nullable_numeric` column
+    NullableNumeric,
+    /// The `nullable_time` column
+    NullableTime,
+    /// The `nullable_timestamp` column
+    NullableTimestamp,
+    /// The `nullable_interval` column
+    NullableInterval,
+    /// The `nullable_color` column
+    NullableColor,
+    /// The `nullable_tags` column
+    NullableTags,
+    /// The `nullable_scores` column
+    NullableScores,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- structs ---
 ////////////////////////////////////////////////////////////////////////////////////
 /// Primary data fields
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct SampleRowData {
     /// Field for column `the_name`
     pub the_name: String,
@@ -22,7 +134,7 @@ pub struct SampleRowData {
     /// Field for column `the_large_int`
     pub the_large_int: i64,
     /// Field for column `the_big_int`
-    pub the_big_int: i64,
+    pub the_big_int: U64,
     /// Field for column `the_date`
     pub the_date: chrono::NaiveDate,
     /// Field for column `the_general_int`
@@ -32,19 +144,39 @@ pub struct SampleRowData {
     /// Field for column `the_uuid`
     pub the_uuid: uuid::Uuid,
     /// Field for column `the_ulong`
-    pub the_ulong: i64,
+    pub the_ulong: U64,
     /// Field for column `the_json`
-    pub the_json: serde_json::Value,
+    pub the_json: SamplePayload,
     /// Field for column `the_jsonb`
     pub the_jsonb: serde_json::Value,
+    /// Field for column `the_double`
+    pub the_double: f64,
+    /// Field for column `the_bytes`
+    pub the_bytes: Vec<u8>,
+    /// Field for column `the_numeric`
+    pub the_numeric: rust_decimal::Decimal,
+    /// Field for column `the_time`
+    pub the_time: chrono::NaiveTime,
+    /// Field for column `the_timestamp`
+    pub the_timestamp: chrono::DateTime<chrono::Utc>,
+    /// Field for column `the_interval`
+    pub the_interval: Interval,
+    /// Field for column `the_color`
+    pub the_color: SampleColor,
+    /// Field for column `the_tags`
+    pub the_tags: Vec<String>,
+    /// Field for column `the_scores`
+    pub the_scores: Vec<i32>,
+    /// Field for column `the_uuids`
+    pub the_uuids: Vec<uuid::Uuid>,
     /// Field for column `nullable_name`
-    pub nullable_name: String,
+    pub nullable_name: Option<String>,
     /// Field for column `nullable_small_int`
     pub nullable_small_int: Option<i16>,
     /// Field for column `nullable_large_int`
     pub nullable_large_int: Option<i64>,
     /// Field for column `nullable_big_int`
-    pub nullable_big_int: Option<i64>,
+    pub nullable_big_int: Option<U64>,
     /// Field for column `nullable_date`
     pub nullable_date: Option<chrono::NaiveDate>,
     /// Field for column `nullable_general_int`
@@ -54,11 +186,29 @@ pub struct SampleRowData {
     /// Field for column `nullable_uuid`
     pub nullable_uuid: Option<uuid::Uuid>,
     /// Field for column `nullable_ulong`
-    pub nullable_ulong: Option<i64>,
+    pub nullable_ulong: Option<U64>,
     /// Field for column `nullable_json`
     pub nullable_json: Option<serde_json::Value>,
     /// Field for column `nullable_jsonb`
-    pub nullable_jsonb: serde_json::Value,
+    pub nullable_jsonb: Option<SamplePayload>,
+    /// Field for column `nullable_double`
+    pub nullable_double: Option<f64>,
+    /// Field for column `nullable_bytes`
+    pub nullable_bytes: Option<Vec<u8>>,
+    /// Field for column `nullable_numeric`
+    pub nullable_numeric: Option<rust_decimal::Decimal>,
+    /// Field for column `nullable_time`
+    pub nullable_time: Option<chrono::NaiveTime>,
+    /// Field for column `nullable_timestamp`
+    pub nullable_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Field for column `nullable_interval`
+    pub nullable_interval: Option<Interval>,
+    /// Field for column `nullable_color`
+    pub nullable_color: Option<SampleColor>,
+    /// Field for column `nullable_tags`
+    pub nullable_tags: Option<Vec<String>>,
+    /// Field for column `nullable_scores`
+    pub nullable_scores: Option<Vec<i32>>,
 }
 
 /// Primary key fields for `Sample`
@@ -75,84 +225,370 @@ pub struct SamplePkey {
 #[derive(Debug, Clone, Default)]
 pub struct TableSample {}
 
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Postgres text literal of the array, e.g. `{"a","b"}`
+///
+///   * **values** - Elements of the array
+///   * _return_ - The array literal
+#[inline]
+fn pg_array_literal<T>(values: &[T]) -> String
+where
+    T: std::fmt::Display,
+{
+    let elements = values
+        .iter()
+        .map(|value| {
+            format!(
+                "\"{}\"",
+                value.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", elements.join(","))
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- type impls ---
 ////////////////////////////////////////////////////////////////////////////////////
+impl SampleRowData {
+    /// Get the primary key of the row
+    ///
+    ///   * _return_ - The key
+    #[inline]
+    pub fn pkey(&self) -> SamplePkey {
+        SamplePkey {
+            the_name: self.the_name.clone(),
+            the_small_int: self.the_small_int,
+        }
+    }
+}
+
 impl TableSample {
+    /// Decode a row of `sample` selected with all columns in table order
+    ///
+    ///   * **row** - The selected row
+    ///   * _return_ - The decoded row or the `tokio_postgres::Error`
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<SampleRowData, tokio_postgres::Error> {
+        Ok(SampleRowData {
+            the_name: row.try_get(0)?,
+            the_small_int: row.try_get(1)?,
+            the_large_int: row.try_get(2)?,
+            the_big_int: row.try_get(3)?,
+            the_date: row.try_get(4)?,
+            the_general_int: row.try_get(5)?,
+            the_date_time: row.try_get(6)?,
+            the_uuid: row.try_get(7)?,
+            the_ulong: row.try_get(8)?,
+            the_json: row.try_get::<_, Json<SamplePayload>>(9)?.0,
+            the_jsonb: row.try_get(10)?,
+            the_double: row.try_get(11)?,
+            the_bytes: row.try_get(12)?,
+            the_numeric: row.try_get(13)?,
+            the_time: row.try_get(14)?,
+            the_timestamp: row.try_get(15)?,
+            the_interval: row.try_get(16)?,
+            the_color: row.try_get(17)?,
+            the_tags: row.try_get(18)?,
+            the_scores: row.try_get(19)?,
+            the_uuids: row.try_get(20)?,
+            nullable_name: row.try_get(21)?,
+            nullable_small_int: row.try_get(22)?,
+            nullable_large_int: row.try_get(23)?,
+            nullable_big_int: row.try_get(24)?,
+            nullable_date: row.try_get(25)?,
+            nullable_general_int: row.try_get(26)?,
+            nullable_date_time: row.try_get(27)?,
+            nullable_uuid: row.try_get(28)?,
+            nullable_ulong: row.try_get(29)?,
+            nullable_json: row.try_get(30)?,
+            nullable_jsonb: row
+                .try_get::<_, Option<Json<SamplePayload>>>(31)?
+                .map(|json| json.0),
+            nullable_double: row.try_get(32)?,
+            nullable_bytes: row.try_get(33)?,
+            nullable_numeric: row.try_get(34)?,
+            nullable_time: row.try_get(35)?,
+            nullable_timestamp: row.try_get(36)?,
+            nullable_interval: row.try_get(37)?,
+            nullable_color: row.try_get(38)?,
+            nullable_tags: row.try_get(39)?,
+            nullable_scores: row.try_get(40)?,
+        })
+    }
+
     /// Select rows of `sample` with provided where clause
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **where_clause** - The where clause (sans `where` keyword)
     ///   * **params** - Any clause parameters
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     pub async fn select_all_where<C>(
         client: &C,
         where_clause: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Vec<SampleRowData>
+    ) -> Result<Vec<SampleRowData>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
         let select_where_statement = format!(
             r#"SELECT 
     the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
-    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
-    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
     FROM sample
     WHERE {where_clause}"#
         );
         let rows = match client.query(&select_where_statement, params).await {
-            Ok(stmt) => stmt,
-            Err(e) => {
-                panic!("Error preparing statement: {e}");
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_all_where `sample` -> {err}");
+                return Err(err);
             }
         };
 
         let mut results = Vec::<SampleRowData>::with_capacity(rows.len());
 
         for row in rows {
-            results.push(SampleRowData {
-                the_name: row.get(0),
-                the_small_int: row.get(1),
-                the_large_int: row.get(2),
-                the_big_int: row.get(3),
-                the_date: row.get(4),
-                the_general_int: row.get(5),
-                the_date_time: row.get(6),
-                the_uuid: row.get(7),
-                the_ulong: row.get(8),
-                the_json: row.get(9),
-                the_jsonb: row.get(10),
-                nullable_name: row.get(11),
-                nullable_small_int: row.get(12),
-                nullable_large_int: row.get(13),
-                nullable_big_int: row.get(14),
-                nullable_date: row.get(15),
-                nullable_general_int: row.get(16),
-                nullable_date_time: row.get(17),
-                nullable_uuid: row.get(18),
-                nullable_ulong: row.get(19),
-                nullable_json: row.get(20),
-                nullable_jsonb: row.get(21),
-            });
+            results.push(Self::from_row(&row)?);
             tracing::trace!("{:?}", results.last().unwrap());
         }
-        results
+        Ok(results)
     }
 
     /// Select rows of `sample`
     ///
     ///   * **client** - The tokio postgresql client
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     #[inline]
-    pub async fn select_all<C>(client: &C) -> Vec<SampleRowData>
+    pub async fn select_all<C>(client: &C) -> Result<Vec<SampleRowData>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
         Self::select_all_where(client, "1=1", &[]).await
     }
 
+    /// Select rows of `sample` with provided where clause as a stream of rows.
+    /// The stream is not `Unpin`, so pin it (e.g. `std::pin::pin!`) to iterate with `next`.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Stream of selected rows or the `tokio_postgres::Error`
+    pub async fn select_stream_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<
+        impl futures::Stream<Item = Result<SampleRowData, tokio_postgres::Error>>,
+        tokio_postgres::Error,
+    >
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    WHERE {where_clause}"#
+        );
+        let rows = match client
+            .query_raw(&select_where_statement, params.iter().copied())
+            .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_stream_where `sample` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(rows.map(|row| row.and_then(|row| Self::from_row(&row))))
+    }
+
+    /// Select rows of `sample` with provided where clause as a stream of chunks,
+    /// fetched through a portal (cursor) bound in `transaction`.
+    ///
+    ///   * **transaction** - The transaction binding the portal
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * **chunk_size** - Maximum number of rows fetched per chunk
+    ///   * _return_ - Stream of chunks of selected rows or the `tokio_postgres::Error`
+    pub async fn select_chunks_where<'a>(
+        transaction: &'a tokio_postgres::Transaction<'a>,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+        chunk_size: i32,
+    ) -> Result<
+        impl futures::Stream<Item = Result<Vec<SampleRowData>, tokio_postgres::Error>> + 'a,
+        tokio_postgres::Error,
+    > {
+        let select_where_statement = format!(
+            r#"SELECT 
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    WHERE {where_clause}"#
+        );
+        let portal = match transaction.bind(&select_where_statement, params).await {
+            Ok(portal) => portal,
+            Err(err) => {
+                tracing::error!("Failed select_chunks_where `sample` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(futures::stream::try_unfold(
+            portal,
+            move |portal| async move {
+                let rows = transaction.query_portal(&portal, chunk_size).await?;
+                if rows.is_empty() {
+                    Ok(None)
+                } else {
+                    let chunk = rows
+                        .iter()
+                        .map(Self::from_row)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(Some((chunk, portal)))
+                }
+            },
+        ))
+    }
+
+    /// Select the page of rows of `sample` following `after` in the order of [SamplePkey].
+    /// Pass `None` for the first page, then the `next` cursor of each page for the page following.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **after** - Key of the last row of the previous page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the key of its last row as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_after<C>(
+        client: &C,
+        after: Option<&SamplePkey>,
+        limit: i64,
+    ) -> Result<Page<SampleRowData, SamplePkey>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let rows = match after {
+            None => {
+                let select_statement = r#"SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    ORDER BY the_name, the_small_int
+    LIMIT $1"#;
+                client.query(select_statement, &[&limit]).await?
+            }
+            Some(key) => {
+                let select_statement = r#"SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    WHERE (the_name, the_small_int) > ($1, $2)
+    ORDER BY the_name, the_small_int
+    LIMIT $3"#;
+                client
+                    .query(
+                        select_statement,
+                        &[&key.the_name, &key.the_small_int, &limit],
+                    )
+                    .await?
+            }
+        };
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            rows.last().map(|last| last.pkey())
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select the page of rows of `sample` at `offset` in the order of [SamplePkey].
+    /// Deep offsets scan the rows skipped, so prefer `select_page_after` to page through a table.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **offset** - Number of rows preceding the page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the offset of the page following as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_at_offset<C>(
+        client: &C,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Page<SampleRowData, i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_statement = r#"SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    ORDER BY the_name, the_small_int
+    LIMIT $1 OFFSET $2"#;
+        let rows = client.query(select_statement, &[&limit, &offset]).await?;
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            Some(offset + limit)
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select rows of `sample` matching the typed `filter`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **filter** - The filter on the columns of the table
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_filtered<C>(
+        client: &C,
+        filter: &Filter<SampleColumn>,
+    ) -> Result<Vec<SampleRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let (where_clause, params) = filter.render();
+        Self::select_all_where(client, &where_clause, &params).await
+    }
+
     /// Insert rows of `sample` by building parameterized statement.
     /// For large insertions prefer [bulk_insert]
     ///
@@ -168,11 +604,20 @@ impl TableSample {
     {
         use itertools::Itertools;
         let mut param_id = 0;
+        let the_json_json = rows
+            .iter()
+            .map(|row| Json(&row.the_json))
+            .collect::<Vec<_>>();
+        let nullable_jsonb_json = rows
+            .iter()
+            .map(|row| row.nullable_jsonb.as_ref().map(Json))
+            .collect::<Vec<_>>();
         let mut params: Vec<&(dyn ToSql + Sync)> =
             Vec::with_capacity(rows.len() * SampleRowData::NUM_FIELDS);
         let value_params = rows
             .iter()
-            .map(|row| {
+            .enumerate()
+            .map(|(i, row)| {
                 let row_params = SampleRowData::FIELD_NAMES
                     .map(|_| {
                         param_id += 1;
@@ -189,8 +634,18 @@ impl TableSample {
                 params.push(&row.the_date_time);
                 params.push(&row.the_uuid);
                 params.push(&row.the_ulong);
-                params.push(&row.the_json);
+                params.push(&the_json_json[i]);
                 params.push(&row.the_jsonb);
+                params.push(&row.the_double);
+                params.push(&row.the_bytes);
+                params.push(&row.the_numeric);
+                params.push(&row.the_time);
+                params.push(&row.the_timestamp);
+                params.push(&row.the_interval);
+                params.push(&row.the_color);
+                params.push(&row.the_tags);
+                params.push(&row.the_scores);
+                params.push(&row.the_uuids);
                 params.push(&row.nullable_name);
                 params.push(&row.nullable_small_int);
                 params.push(&row.nullable_large_int);
@@ -201,7 +656,16 @@ impl TableSample {
                 params.push(&row.nullable_uuid);
                 params.push(&row.nullable_ulong);
                 params.push(&row.nullable_json);
-                params.push(&row.nullable_jsonb);
+                params.push(&nullable_jsonb_json[i]);
+                params.push(&row.nullable_double);
+                params.push(&row.nullable_bytes);
+                params.push(&row.nullable_numeric);
+                params.push(&row.nullable_time);
+                params.push(&row.nullable_timestamp);
+                params.push(&row.nullable_interval);
+                params.push(&row.nullable_color);
+                params.push(&row.nullable_tags);
+                params.push(&row.nullable_scores);
 
                 format!("({row_params})")
             })
@@ -211,9 +675,12 @@ impl TableSample {
             r#"insert into sample 
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
-    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
-    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
     )
     VALUES
     {value_params}
@@ -261,6 +728,16 @@ impl TableSample {
         let mut the_ulong = Vec::with_capacity(chunk_size);
         let mut the_json = Vec::with_capacity(chunk_size);
         let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_double = Vec::with_capacity(chunk_size);
+        let mut the_bytes = Vec::with_capacity(chunk_size);
+        let mut the_numeric = Vec::with_capacity(chunk_size);
+        let mut the_time = Vec::with_capacity(chunk_size);
+        let mut the_timestamp = Vec::with_capacity(chunk_size);
+        let mut the_interval = Vec::with_capacity(chunk_size);
+        let mut the_color = Vec::with_capacity(chunk_size);
+        let mut the_tags = Vec::with_capacity(chunk_size);
+        let mut the_scores = Vec::with_capacity(chunk_size);
+        let mut the_uuids = Vec::with_capacity(chunk_size);
         let mut nullable_name = Vec::with_capacity(chunk_size);
         let mut nullable_small_int = Vec::with_capacity(chunk_size);
         let mut nullable_large_int = Vec::with_capacity(chunk_size);
@@ -272,21 +749,49 @@ impl TableSample {
         let mut nullable_ulong = Vec::with_capacity(chunk_size);
         let mut nullable_json = Vec::with_capacity(chunk_size);
         let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_double = Vec::with_capacity(chunk_size);
+        let mut nullable_bytes = Vec::with_capacity(chunk_size);
+        let mut nullable_numeric = Vec::with_capacity(chunk_size);
+        let mut nullable_time = Vec::with_capacity(chunk_size);
+        let mut nullable_timestamp = Vec::with_capacity(chunk_size);
+        let mut nullable_interval = Vec::with_capacity(chunk_size);
+        let mut nullable_color = Vec::with_capacity(chunk_size);
+        let mut nullable_tags = Vec::with_capacity(chunk_size);
+        let mut nullable_scores = Vec::with_capacity(chunk_size);
 
         let bulk_insert_statement = r#"insert into sample
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
-    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
-    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
     )
-    SELECT * FROM UNNEST
+    SELECT the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags::text[], the_scores::int[], the_uuids::uuid[], nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags::text[], nullable_scores::int[]
+    FROM UNNEST
     (
     	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
-    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::json[], $12::varchar[],
-    	$13::smallint[], $14::bigint[], $15::bigint[], $16::date[], $17::int[], $18::timestamp[],
-    	$19::uuid[], $20::bigint[], $21::json[], $22::json[]
-    )
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::double precision[],
+    	$13::bytea[], $14::numeric[], $15::time[], $16::timestamptz[], $17::interval[], $18::sample_color[],
+    	$19::text[], $20::text[], $21::text[], $22::varchar[], $23::smallint[], $24::bigint[],
+    	$25::bigint[], $26::date[], $27::int[], $28::timestamp[], $29::uuid[], $30::bigint[],
+    	$31::json[], $32::jsonb[], $33::double precision[], $34::bytea[], $35::numeric[], $36::time[],
+    	$37::timestamptz[], $38::interval[], $39::sample_color[], $40::text[], $41::text[]
+    ) AS unnested(the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores)
     "#;
         for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
             for row in chunk_rows.iter() {
@@ -299,8 +804,18 @@ impl TableSample {
                 the_date_time.push(row.the_date_time);
                 the_uuid.push(row.the_uuid);
                 the_ulong.push(row.the_ulong);
-                the_json.push(&row.the_json);
+                the_json.push(Json(&row.the_json));
                 the_jsonb.push(&row.the_jsonb);
+                the_double.push(row.the_double);
+                the_bytes.push(&row.the_bytes);
+                the_numeric.push(row.the_numeric);
+                the_time.push(row.the_time);
+                the_timestamp.push(row.the_timestamp);
+                the_interval.push(row.the_interval);
+                the_color.push(row.the_color);
+                the_tags.push(pg_array_literal(&row.the_tags));
+                the_scores.push(pg_array_literal(&row.the_scores));
+                the_uuids.push(pg_array_literal(&row.the_uuids));
                 nullable_name.push(&row.nullable_name);
                 nullable_small_int.push(row.nullable_small_int);
                 nullable_large_int.push(row.nullable_large_int);
@@ -311,7 +826,16 @@ impl TableSample {
                 nullable_uuid.push(row.nullable_uuid);
                 nullable_ulong.push(row.nullable_ulong);
                 nullable_json.push(&row.nullable_json);
-                nullable_jsonb.push(&row.nullable_jsonb);
+                nullable_jsonb.push(row.nullable_jsonb.as_ref().map(Json));
+                nullable_double.push(row.nullable_double);
+                nullable_bytes.push(&row.nullable_bytes);
+                nullable_numeric.push(row.nullable_numeric);
+                nullable_time.push(row.nullable_time);
+                nullable_timestamp.push(row.nullable_timestamp);
+                nullable_interval.push(row.nullable_interval);
+                nullable_color.push(row.nullable_color);
+                nullable_tags.push(row.nullable_tags.as_deref().map(pg_array_literal));
+                nullable_scores.push(row.nullable_scores.as_deref().map(pg_array_literal));
             }
 
             let chunk_result = client
@@ -329,6 +853,16 @@ impl TableSample {
                         &the_ulong,
                         &the_json,
                         &the_jsonb,
+                        &the_double,
+                        &the_bytes,
+                        &the_numeric,
+                        &the_time,
+                        &the_timestamp,
+                        &the_interval,
+                        &the_color,
+                        &the_tags,
+                        &the_scores,
+                        &the_uuids,
                         &nullable_name,
                         &nullable_small_int,
                         &nullable_large_int,
@@ -340,6 +874,15 @@ impl TableSample {
                         &nullable_ulong,
                         &nullable_json,
                         &nullable_jsonb,
+                        &nullable_double,
+                        &nullable_bytes,
+                        &nullable_numeric,
+                        &nullable_time,
+                        &nullable_timestamp,
+                        &nullable_interval,
+                        &nullable_color,
+                        &nullable_tags,
+                        &nullable_scores,
                     ],
                 )
                 .await;
@@ -364,6 +907,16 @@ impl TableSample {
             the_ulong.clear();
             the_json.clear();
             the_jsonb.clear();
+            the_double.clear();
+            the_bytes.clear();
+            the_numeric.clear();
+            the_time.clear();
+            the_timestamp.clear();
+            the_interval.clear();
+            the_color.clear();
+            the_tags.clear();
+            the_scores.clear();
+            the_uuids.clear();
             nullable_name.clear();
             nullable_small_int.clear();
             nullable_large_int.clear();
@@ -375,12 +928,211 @@ impl TableSample {
             nullable_ulong.clear();
             nullable_json.clear();
             nullable_jsonb.clear();
+            nullable_double.clear();
+            nullable_bytes.clear();
+            nullable_numeric.clear();
+            nullable_time.clear();
+            nullable_timestamp.clear();
+            nullable_interval.clear();
+            nullable_color.clear();
+            nullable_tags.clear();
+            nullable_scores.clear();
         }
 
         Ok(())
     }
 
-    /// Upsert large batch of [Sample] rows.
+    /// Insert large batch of [Sample] rows with `COPY ... FROM STDIN BINARY`.
+    /// Prefer over [bulk_insert] when the _auto id_ of inserted rows is not needed.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data to insert
+    ///   * _return_ - Number of rows copied in or tokio_postgres::Error
+    pub async fn copy_in<C>(
+        client: &C,
+        rows: &[SampleRowData],
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = client
+        .prepare(r#"SELECT NULL::varchar, NULL::smallint, NULL::bigint, NULL::bigint, NULL::date, NULL::int, NULL::timestamp, NULL::uuid, NULL::bigint, NULL::json, NULL::jsonb, NULL::double precision, NULL::bytea, NULL::numeric, NULL::time, NULL::timestamptz, NULL::interval, NULL::sample_color, NULL::text[], NULL::int[], NULL::uuid[], NULL::varchar, NULL::smallint, NULL::bigint, NULL::bigint, NULL::date, NULL::int, NULL::timestamp, NULL::uuid, NULL::bigint, NULL::json, NULL::jsonb, NULL::double precision, NULL::bytea, NULL::numeric, NULL::time, NULL::timestamptz, NULL::interval, NULL::sample_color, NULL::text[], NULL::int[]"#)
+        .await?
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect::<Vec<_>>();
+        let copy_in_statement = r#"COPY sample
+    (
+    	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    )
+    FROM STDIN BINARY"#;
+        let sink = match client.client().copy_in(copy_in_statement).await {
+            Ok(sink) => sink,
+            Err(err) => {
+                tracing::error!("Failed copy_in `sample` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut writer = std::pin::pin!(BinaryCopyInWriter::new(sink, &types));
+        for row in rows {
+            writer
+                .as_mut()
+                .write(&[
+                    &row.the_name,
+                    &row.the_small_int,
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &Json(&row.the_json),
+                    &row.the_jsonb,
+                    &row.the_double,
+                    &row.the_bytes,
+                    &row.the_numeric,
+                    &row.the_time,
+                    &row.the_timestamp,
+                    &row.the_interval,
+                    &row.the_color,
+                    &row.the_tags,
+                    &row.the_scores,
+                    &row.the_uuids,
+                    &row.nullable_name,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb.as_ref().map(Json),
+                    &row.nullable_double,
+                    &row.nullable_bytes,
+                    &row.nullable_numeric,
+                    &row.nullable_time,
+                    &row.nullable_timestamp,
+                    &row.nullable_interval,
+                    &row.nullable_color,
+                    &row.nullable_tags,
+                    &row.nullable_scores,
+                ])
+                .await?;
+        }
+        let copied = writer.finish().await?;
+        tracing::debug!("Finished copy_in of size({copied}) in `sample`");
+        Ok(copied)
+    }
+
+    /// Export rows of `sample` with provided where clause with `COPY ... TO STDOUT BINARY`.
+    /// `COPY` does not support parameters so the where clause is embedded as is and
+    /// must not contain untrusted input.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * _return_ - Copied out rows or the `tokio_postgres::Error`
+    pub async fn copy_out_where<C>(
+        client: &C,
+        where_clause: &str,
+    ) -> Result<Vec<SampleRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = client
+        .prepare(r#"SELECT NULL::varchar, NULL::smallint, NULL::bigint, NULL::bigint, NULL::date, NULL::int, NULL::timestamp, NULL::uuid, NULL::bigint, NULL::json, NULL::jsonb, NULL::double precision, NULL::bytea, NULL::numeric, NULL::time, NULL::timestamptz, NULL::interval, NULL::sample_color, NULL::text[], NULL::int[], NULL::uuid[], NULL::varchar, NULL::smallint, NULL::bigint, NULL::bigint, NULL::date, NULL::int, NULL::timestamp, NULL::uuid, NULL::bigint, NULL::json, NULL::jsonb, NULL::double precision, NULL::bytea, NULL::numeric, NULL::time, NULL::timestamptz, NULL::interval, NULL::sample_color, NULL::text[], NULL::int[]"#)
+        .await?
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect::<Vec<_>>();
+        let copy_out_statement = format!(
+            r#"COPY (SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    WHERE {where_clause}) TO STDOUT BINARY"#
+        );
+        let stream = match client.client().copy_out(&copy_out_statement).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("Failed copy_out_where `sample` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut rows = std::pin::pin!(BinaryCopyOutStream::new(stream, &types));
+
+        let mut results = Vec::<SampleRowData>::new();
+        while let Some(row) = rows.next().await {
+            let row = row?;
+            results.push(SampleRowData {
+                the_name: row.try_get(0)?,
+                the_small_int: row.try_get(1)?,
+                the_large_int: row.try_get(2)?,
+                the_big_int: row.try_get(3)?,
+                the_date: row.try_get(4)?,
+                the_general_int: row.try_get(5)?,
+                the_date_time: row.try_get(6)?,
+                the_uuid: row.try_get(7)?,
+                the_ulong: row.try_get(8)?,
+                the_json: row.try_get::<Json<SamplePayload>>(9)?.0,
+                the_jsonb: row.try_get(10)?,
+                the_double: row.try_get(11)?,
+                the_bytes: row.try_get(12)?,
+                the_numeric: row.try_get(13)?,
+                the_time: row.try_get(14)?,
+                the_timestamp: row.try_get(15)?,
+                the_interval: row.try_get(16)?,
+                the_color: row.try_get(17)?,
+                the_tags: row.try_get(18)?,
+                the_scores: row.try_get(19)?,
+                the_uuids: row.try_get(20)?,
+                nullable_name: row.try_get(21)?,
+                nullable_small_int: row.try_get(22)?,
+                nullable_large_int: row.try_get(23)?,
+                nullable_big_int: row.try_get(24)?,
+                nullable_date: row.try_get(25)?,
+                nullable_general_int: row.try_get(26)?,
+                nullable_date_time: row.try_get(27)?,
+                nullable_uuid: row.try_get(28)?,
+                nullable_ulong: row.try_get(29)?,
+                nullable_json: row.try_get(30)?,
+                nullable_jsonb: row
+                    .try_get::<Option<Json<SamplePayload>>>(31)?
+                    .map(|json| json.0),
+                nullable_double: row.try_get(32)?,
+                nullable_bytes: row.try_get(33)?,
+                nullable_numeric: row.try_get(34)?,
+                nullable_time: row.try_get(35)?,
+                nullable_timestamp: row.try_get(36)?,
+                nullable_interval: row.try_get(37)?,
+                nullable_color: row.try_get(38)?,
+                nullable_tags: row.try_get(39)?,
+                nullable_scores: row.try_get(40)?,
+            });
+        }
+        tracing::debug!(
+            "Finished copy_out_where of size({}) in `sample`",
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Upsert large batch of [Sample] rows, resolving conflicts on (the_name, the_small_int).
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **rows** - Row data to insert
@@ -405,6 +1157,16 @@ impl TableSample {
         let mut the_ulong = Vec::with_capacity(chunk_size);
         let mut the_json = Vec::with_capacity(chunk_size);
         let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_double = Vec::with_capacity(chunk_size);
+        let mut the_bytes = Vec::with_capacity(chunk_size);
+        let mut the_numeric = Vec::with_capacity(chunk_size);
+        let mut the_time = Vec::with_capacity(chunk_size);
+        let mut the_timestamp = Vec::with_capacity(chunk_size);
+        let mut the_interval = Vec::with_capacity(chunk_size);
+        let mut the_color = Vec::with_capacity(chunk_size);
+        let mut the_tags = Vec::with_capacity(chunk_size);
+        let mut the_scores = Vec::with_capacity(chunk_size);
+        let mut the_uuids = Vec::with_capacity(chunk_size);
         let mut nullable_name = Vec::with_capacity(chunk_size);
         let mut nullable_small_int = Vec::with_capacity(chunk_size);
         let mut nullable_large_int = Vec::with_capacity(chunk_size);
@@ -416,20 +1178,48 @@ impl TableSample {
         let mut nullable_ulong = Vec::with_capacity(chunk_size);
         let mut nullable_json = Vec::with_capacity(chunk_size);
         let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_double = Vec::with_capacity(chunk_size);
+        let mut nullable_bytes = Vec::with_capacity(chunk_size);
+        let mut nullable_numeric = Vec::with_capacity(chunk_size);
+        let mut nullable_time = Vec::with_capacity(chunk_size);
+        let mut nullable_timestamp = Vec::with_capacity(chunk_size);
+        let mut nullable_interval = Vec::with_capacity(chunk_size);
+        let mut nullable_color = Vec::with_capacity(chunk_size);
+        let mut nullable_tags = Vec::with_capacity(chunk_size);
+        let mut nullable_scores = Vec::with_capacity(chunk_size);
         let bulk_upsert_statement = r#"insert into sample
     (
     	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
-    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, nullable_name,
-    	nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time,
-    	nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
     )
-    SELECT * FROM UNNEST
+    SELECT the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags::text[], the_scores::int[], the_uuids::uuid[], nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags::text[], nullable_scores::int[]
+    FROM UNNEST
     (
     	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
-    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::json[], $12::varchar[],
-    	$13::smallint[], $14::bigint[], $15::bigint[], $16::date[], $17::int[], $18::timestamp[],
-    	$19::uuid[], $20::bigint[], $21::json[], $22::json[]
-    )
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::double precision[],
+    	$13::bytea[], $14::numeric[], $15::time[], $16::timestamptz[], $17::interval[], $18::sample_color[],
+    	$19::text[], $20::text[], $21::text[], $22::varchar[], $23::smallint[], $24::bigint[],
+    	$25::bigint[], $26::date[], $27::int[], $28::timestamp[], $29::uuid[], $30::bigint[],
+    	$31::json[], $32::jsonb[], $33::double precision[], $34::bytea[], $35::numeric[], $36::time[],
+    	$37::timestamptz[], $38::interval[], $39::sample_color[], $40::text[], $41::text[]
+    ) AS unnested(the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores)
     ON CONFLICT (the_name, the_small_int)
     DO UPDATE SET
         the_large_int = EXCLUDED.the_large_int,
@@ -441,6 +1231,16 @@ impl TableSample {
     	the_ulong = EXCLUDED.the_ulong,
     	the_json = EXCLUDED.the_json,
     	the_jsonb = EXCLUDED.the_jsonb,
+    	the_double = EXCLUDED.the_double,
+    	the_bytes = EXCLUDED.the_bytes,
+    	the_numeric = EXCLUDED.the_numeric,
+    	the_time = EXCLUDED.the_time,
+    	the_timestamp = EXCLUDED.the_timestamp,
+    	the_interval = EXCLUDED.the_interval,
+    	the_color = EXCLUDED.the_color,
+    	the_tags = EXCLUDED.the_tags,
+    	the_scores = EXCLUDED.the_scores,
+    	the_uuids = EXCLUDED.the_uuids,
     	nullable_name = EXCLUDED.nullable_name,
     	nullable_small_int = EXCLUDED.nullable_small_int,
     	nullable_large_int = EXCLUDED.nullable_large_int,
@@ -451,7 +1251,16 @@ impl TableSample {
     	nullable_uuid = EXCLUDED.nullable_uuid,
     	nullable_ulong = EXCLUDED.nullable_ulong,
     	nullable_json = EXCLUDED.nullable_json,
-    	nullable_jsonb = EXCLUDED.nullable_jsonb
+    	nullable_jsonb = EXCLUDED.nullable_jsonb,
+    	nullable_double = EXCLUDED.nullable_double,
+    	nullable_bytes = EXCLUDED.nullable_bytes,
+    	nullable_numeric = EXCLUDED.nullable_numeric,
+    	nullable_time = EXCLUDED.nullable_time,
+    	nullable_timestamp = EXCLUDED.nullable_timestamp,
+    	nullable_interval = EXCLUDED.nullable_interval,
+    	nullable_color = EXCLUDED.nullable_color,
+    	nullable_tags = EXCLUDED.nullable_tags,
+    	nullable_scores = EXCLUDED.nullable_scores
     "#;
         for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
             for row in chunk_rows.iter() {
@@ -464,8 +1273,18 @@ impl TableSample {
                 the_date_time.push(row.the_date_time);
                 the_uuid.push(row.the_uuid);
                 the_ulong.push(row.the_ulong);
-                the_json.push(&row.the_json);
+                the_json.push(Json(&row.the_json));
                 the_jsonb.push(&row.the_jsonb);
+                the_double.push(row.the_double);
+                the_bytes.push(&row.the_bytes);
+                the_numeric.push(row.the_numeric);
+                the_time.push(row.the_time);
+                the_timestamp.push(row.the_timestamp);
+                the_interval.push(row.the_interval);
+                the_color.push(row.the_color);
+                the_tags.push(pg_array_literal(&row.the_tags));
+                the_scores.push(pg_array_literal(&row.the_scores));
+                the_uuids.push(pg_array_literal(&row.the_uuids));
                 nullable_name.push(&row.nullable_name);
                 nullable_small_int.push(row.nullable_small_int);
                 nullable_large_int.push(row.nullable_large_int);
@@ -476,7 +1295,16 @@ impl TableSample {
                 nullable_uuid.push(row.nullable_uuid);
                 nullable_ulong.push(row.nullable_ulong);
                 nullable_json.push(&row.nullable_json);
-                nullable_jsonb.push(&row.nullable_jsonb);
+                nullable_jsonb.push(row.nullable_jsonb.as_ref().map(Json));
+                nullable_double.push(row.nullable_double);
+                nullable_bytes.push(&row.nullable_bytes);
+                nullable_numeric.push(row.nullable_numeric);
+                nullable_time.push(row.nullable_time);
+                nullable_timestamp.push(row.nullable_timestamp);
+                nullable_interval.push(row.nullable_interval);
+                nullable_color.push(row.nullable_color);
+                nullable_tags.push(row.nullable_tags.as_deref().map(pg_array_literal));
+                nullable_scores.push(row.nullable_scores.as_deref().map(pg_array_literal));
             }
             let chunk_result = client
                 .execute(
@@ -493,6 +1321,16 @@ impl TableSample {
                         &the_ulong,
                         &the_json,
                         &the_jsonb,
+                        &the_double,
+                        &the_bytes,
+                        &the_numeric,
+                        &the_time,
+                        &the_timestamp,
+                        &the_interval,
+                        &the_color,
+                        &the_tags,
+                        &the_scores,
+                        &the_uuids,
                         &nullable_name,
                         &nullable_small_int,
                         &nullable_large_int,
@@ -504,6 +1342,15 @@ impl TableSample {
                         &nullable_ulong,
                         &nullable_json,
                         &nullable_jsonb,
+                        &nullable_double,
+                        &nullable_bytes,
+                        &nullable_numeric,
+                        &nullable_time,
+                        &nullable_timestamp,
+                        &nullable_interval,
+                        &nullable_color,
+                        &nullable_tags,
+                        &nullable_scores,
                     ],
                 )
                 .await;
@@ -528,6 +1375,16 @@ impl TableSample {
             the_ulong.clear();
             the_json.clear();
             the_jsonb.clear();
+            the_double.clear();
+            the_bytes.clear();
+            the_numeric.clear();
+            the_time.clear();
+            the_timestamp.clear();
+            the_interval.clear();
+            the_color.clear();
+            the_tags.clear();
+            the_scores.clear();
+            the_uuids.clear();
             nullable_name.clear();
             nullable_small_int.clear();
             nullable_large_int.clear();
@@ -539,10 +1396,185 @@ impl TableSample {
             nullable_ulong.clear();
             nullable_json.clear();
             nullable_jsonb.clear();
+            nullable_double.clear();
+            nullable_bytes.clear();
+            nullable_numeric.clear();
+            nullable_time.clear();
+            nullable_timestamp.clear();
+            nullable_interval.clear();
+            nullable_color.clear();
+            nullable_tags.clear();
+            nullable_scores.clear();
         }
         Ok(())
     }
 
+    /// Insert large batch of [Sample] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_insert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<()>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_insert(&transaction, chunk_rows, chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_insert(&savepoint, chunk_rows, chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_insert_transactional chunk({chunk}) `sample` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
+    /// Upsert large batch of [Sample] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to upsert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_upsert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<()>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_upsert(&transaction, chunk_rows, chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_upsert(&savepoint, chunk_rows, chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_upsert_transactional chunk({chunk}) `sample` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
     /// Delete all rows of `sample`
     ///
     ///   * **client** - The tokio postgresql client
@@ -555,50 +1587,2964 @@ impl TableSample {
         let delete_statement = "DELETE FROM sample";
         client.execute(delete_statement, &[]).await
     }
-}
 
-impl SampleRowData {
-    /// Number of fields
-    pub const NUM_FIELDS: usize = 22;
+    /// Create the table `sample`, with any enum types and unique indices, if not present.
+    /// Runs the statements of [Self::CREATE_DDL], against the backdoor table name if set.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn create_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let create_statement = r#"DO $$ BEGIN
+        CREATE TYPE sample_color AS ENUM ('red', 'green', 'blue');
+    EXCEPTION
+        WHEN duplicate_object THEN NULL;
+    END $$;
+    
+    CREATE TABLE IF NOT EXISTS sample (
+    	the_name varchar(255) NOT NULL,
+    	the_small_int smallint NOT NULL,
+    	the_large_int bigint NOT NULL,
+    	the_big_int bigint NOT NULL,
+    	the_date date NOT NULL,
+    	the_general_int int NOT NULL,
+    	the_date_time timestamp NOT NULL,
+    	the_uuid uuid NOT NULL,
+    	the_ulong bigint NOT NULL,
+    	the_json json NOT NULL,
+    	the_jsonb jsonb NOT NULL,
+    	the_double double precision NOT NULL,
+    	the_bytes bytea NOT NULL,
+    	the_numeric numeric(12, 4) NOT NULL,
+    	the_time time NOT NULL,
+    	the_timestamp timestamptz NOT NULL,
+    	the_interval interval NOT NULL,
+    	the_color sample_color NOT NULL,
+    	the_tags text[] NOT NULL,
+    	the_scores int[] NOT NULL,
+    	the_uuids uuid[] NOT NULL,
+    	nullable_name varchar(255),
+    	nullable_small_int smallint,
+    	nullable_large_int bigint,
+    	nullable_big_int bigint,
+    	nullable_date date,
+    	nullable_general_int int,
+    	nullable_date_time timestamp,
+    	nullable_uuid uuid,
+    	nullable_ulong bigint,
+    	nullable_json json,
+    	nullable_jsonb jsonb,
+    	nullable_double double precision,
+    	nullable_bytes bytea,
+    	nullable_numeric numeric(12, 4),
+    	nullable_time time,
+    	nullable_timestamp timestamptz,
+    	nullable_interval interval,
+    	nullable_color sample_color,
+    	nullable_tags text[],
+    	nullable_scores int[],
+    	PRIMARY KEY (the_name, the_small_int)
+    );"#;
+        match client.batch_execute(create_statement).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                tracing::error!("Failed create_table `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
 
-    /// Names of fields
-    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
-        "the_name",
-        "the_small_int",
-        "the_large_int",
-        "the_big_int",
-        "the_date",
-        "the_general_int",
-        "the_date_time",
-        "the_uuid",
-        "the_ulong",
-        "the_json",
-        "the_jsonb",
-        "nullable_name",
-        "nullable_small_int",
-        "nullable_large_int",
-        "nullable_big_int",
-        "nullable_date",
-        "nullable_general_int",
-        "nullable_date_time",
-        "nullable_uuid",
-        "nullable_ulong",
-        "nullable_json",
-        "nullable_jsonb",
-    ];
-}
+    /// Drop the table `sample` if present, leaving any enum types in place
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn drop_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let drop_statement = "DROP TABLE IF EXISTS sample";
+        client.execute(drop_statement, &[]).await.map(|_| ())
+    }
 
-impl SamplePkey {
-    /// Number of fields
-    pub const NUM_FIELDS: usize = 2;
+    /// Verify the live table matches the columns assumed in [Self::COLUMN_SCHEMA].
+    /// Drift is returned in the report rather than as an error.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - The report of any drift or the `tokio_postgres::Error` reading the schema
+    #[inline]
+    pub async fn verify_schema<C>(client: &C) -> Result<SchemaReport, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        verify_table_schema(client, "sample", &Self::COLUMN_SCHEMA).await
+    }
 
-    /// Names of fields
-    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = ["the_name", "the_small_int"];
-}
+    /// Select the row of `sample` identified by [SamplePkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_pkey<C>(
+        client: &C,
+        key: &SamplePkey,
+    ) -> Result<Option<SampleRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut selected = Self::select_all_where(
+            client,
+            r#"the_name = $1 AND the_small_int = $2"#,
+            &[&key.the_name, &key.the_small_int],
+        )
+        .await?;
+        Ok(selected.pop())
+    }
 
-impl TableSample {
-    /// The total number of key and value columns
-    pub const COLUMN_COUNT: usize = 22;
+    /// Select the rows of `sample` identified by the batch of [SamplePkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **keys** - The keys identifying the rows
+    ///   * _return_ - The rows found
+    pub async fn select_by_pkeys<C>(
+        client: &C,
+        keys: &[SamplePkey],
+    ) -> Result<Vec<SampleRowData>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let the_name = keys.iter().map(|key| &key.the_name).collect::<Vec<_>>();
+        let the_small_int = keys.iter().map(|key| key.the_small_int).collect::<Vec<_>>();
+        Self::select_all_where(
+            client,
+            r#"(the_name, the_small_int) IN (SELECT * FROM UNNEST($1::varchar[], $2::smallint[]))"#,
+            &[&the_name, &the_small_int],
+        )
+        .await
+    }
+
+    /// Update the row of `sample` identified by [SamplePkey] with the non-key fields of `row`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * **row** - The new values for the row
+    ///   * _return_ - Number of rows updated
+    pub async fn update_by_pkey<C>(
+        client: &C,
+        key: &SamplePkey,
+        row: &SampleRowData,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let update_statement = r#"UPDATE sample SET
+        the_large_int = $1,
+    	the_big_int = $2,
+    	the_date = $3,
+    	the_general_int = $4,
+    	the_date_time = $5,
+    	the_uuid = $6,
+    	the_ulong = $7,
+    	the_json = $8,
+    	the_jsonb = $9,
+    	the_double = $10,
+    	the_bytes = $11,
+    	the_numeric = $12,
+    	the_time = $13,
+    	the_timestamp = $14,
+    	the_interval = $15,
+    	the_color = $16,
+    	the_tags = $17,
+    	the_scores = $18,
+    	the_uuids = $19,
+    	nullable_name = $20,
+    	nullable_small_int = $21,
+    	nullable_large_int = $22,
+    	nullable_big_int = $23,
+    	nullable_date = $24,
+    	nullable_general_int = $25,
+    	nullable_date_time = $26,
+    	nullable_uuid = $27,
+    	nullable_ulong = $28,
+    	nullable_json = $29,
+    	nullable_jsonb = $30,
+    	nullable_double = $31,
+    	nullable_bytes = $32,
+    	nullable_numeric = $33,
+    	nullable_time = $34,
+    	nullable_timestamp = $35,
+    	nullable_interval = $36,
+    	nullable_color = $37,
+    	nullable_tags = $38,
+    	nullable_scores = $39
+    WHERE the_name = $40 AND the_small_int = $41"#;
+        client
+            .execute(
+                update_statement,
+                &[
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &Json(&row.the_json),
+                    &row.the_jsonb,
+                    &row.the_double,
+                    &row.the_bytes,
+                    &row.the_numeric,
+                    &row.the_time,
+                    &row.the_timestamp,
+                    &row.the_interval,
+                    &row.the_color,
+                    &row.the_tags,
+                    &row.the_scores,
+                    &row.the_uuids,
+                    &row.nullable_name,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb.as_ref().map(Json),
+                    &row.nullable_double,
+                    &row.nullable_bytes,
+                    &row.nullable_numeric,
+                    &row.nullable_time,
+                    &row.nullable_timestamp,
+                    &row.nullable_interval,
+                    &row.nullable_color,
+                    &row.nullable_tags,
+                    &row.nullable_scores,
+                    &key.the_name,
+                    &key.the_small_int,
+                ],
+            )
+            .await
+    }
+
+    /// Delete the row of `sample` identified by [SamplePkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - Number of rows deleted
+    pub async fn delete_by_pkey<C>(
+        client: &C,
+        key: &SamplePkey,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement = r#"DELETE FROM sample WHERE the_name = $1 AND the_small_int = $2"#;
+        client
+            .execute(delete_statement, &[&key.the_name, &key.the_small_int])
+            .await
+    }
+
+    /// Update large batch of rows of `sample` identified by [SamplePkey] with their non-key fields
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - The rows with the new values
+    ///   * **chunk_size** - How to chunk the updates
+    ///   * _return_ - Number of rows updated
+    pub async fn bulk_update<C>(
+        client: &C,
+        rows: &[SampleRowData],
+        chunk_size: usize,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_double = Vec::with_capacity(chunk_size);
+        let mut the_bytes = Vec::with_capacity(chunk_size);
+        let mut the_numeric = Vec::with_capacity(chunk_size);
+        let mut the_time = Vec::with_capacity(chunk_size);
+        let mut the_timestamp = Vec::with_capacity(chunk_size);
+        let mut the_interval = Vec::with_capacity(chunk_size);
+        let mut the_color = Vec::with_capacity(chunk_size);
+        let mut the_tags = Vec::with_capacity(chunk_size);
+        let mut the_scores = Vec::with_capacity(chunk_size);
+        let mut the_uuids = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_double = Vec::with_capacity(chunk_size);
+        let mut nullable_bytes = Vec::with_capacity(chunk_size);
+        let mut nullable_numeric = Vec::with_capacity(chunk_size);
+        let mut nullable_time = Vec::with_capacity(chunk_size);
+        let mut nullable_timestamp = Vec::with_capacity(chunk_size);
+        let mut nullable_interval = Vec::with_capacity(chunk_size);
+        let mut nullable_color = Vec::with_capacity(chunk_size);
+        let mut nullable_tags = Vec::with_capacity(chunk_size);
+        let mut nullable_scores = Vec::with_capacity(chunk_size);
+        let mut affected = 0;
+        let bulk_update_statement = r#"UPDATE sample AS t SET
+        the_large_int = u.the_large_int,
+    	the_big_int = u.the_big_int,
+    	the_date = u.the_date,
+    	the_general_int = u.the_general_int,
+    	the_date_time = u.the_date_time,
+    	the_uuid = u.the_uuid,
+    	the_ulong = u.the_ulong,
+    	the_json = u.the_json,
+    	the_jsonb = u.the_jsonb,
+    	the_double = u.the_double,
+    	the_bytes = u.the_bytes,
+    	the_numeric = u.the_numeric,
+    	the_time = u.the_time,
+    	the_timestamp = u.the_timestamp,
+    	the_interval = u.the_interval,
+    	the_color = u.the_color,
+    	the_tags = u.the_tags::text[],
+    	the_scores = u.the_scores::int[],
+    	the_uuids = u.the_uuids::uuid[],
+    	nullable_name = u.nullable_name,
+    	nullable_small_int = u.nullable_small_int,
+    	nullable_large_int = u.nullable_large_int,
+    	nullable_big_int = u.nullable_big_int,
+    	nullable_date = u.nullable_date,
+    	nullable_general_int = u.nullable_general_int,
+    	nullable_date_time = u.nullable_date_time,
+    	nullable_uuid = u.nullable_uuid,
+    	nullable_ulong = u.nullable_ulong,
+    	nullable_json = u.nullable_json,
+    	nullable_jsonb = u.nullable_jsonb,
+    	nullable_double = u.nullable_double,
+    	nullable_bytes = u.nullable_bytes,
+    	nullable_numeric = u.nullable_numeric,
+    	nullable_time = u.nullable_time,
+    	nullable_timestamp = u.nullable_timestamp,
+    	nullable_interval = u.nullable_interval,
+    	nullable_color = u.nullable_color,
+    	nullable_tags = u.nullable_tags::text[],
+    	nullable_scores = u.nullable_scores::int[]
+    FROM UNNEST($1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[], $7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::double precision[], $13::bytea[], $14::numeric[], $15::time[], $16::timestamptz[], $17::interval[], $18::sample_color[], $19::text[], $20::text[], $21::text[], $22::varchar[], $23::smallint[], $24::bigint[], $25::bigint[], $26::date[], $27::int[], $28::timestamp[], $29::uuid[], $30::bigint[], $31::json[], $32::jsonb[], $33::double precision[], $34::bytea[], $35::numeric[], $36::time[], $37::timestamptz[], $38::interval[], $39::sample_color[], $40::text[], $41::text[]) AS u(the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double, the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color, the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time, nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores)
+    WHERE t.the_name = u.the_name AND t.the_small_int = u.the_small_int"#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for item in chunk_rows.iter() {
+                the_name.push(&item.the_name);
+                the_small_int.push(item.the_small_int);
+                the_large_int.push(item.the_large_int);
+                the_big_int.push(item.the_big_int);
+                the_date.push(item.the_date);
+                the_general_int.push(item.the_general_int);
+                the_date_time.push(item.the_date_time);
+                the_uuid.push(item.the_uuid);
+                the_ulong.push(item.the_ulong);
+                the_json.push(Json(&item.the_json));
+                the_jsonb.push(&item.the_jsonb);
+                the_double.push(item.the_double);
+                the_bytes.push(&item.the_bytes);
+                the_numeric.push(item.the_numeric);
+                the_time.push(item.the_time);
+                the_timestamp.push(item.the_timestamp);
+                the_interval.push(item.the_interval);
+                the_color.push(item.the_color);
+                the_tags.push(pg_array_literal(&item.the_tags));
+                the_scores.push(pg_array_literal(&item.the_scores));
+                the_uuids.push(pg_array_literal(&item.the_uuids));
+                nullable_name.push(&item.nullable_name);
+                nullable_small_int.push(item.nullable_small_int);
+                nullable_large_int.push(item.nullable_large_int);
+                nullable_big_int.push(item.nullable_big_int);
+                nullable_date.push(item.nullable_date);
+                nullable_general_int.push(item.nullable_general_int);
+                nullable_date_time.push(item.nullable_date_time);
+                nullable_uuid.push(item.nullable_uuid);
+                nullable_ulong.push(item.nullable_ulong);
+                nullable_json.push(&item.nullable_json);
+                nullable_jsonb.push(item.nullable_jsonb.as_ref().map(Json));
+                nullable_double.push(item.nullable_double);
+                nullable_bytes.push(&item.nullable_bytes);
+                nullable_numeric.push(item.nullable_numeric);
+                nullable_time.push(item.nullable_time);
+                nullable_timestamp.push(item.nullable_timestamp);
+                nullable_interval.push(item.nullable_interval);
+                nullable_color.push(item.nullable_color);
+                nullable_tags.push(item.nullable_tags.as_deref().map(pg_array_literal));
+                nullable_scores.push(item.nullable_scores.as_deref().map(pg_array_literal));
+            }
+
+            let chunk_result = client
+                .execute(
+                    bulk_update_statement,
+                    &[
+                        &the_name,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_double,
+                        &the_bytes,
+                        &the_numeric,
+                        &the_time,
+                        &the_timestamp,
+                        &the_interval,
+                        &the_color,
+                        &the_tags,
+                        &the_scores,
+                        &the_uuids,
+                        &nullable_name,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                        &nullable_double,
+                        &nullable_bytes,
+                        &nullable_numeric,
+                        &nullable_time,
+                        &nullable_timestamp,
+                        &nullable_interval,
+                        &nullable_color,
+                        &nullable_tags,
+                        &nullable_scores,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_update `sample` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!("Finished bulk update of size({}) in `sample`", chunk_result);
+                    affected += chunk_result;
+                }
+            }
+            the_name.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_double.clear();
+            the_bytes.clear();
+            the_numeric.clear();
+            the_time.clear();
+            the_timestamp.clear();
+            the_interval.clear();
+            the_color.clear();
+            the_tags.clear();
+            the_scores.clear();
+            the_uuids.clear();
+            nullable_name.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+            nullable_double.clear();
+            nullable_bytes.clear();
+            nullable_numeric.clear();
+            nullable_time.clear();
+            nullable_timestamp.clear();
+            nullable_interval.clear();
+            nullable_color.clear();
+            nullable_tags.clear();
+            nullable_scores.clear();
+        }
+
+        Ok(affected)
+    }
+
+    /// Delete large batch of rows of `sample` identified by [SamplePkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **keys** - The keys identifying the rows
+    ///   * **chunk_size** - How to chunk the deletes
+    ///   * _return_ - Number of rows deleted
+    pub async fn bulk_delete_by_pkeys<C>(
+        client: &C,
+        keys: &[SamplePkey],
+        chunk_size: usize,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut affected = 0;
+        let bulk_delete_statement = r#"DELETE FROM sample WHERE (the_name, the_small_int) IN (SELECT * FROM UNNEST($1::varchar[], $2::smallint[]))"#;
+        for (chunk, chunk_keys) in keys.chunks(chunk_size).enumerate() {
+            for item in chunk_keys.iter() {
+                the_name.push(&item.the_name);
+                the_small_int.push(item.the_small_int);
+            }
+
+            let chunk_result = client
+                .execute(bulk_delete_statement, &[&the_name, &the_small_int])
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_delete_by_pkeys `sample` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!("Finished bulk delete of size({}) in `sample`", chunk_result);
+                    affected += chunk_result;
+                }
+            }
+            the_name.clear();
+            the_small_int.clear();
+        }
+
+        Ok(affected)
+    }
+
+    /// Make `sample` match `rows` exactly, writing only the rows that differ.
+    /// Rows with a key absent from the table are inserted, rows with a key present but other values
+    /// are updated and rows of the table with a key absent from `rows` are deleted, all in one transaction.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - The rows the table is to match, the last row kept where a primary key repeats
+    ///   * _return_ - Summary of the changes applied, or the `tokio_postgres::Error` rolling them back
+    pub async fn sync_rows<C>(
+        client: &mut C,
+        rows: &[SampleRowData],
+    ) -> Result<SyncSummary, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let transaction = client.transaction().await?;
+        let current_rows = Self::select_all(&transaction).await?;
+        let mut current_by_key = current_rows
+            .iter()
+            .map(|current| (current.pkey(), current))
+            .collect::<std::collections::HashMap<_, _>>();
+        // Keep only the last row for each key, in the order of the rows kept
+        let mut seen = std::collections::HashSet::new();
+        let mut unique_rows = rows
+            .iter()
+            .rev()
+            .filter(|row| seen.insert(row.pkey()))
+            .collect::<Vec<_>>();
+        unique_rows.reverse();
+        let mut inserts = Vec::new();
+        let mut updates = Vec::new();
+        let mut unchanged = 0;
+        for row in unique_rows {
+            match current_by_key.remove(&row.pkey()) {
+                None => inserts.push(row.clone()),
+                Some(current) if current == row => unchanged += 1,
+                Some(_) => updates.push(row.clone()),
+            }
+        }
+        let deletes = current_by_key.into_keys().collect::<Vec<_>>();
+
+        let deleted = if deletes.is_empty() {
+            0
+        } else {
+            Self::bulk_delete_by_pkeys(&transaction, &deletes, deletes.len()).await?
+        };
+        let updated = if updates.is_empty() {
+            0
+        } else {
+            Self::bulk_update(&transaction, &updates, updates.len()).await?
+        };
+        if !inserts.is_empty() {
+            Self::bulk_upsert(&transaction, &inserts, inserts.len()).await?;
+        }
+        transaction.commit().await?;
+
+        let summary = SyncSummary {
+            inserted: inserts.len() as u64,
+            updated,
+            deleted,
+            unchanged,
+        };
+        tracing::debug!(
+            "Finished sync of size({}) in `sample` -> {summary:?}",
+            rows.len()
+        );
+        Ok(summary)
+    }
+
+    /// Count rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Number of rows matched or the `tokio_postgres::Error`
+    pub async fn count_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<i64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement = format!(r#"SELECT count(*) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed count_where `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Check for any row of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - True if any row matched or the `tokio_postgres::Error`
+    pub async fn exists_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<bool, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT EXISTS (SELECT 1 FROM sample WHERE {where_clause})"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed exists_where `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_name` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_name) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_name `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_name` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_name) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_name `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_small_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_small_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_small_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_small_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_small_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_small_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_large_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_large_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_large_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_large_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_large_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_large_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_big_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_big_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_big_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_big_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_big_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_big_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_date) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_date) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_general_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_general_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_general_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_general_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_general_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_general_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_date_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_date_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_ulong` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_ulong) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_ulong `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_ulong` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_ulong) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_ulong `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_double` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_double<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<f64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_double) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_double `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_double` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_double<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<f64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_double) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_double `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_numeric` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_numeric<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<rust_decimal::Decimal>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_numeric) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_numeric `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_numeric` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_numeric<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<rust_decimal::Decimal>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_numeric) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_numeric `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_timestamp` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_timestamp<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_timestamp) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_timestamp `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_timestamp` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_timestamp<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_timestamp) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_timestamp `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_interval` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_interval<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<Interval>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_interval) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_interval `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_interval` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_interval<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<Interval>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_interval) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_interval `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_name` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_name) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_name `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_name` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_name) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_name `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_small_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_small_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_small_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_small_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_small_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_small_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_large_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_large_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_large_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_large_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_large_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_large_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_big_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_big_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_big_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_big_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_big_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_big_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_date) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_date) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_general_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_general_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_general_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_general_int` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_general_int) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_general_int `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_date_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_date_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_ulong` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_ulong) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_ulong `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_ulong` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_ulong) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_ulong `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_double` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_double<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<f64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_double) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_double `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_double` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_double<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<f64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_double) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_double `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_numeric` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_numeric<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<rust_decimal::Decimal>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_numeric) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_numeric `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_numeric` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_numeric<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<rust_decimal::Decimal>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_numeric) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_numeric `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_time` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_time) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_time `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_timestamp` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_timestamp<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_timestamp) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_timestamp `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_timestamp` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_timestamp<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_timestamp) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_timestamp `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_interval` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_interval<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<Interval>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_interval) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_interval `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_interval` of rows of `sample` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_interval<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<Interval>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_interval) FROM sample WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_interval `sample` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Insert large batch of [Sample] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ - Success or tokio_postgres::Error
+    pub async fn bulk_insert_prepared<C>(
+        client: &C,
+        rows: &[SampleRowData],
+        chunk_size: usize,
+    ) -> Result<(), tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_double = Vec::with_capacity(chunk_size);
+        let mut the_bytes = Vec::with_capacity(chunk_size);
+        let mut the_numeric = Vec::with_capacity(chunk_size);
+        let mut the_time = Vec::with_capacity(chunk_size);
+        let mut the_timestamp = Vec::with_capacity(chunk_size);
+        let mut the_interval = Vec::with_capacity(chunk_size);
+        let mut the_color = Vec::with_capacity(chunk_size);
+        let mut the_tags = Vec::with_capacity(chunk_size);
+        let mut the_scores = Vec::with_capacity(chunk_size);
+        let mut the_uuids = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_double = Vec::with_capacity(chunk_size);
+        let mut nullable_bytes = Vec::with_capacity(chunk_size);
+        let mut nullable_numeric = Vec::with_capacity(chunk_size);
+        let mut nullable_time = Vec::with_capacity(chunk_size);
+        let mut nullable_timestamp = Vec::with_capacity(chunk_size);
+        let mut nullable_interval = Vec::with_capacity(chunk_size);
+        let mut nullable_color = Vec::with_capacity(chunk_size);
+        let mut nullable_tags = Vec::with_capacity(chunk_size);
+        let mut nullable_scores = Vec::with_capacity(chunk_size);
+
+        let bulk_insert_statement = r#"insert into sample
+    (
+    	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    )
+    SELECT the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags::text[], the_scores::int[], the_uuids::uuid[], nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags::text[], nullable_scores::int[]
+    FROM UNNEST
+    (
+    	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::double precision[],
+    	$13::bytea[], $14::numeric[], $15::time[], $16::timestamptz[], $17::interval[], $18::sample_color[],
+    	$19::text[], $20::text[], $21::text[], $22::varchar[], $23::smallint[], $24::bigint[],
+    	$25::bigint[], $26::date[], $27::int[], $28::timestamp[], $29::uuid[], $30::bigint[],
+    	$31::json[], $32::jsonb[], $33::double precision[], $34::bytea[], $35::numeric[], $36::time[],
+    	$37::timestamptz[], $38::interval[], $39::sample_color[], $40::text[], $41::text[]
+    ) AS unnested(the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores)
+    "#;
+        let prepared = client.prepare_cached(bulk_insert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(Json(&row.the_json));
+                the_jsonb.push(&row.the_jsonb);
+                the_double.push(row.the_double);
+                the_bytes.push(&row.the_bytes);
+                the_numeric.push(row.the_numeric);
+                the_time.push(row.the_time);
+                the_timestamp.push(row.the_timestamp);
+                the_interval.push(row.the_interval);
+                the_color.push(row.the_color);
+                the_tags.push(pg_array_literal(&row.the_tags));
+                the_scores.push(pg_array_literal(&row.the_scores));
+                the_uuids.push(pg_array_literal(&row.the_uuids));
+                nullable_name.push(&row.nullable_name);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(row.nullable_jsonb.as_ref().map(Json));
+                nullable_double.push(row.nullable_double);
+                nullable_bytes.push(&row.nullable_bytes);
+                nullable_numeric.push(row.nullable_numeric);
+                nullable_time.push(row.nullable_time);
+                nullable_timestamp.push(row.nullable_timestamp);
+                nullable_interval.push(row.nullable_interval);
+                nullable_color.push(row.nullable_color);
+                nullable_tags.push(row.nullable_tags.as_deref().map(pg_array_literal));
+                nullable_scores.push(row.nullable_scores.as_deref().map(pg_array_literal));
+            }
+
+            let chunk_result = client
+                .execute(
+                    &prepared,
+                    &[
+                        &the_name,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_double,
+                        &the_bytes,
+                        &the_numeric,
+                        &the_time,
+                        &the_timestamp,
+                        &the_interval,
+                        &the_color,
+                        &the_tags,
+                        &the_scores,
+                        &the_uuids,
+                        &nullable_name,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                        &nullable_double,
+                        &nullable_bytes,
+                        &nullable_numeric,
+                        &nullable_time,
+                        &nullable_timestamp,
+                        &nullable_interval,
+                        &nullable_color,
+                        &nullable_tags,
+                        &nullable_scores,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_insert `sample` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!("Finished bulk insert of size({}) in `sample`", chunk_result);
+                }
+            }
+            the_name.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_double.clear();
+            the_bytes.clear();
+            the_numeric.clear();
+            the_time.clear();
+            the_timestamp.clear();
+            the_interval.clear();
+            the_color.clear();
+            the_tags.clear();
+            the_scores.clear();
+            the_uuids.clear();
+            nullable_name.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+            nullable_double.clear();
+            nullable_bytes.clear();
+            nullable_numeric.clear();
+            nullable_time.clear();
+            nullable_timestamp.clear();
+            nullable_interval.clear();
+            nullable_color.clear();
+            nullable_tags.clear();
+            nullable_scores.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Upsert large batch of [Sample] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert_prepared<C>(
+        client: &C,
+        rows: &[SampleRowData],
+        chunk_size: usize,
+    ) -> Result<(), tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_double = Vec::with_capacity(chunk_size);
+        let mut the_bytes = Vec::with_capacity(chunk_size);
+        let mut the_numeric = Vec::with_capacity(chunk_size);
+        let mut the_time = Vec::with_capacity(chunk_size);
+        let mut the_timestamp = Vec::with_capacity(chunk_size);
+        let mut the_interval = Vec::with_capacity(chunk_size);
+        let mut the_color = Vec::with_capacity(chunk_size);
+        let mut the_tags = Vec::with_capacity(chunk_size);
+        let mut the_scores = Vec::with_capacity(chunk_size);
+        let mut the_uuids = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut nullable_double = Vec::with_capacity(chunk_size);
+        let mut nullable_bytes = Vec::with_capacity(chunk_size);
+        let mut nullable_numeric = Vec::with_capacity(chunk_size);
+        let mut nullable_time = Vec::with_capacity(chunk_size);
+        let mut nullable_timestamp = Vec::with_capacity(chunk_size);
+        let mut nullable_interval = Vec::with_capacity(chunk_size);
+        let mut nullable_color = Vec::with_capacity(chunk_size);
+        let mut nullable_tags = Vec::with_capacity(chunk_size);
+        let mut nullable_scores = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"insert into sample
+    (
+    	the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    )
+    SELECT the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags::text[], the_scores::int[], the_uuids::uuid[], nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags::text[], nullable_scores::int[]
+    FROM UNNEST
+    (
+    	$1::varchar[], $2::smallint[], $3::bigint[], $4::bigint[], $5::date[], $6::int[],
+    	$7::timestamp[], $8::uuid[], $9::bigint[], $10::json[], $11::jsonb[], $12::double precision[],
+    	$13::bytea[], $14::numeric[], $15::time[], $16::timestamptz[], $17::interval[], $18::sample_color[],
+    	$19::text[], $20::text[], $21::text[], $22::varchar[], $23::smallint[], $24::bigint[],
+    	$25::bigint[], $26::date[], $27::int[], $28::timestamp[], $29::uuid[], $30::bigint[],
+    	$31::json[], $32::jsonb[], $33::double precision[], $34::bytea[], $35::numeric[], $36::time[],
+    	$37::timestamptz[], $38::interval[], $39::sample_color[], $40::text[], $41::text[]
+    ) AS unnested(the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores)
+    ON CONFLICT (the_name, the_small_int)
+    DO UPDATE SET
+        the_large_int = EXCLUDED.the_large_int,
+    	the_big_int = EXCLUDED.the_big_int,
+    	the_date = EXCLUDED.the_date,
+    	the_general_int = EXCLUDED.the_general_int,
+    	the_date_time = EXCLUDED.the_date_time,
+    	the_uuid = EXCLUDED.the_uuid,
+    	the_ulong = EXCLUDED.the_ulong,
+    	the_json = EXCLUDED.the_json,
+    	the_jsonb = EXCLUDED.the_jsonb,
+    	the_double = EXCLUDED.the_double,
+    	the_bytes = EXCLUDED.the_bytes,
+    	the_numeric = EXCLUDED.the_numeric,
+    	the_time = EXCLUDED.the_time,
+    	the_timestamp = EXCLUDED.the_timestamp,
+    	the_interval = EXCLUDED.the_interval,
+    	the_color = EXCLUDED.the_color,
+    	the_tags = EXCLUDED.the_tags,
+    	the_scores = EXCLUDED.the_scores,
+    	the_uuids = EXCLUDED.the_uuids,
+    	nullable_name = EXCLUDED.nullable_name,
+    	nullable_small_int = EXCLUDED.nullable_small_int,
+    	nullable_large_int = EXCLUDED.nullable_large_int,
+    	nullable_big_int = EXCLUDED.nullable_big_int,
+    	nullable_date = EXCLUDED.nullable_date,
+    	nullable_general_int = EXCLUDED.nullable_general_int,
+    	nullable_date_time = EXCLUDED.nullable_date_time,
+    	nullable_uuid = EXCLUDED.nullable_uuid,
+    	nullable_ulong = EXCLUDED.nullable_ulong,
+    	nullable_json = EXCLUDED.nullable_json,
+    	nullable_jsonb = EXCLUDED.nullable_jsonb,
+    	nullable_double = EXCLUDED.nullable_double,
+    	nullable_bytes = EXCLUDED.nullable_bytes,
+    	nullable_numeric = EXCLUDED.nullable_numeric,
+    	nullable_time = EXCLUDED.nullable_time,
+    	nullable_timestamp = EXCLUDED.nullable_timestamp,
+    	nullable_interval = EXCLUDED.nullable_interval,
+    	nullable_color = EXCLUDED.nullable_color,
+    	nullable_tags = EXCLUDED.nullable_tags,
+    	nullable_scores = EXCLUDED.nullable_scores
+    "#;
+        let prepared = client.prepare_cached(bulk_upsert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(Json(&row.the_json));
+                the_jsonb.push(&row.the_jsonb);
+                the_double.push(row.the_double);
+                the_bytes.push(&row.the_bytes);
+                the_numeric.push(row.the_numeric);
+                the_time.push(row.the_time);
+                the_timestamp.push(row.the_timestamp);
+                the_interval.push(row.the_interval);
+                the_color.push(row.the_color);
+                the_tags.push(pg_array_literal(&row.the_tags));
+                the_scores.push(pg_array_literal(&row.the_scores));
+                the_uuids.push(pg_array_literal(&row.the_uuids));
+                nullable_name.push(&row.nullable_name);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(row.nullable_jsonb.as_ref().map(Json));
+                nullable_double.push(row.nullable_double);
+                nullable_bytes.push(&row.nullable_bytes);
+                nullable_numeric.push(row.nullable_numeric);
+                nullable_time.push(row.nullable_time);
+                nullable_timestamp.push(row.nullable_timestamp);
+                nullable_interval.push(row.nullable_interval);
+                nullable_color.push(row.nullable_color);
+                nullable_tags.push(row.nullable_tags.as_deref().map(pg_array_literal));
+                nullable_scores.push(row.nullable_scores.as_deref().map(pg_array_literal));
+            }
+            let chunk_result = client
+                .execute(
+                    &prepared,
+                    &[
+                        &the_name,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_double,
+                        &the_bytes,
+                        &the_numeric,
+                        &the_time,
+                        &the_timestamp,
+                        &the_interval,
+                        &the_color,
+                        &the_tags,
+                        &the_scores,
+                        &the_uuids,
+                        &nullable_name,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                        &nullable_double,
+                        &nullable_bytes,
+                        &nullable_numeric,
+                        &nullable_time,
+                        &nullable_timestamp,
+                        &nullable_interval,
+                        &nullable_color,
+                        &nullable_tags,
+                        &nullable_scores,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert `sample` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!("Finished bulk upsert of size({}) in `sample`", chunk_result);
+                }
+            }
+            the_name.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_double.clear();
+            the_bytes.clear();
+            the_numeric.clear();
+            the_time.clear();
+            the_timestamp.clear();
+            the_interval.clear();
+            the_color.clear();
+            the_tags.clear();
+            the_scores.clear();
+            the_uuids.clear();
+            nullable_name.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+            nullable_double.clear();
+            nullable_bytes.clear();
+            nullable_numeric.clear();
+            nullable_time.clear();
+            nullable_timestamp.clear();
+            nullable_interval.clear();
+            nullable_color.clear();
+            nullable_tags.clear();
+            nullable_scores.clear();
+        }
+        Ok(())
+    }
+
+    /// Delete all rows of `sample` with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn delete_all_prepared<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let delete_statement = "DELETE FROM sample";
+        let prepared = client.prepare_cached(delete_statement).await?;
+        client.execute(&prepared, &[]).await
+    }
+
+    /// Select the row of `sample` identified by [SamplePkey] with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_pkey_prepared<C>(
+        client: &C,
+        key: &SamplePkey,
+    ) -> Result<Option<SampleRowData>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let select_statement = r#"SELECT
+    the_name, the_small_int, the_large_int, the_big_int, the_date, the_general_int,
+    	the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_double,
+    	the_bytes, the_numeric, the_time, the_timestamp, the_interval, the_color,
+    	the_tags, the_scores, the_uuids, nullable_name, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb, nullable_double, nullable_bytes, nullable_numeric, nullable_time,
+    	nullable_timestamp, nullable_interval, nullable_color, nullable_tags, nullable_scores
+    FROM sample
+    WHERE the_name = $1 AND the_small_int = $2"#;
+        let prepared = client.prepare_cached(select_statement).await?;
+        client
+            .query_opt(&prepared, &[&key.the_name, &key.the_small_int])
+            .await?
+            .map(|row| Self::from_row(&row))
+            .transpose()
+    }
+}
+
+impl SampleColumn {
+    /// All columns of the table
+    pub const ALL: [SampleColumn; 41] = [
+        SampleColumn::TheName,
+        SampleColumn::TheSmallInt,
+        SampleColumn::TheLargeInt,
+        SampleColumn::TheBigInt,
+        SampleColumn::TheDate,
+        SampleColumn::TheGeneralInt,
+        SampleColumn::TheDateTime,
+        SampleColumn::TheUuid,
+        SampleColumn::TheUlong,
+        SampleColumn::TheJson,
+        SampleColumn::TheJsonb,
+        SampleColumn::TheDouble,
+        SampleColumn::TheBytes,
+        SampleColumn::TheNumeric,
+        SampleColumn::TheTime,
+        SampleColumn::TheTimestamp,
+        SampleColumn::TheInterval,
+        SampleColumn::TheColor,
+        SampleColumn::TheTags,
+        SampleColumn::TheScores,
+        SampleColumn::TheUuids,
+        SampleColumn::NullableName,
+        SampleColumn::NullableSmallInt,
+        SampleColumn::NullableLargeInt,
+        SampleColumn::NullableBigInt,
+        SampleColumn::NullableDate,
+        SampleColumn::NullableGeneralInt,
+        SampleColumn::NullableDateTime,
+        SampleColumn::NullableUuid,
+        SampleColumn::NullableUlong,
+        SampleColumn::NullableJson,
+        SampleColumn::NullableJsonb,
+        SampleColumn::NullableDouble,
+        SampleColumn::NullableBytes,
+        SampleColumn::NullableNumeric,
+        SampleColumn::NullableTime,
+        SampleColumn::NullableTimestamp,
+        SampleColumn::NullableInterval,
+        SampleColumn::NullableColor,
+        SampleColumn::NullableTags,
+        SampleColumn::NullableScores,
+    ];
+}
+
+impl SampleRowData {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 41;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
+        "the_name",
+        "the_small_int",
+        "the_large_int",
+        "the_big_int",
+        "the_date",
+        "the_general_int",
+        "the_date_time",
+        "the_uuid",
+        "the_ulong",
+        "the_json",
+        "the_jsonb",
+        "the_double",
+        "the_bytes",
+        "the_numeric",
+        "the_time",
+        "the_timestamp",
+        "the_interval",
+        "the_color",
+        "the_tags",
+        "the_scores",
+        "the_uuids",
+        "nullable_name",
+        "nullable_small_int",
+        "nullable_large_int",
+        "nullable_big_int",
+        "nullable_date",
+        "nullable_general_int",
+        "nullable_date_time",
+        "nullable_uuid",
+        "nullable_ulong",
+        "nullable_json",
+        "nullable_jsonb",
+        "nullable_double",
+        "nullable_bytes",
+        "nullable_numeric",
+        "nullable_time",
+        "nullable_timestamp",
+        "nullable_interval",
+        "nullable_color",
+        "nullable_tags",
+        "nullable_scores",
+    ];
+}
+
+impl SamplePkey {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 2;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = ["the_name", "the_small_int"];
+}
+
+impl TableSample {
+    /// The total number of key and value columns
+    pub const COLUMN_COUNT: usize = 41;
+
+    /// Statements creating the table `sample`, its enum types and unique indices
+    pub const CREATE_DDL: &str = r#"DO $$ BEGIN
+      CREATE TYPE sample_color AS ENUM ('red', 'green', 'blue');
+  EXCEPTION
+      WHEN duplicate_object THEN NULL;
+  END $$;
+  
+  CREATE TABLE IF NOT EXISTS sample (
+  	the_name varchar(255) NOT NULL,
+  	the_small_int smallint NOT NULL,
+  	the_large_int bigint NOT NULL,
+  	the_big_int bigint NOT NULL,
+  	the_date date NOT NULL,
+  	the_general_int int NOT NULL,
+  	the_date_time timestamp NOT NULL,
+  	the_uuid uuid NOT NULL,
+  	the_ulong bigint NOT NULL,
+  	the_json json NOT NULL,
+  	the_jsonb jsonb NOT NULL,
+  	the_double double precision NOT NULL,
+  	the_bytes bytea NOT NULL,
+  	the_numeric numeric(12, 4) NOT NULL,
+  	the_time time NOT NULL,
+  	the_timestamp timestamptz NOT NULL,
+  	the_interval interval NOT NULL,
+  	the_color sample_color NOT NULL,
+  	the_tags text[] NOT NULL,
+  	the_scores int[] NOT NULL,
+  	the_uuids uuid[] NOT NULL,
+  	nullable_name varchar(255),
+  	nullable_small_int smallint,
+  	nullable_large_int bigint,
+  	nullable_big_int bigint,
+  	nullable_date date,
+  	nullable_general_int int,
+  	nullable_date_time timestamp,
+  	nullable_uuid uuid,
+  	nullable_ulong bigint,
+  	nullable_json json,
+  	nullable_jsonb jsonb,
+  	nullable_double double precision,
+  	nullable_bytes bytea,
+  	nullable_numeric numeric(12, 4),
+  	nullable_time time,
+  	nullable_timestamp timestamptz,
+  	nullable_interval interval,
+  	nullable_color sample_color,
+  	nullable_tags text[],
+  	nullable_scores int[],
+  	PRIMARY KEY (the_name, the_small_int)
+  );"#;
+
+    /// The columns of the table, in order, as assumed by the generated code
+    pub const COLUMN_SCHEMA: [ColumnSchema; Self::COLUMN_COUNT] = [
+        ColumnSchema {
+            name: "the_name",
+            udt_name: "varchar",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_small_int",
+            udt_name: "int2",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_large_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_big_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date",
+            udt_name: "date",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_general_int",
+            udt_name: "int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date_time",
+            udt_name: "timestamp",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_uuid",
+            udt_name: "uuid",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_ulong",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_json",
+            udt_name: "json",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_jsonb",
+            udt_name: "jsonb",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_double",
+            udt_name: "float8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_bytes",
+            udt_name: "bytea",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_numeric",
+            udt_name: "numeric",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_time",
+            udt_name: "time",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_timestamp",
+            udt_name: "timestamptz",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_interval",
+            udt_name: "interval",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_color",
+            udt_name: "sample_color",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_tags",
+            udt_name: "_text",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_scores",
+            udt_name: "_int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_uuids",
+            udt_name: "_uuid",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "nullable_name",
+            udt_name: "varchar",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_small_int",
+            udt_name: "int2",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_large_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_big_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date",
+            udt_name: "date",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_general_int",
+            udt_name: "int4",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date_time",
+            udt_name: "timestamp",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_uuid",
+            udt_name: "uuid",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_ulong",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_json",
+            udt_name: "json",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_jsonb",
+            udt_name: "jsonb",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_double",
+            udt_name: "float8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_bytes",
+            udt_name: "bytea",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_numeric",
+            udt_name: "numeric",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_time",
+            udt_name: "time",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_timestamp",
+            udt_name: "timestamptz",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_interval",
+            udt_name: "interval",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_color",
+            udt_name: "sample_color",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_tags",
+            udt_name: "_text",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_scores",
+            udt_name: "_int4",
+            is_nullable: true,
+        },
+    ];
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SqlColumn for SampleColumn {
+    /// The name of the column as it appears in sql
+    ///
+    ///   * _return_ - The column name
+    fn column_name(&self) -> &'static str {
+        match self {
+            SampleColumn::TheName => "the_name",
+            SampleColumn::TheSmallInt => "the_small_int",
+            SampleColumn::TheLargeInt => "the_large_int",
+            SampleColumn::TheBigInt => "the_big_int",
+            SampleColumn::TheDate => "the_date",
+            SampleColumn::TheGeneralInt => "the_general_int",
+            SampleColumn::TheDateTime => "the_date_time",
+            SampleColumn::TheUuid => "the_uuid",
+            SampleColumn::TheUlong => "the_ulong",
+            SampleColumn::TheJson => "the_json",
+            SampleColumn::TheJsonb => "the_jsonb",
+            SampleColumn::TheDouble => "the_double",
+            SampleColumn::TheBytes => "the_bytes",
+            SampleColumn::TheNumeric => "the_numeric",
+            SampleColumn::TheTime => "the_time",
+            SampleColumn::TheTimestamp => "the_timestamp",
+            SampleColumn::TheInterval => "the_interval",
+            SampleColumn::TheColor => "the_color",
+            SampleColumn::TheTags => "the_tags",
+            SampleColumn::TheScores => "the_scores",
+            SampleColumn::TheUuids => "the_uuids",
+            SampleColumn::NullableName => "nullable_name",
+            SampleColumn::NullableSmallInt => "nullable_small_int",
+            SampleColumn::NullableLargeInt => "nullable_large_int",
+            SampleColumn::NullableBigInt => "nullable_big_int",
+            SampleColumn::NullableDate => "nullable_date",
+            SampleColumn::NullableGeneralInt => "nullable_general_int",
+            SampleColumn::NullableDateTime => "nullable_date_time",
+            SampleColumn::NullableUuid => "nullable_uuid",
+            SampleColumn::NullableUlong => "nullable_ulong",
+            SampleColumn::NullableJson => "nullable_json",
+            SampleColumn::NullableJsonb => "nullable_jsonb",
+            SampleColumn::NullableDouble => "nullable_double",
+            SampleColumn::NullableBytes => "nullable_bytes",
+            SampleColumn::NullableNumeric => "nullable_numeric",
+            SampleColumn::NullableTime => "nullable_time",
+            SampleColumn::NullableTimestamp => "nullable_timestamp",
+            SampleColumn::NullableInterval => "nullable_interval",
+            SampleColumn::NullableColor => "nullable_color",
+            SampleColumn::NullableTags => "nullable_tags",
+            SampleColumn::NullableScores => "nullable_scores",
+        }
+    }
 }
 
 // α <mod-def sample>
@@ -0,0 +1,1588 @@
+//! Table gateway pattern implemented for SampleAutoId
+//!
+//! > Table with auto-id and no primary key, paged by the auto-id
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use crate::batch::BatchMode;
+use crate::batch::BatchReport;
+use crate::batch::ChunkFailure;
+use crate::batch::ChunkSuccess;
+use crate::filter::Filter;
+use crate::filter::SqlColumn;
+use crate::page::Page;
+use crate::schema::verify_table_schema;
+use crate::schema::ColumnSchema;
+use crate::schema::SchemaReport;
+use futures::StreamExt;
+#[allow(unused)]
+use std::sync::LazyLock;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::binary_copy::BinaryCopyOutStream;
+use tokio_postgres::types::ToSql;
+use tokio_postgres::types::Type;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The columns of `sample_auto_id`, with variants in the same order as the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleAutoIdColumn {
+    /// The `auto_id` column
+    AutoId,
+    /// The `the_name` column
+    TheName,
+    /// The `the_large_int` column
+    TheLargeInt,
+    /// The `the_date` column
+    TheDate,
+    /// The `nullable_name` column
+    NullableName,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Primary data fields
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SampleAutoIdRowData {
+    /// Field for column `the_name`
+    pub the_name: String,
+    /// Field for column `the_large_int`
+    pub the_large_int: i64,
+    /// Field for column `the_date`
+    pub the_date: chrono::NaiveDate,
+    /// Field for column `nullable_name`
+    pub nullable_name: Option<String>,
+}
+
+/// All fields plus auto id for table `sample_auto_id`.
+#[derive(Debug, Clone)]
+pub struct SampleAutoIdEntry {
+    /// Field for column `auto_id`
+    pub auto_id: i64,
+    /// The data fields
+    pub data: SampleAutoIdRowData,
+}
+
+/// Table Gateway Support for table `sample_auto_id`.
+/// Table with auto-id and no primary key, paged by the auto-id
+#[derive(Debug, Clone, Default)]
+pub struct TableSampleAutoId {}
+
+/// Fields of unique index `sample_auto_id_the_name_unique` of `SampleAutoId`
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SampleAutoIdTheNameUnique {
+    /// Field for column `the_name`
+    pub the_name: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SampleAutoIdRowData {
+    /// Get the key of unique index `sample_auto_id_the_name_unique` of the row
+    ///
+    ///   * _return_ - The key
+    #[inline]
+    pub fn sample_auto_id_the_name_unique_key(&self) -> SampleAutoIdTheNameUnique {
+        SampleAutoIdTheNameUnique {
+            the_name: self.the_name.clone(),
+        }
+    }
+}
+
+impl TableSampleAutoId {
+    /// Decode a row of `sample_auto_id` selected with all columns in table order
+    ///
+    ///   * **row** - The selected row
+    ///   * _return_ - The decoded row or the `tokio_postgres::Error`
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<SampleAutoIdEntry, tokio_postgres::Error> {
+        Ok(SampleAutoIdEntry {
+            auto_id: row.try_get(0)?,
+            data: SampleAutoIdRowData {
+                the_name: row.try_get(1)?,
+                the_large_int: row.try_get(2)?,
+                the_date: row.try_get(3)?,
+                nullable_name: row.try_get(4)?,
+            },
+        })
+    }
+
+    /// Select rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    WHERE {where_clause}"#
+        );
+        let rows = match client.query(&select_where_statement, params).await {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_all_where `sample_auto_id` -> {err}");
+                return Err(err);
+            }
+        };
+
+        let mut results = Vec::<SampleAutoIdEntry>::with_capacity(rows.len());
+
+        for row in rows {
+            results.push(Self::from_row(&row)?);
+            tracing::trace!("{:?}", results.last().unwrap());
+        }
+        Ok(results)
+    }
+
+    /// Select rows of `sample_auto_id`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    #[inline]
+    pub async fn select_all<C>(client: &C) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        Self::select_all_where(client, "1=1", &[]).await
+    }
+
+    /// Select rows of `sample_auto_id` with provided where clause as a stream of rows.
+    /// The stream is not `Unpin`, so pin it (e.g. `std::pin::pin!`) to iterate with `next`.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Stream of selected rows or the `tokio_postgres::Error`
+    pub async fn select_stream_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<
+        impl futures::Stream<Item = Result<SampleAutoIdEntry, tokio_postgres::Error>>,
+        tokio_postgres::Error,
+    >
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    WHERE {where_clause}"#
+        );
+        let rows = match client
+            .query_raw(&select_where_statement, params.iter().copied())
+            .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_stream_where `sample_auto_id` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(rows.map(|row| row.and_then(|row| Self::from_row(&row))))
+    }
+
+    /// Select rows of `sample_auto_id` with provided where clause as a stream of chunks,
+    /// fetched through a portal (cursor) bound in `transaction`.
+    ///
+    ///   * **transaction** - The transaction binding the portal
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * **chunk_size** - Maximum number of rows fetched per chunk
+    ///   * _return_ - Stream of chunks of selected rows or the `tokio_postgres::Error`
+    pub async fn select_chunks_where<'a>(
+        transaction: &'a tokio_postgres::Transaction<'a>,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+        chunk_size: i32,
+    ) -> Result<
+        impl futures::Stream<Item = Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>> + 'a,
+        tokio_postgres::Error,
+    > {
+        let select_where_statement = format!(
+            r#"SELECT 
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    WHERE {where_clause}"#
+        );
+        let portal = match transaction.bind(&select_where_statement, params).await {
+            Ok(portal) => portal,
+            Err(err) => {
+                tracing::error!("Failed select_chunks_where `sample_auto_id` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(futures::stream::try_unfold(
+            portal,
+            move |portal| async move {
+                let rows = transaction.query_portal(&portal, chunk_size).await?;
+                if rows.is_empty() {
+                    Ok(None)
+                } else {
+                    let chunk = rows
+                        .iter()
+                        .map(Self::from_row)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(Some((chunk, portal)))
+                }
+            },
+        ))
+    }
+
+    /// Select the page of rows of `sample_auto_id` following `after` in the order of [i64].
+    /// Pass `None` for the first page, then the `next` cursor of each page for the page following.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **after** - Key of the last row of the previous page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the key of its last row as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_after<C>(
+        client: &C,
+        after: Option<&i64>,
+        limit: i64,
+    ) -> Result<Page<SampleAutoIdEntry, i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let rows = match after {
+            None => {
+                let select_statement = r#"SELECT
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    ORDER BY auto_id
+    LIMIT $1"#;
+                client.query(select_statement, &[&limit]).await?
+            }
+            Some(key) => {
+                let select_statement = r#"SELECT
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    WHERE (auto_id) > ($1)
+    ORDER BY auto_id
+    LIMIT $2"#;
+                client.query(select_statement, &[key, &limit]).await?
+            }
+        };
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            rows.last().map(|last| last.auto_id)
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample_auto_id`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select the page of rows of `sample_auto_id` at `offset` in the order of [i64].
+    /// Deep offsets scan the rows skipped, so prefer `select_page_after` to page through a table.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **offset** - Number of rows preceding the page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the offset of the page following as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_at_offset<C>(
+        client: &C,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Page<SampleAutoIdEntry, i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_statement = r#"SELECT
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    ORDER BY auto_id
+    LIMIT $1 OFFSET $2"#;
+        let rows = client.query(select_statement, &[&limit, &offset]).await?;
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            Some(offset + limit)
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample_auto_id`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select rows of `sample_auto_id` matching the typed `filter`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **filter** - The filter on the columns of the table
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_filtered<C>(
+        client: &C,
+        filter: &Filter<SampleAutoIdColumn>,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let (where_clause, params) = filter.render();
+        Self::select_all_where(client, &where_clause, &params).await
+    }
+
+    /// Insert rows of `sample_auto_id` by building parameterized statement.
+    /// For large insertions prefer [bulk_insert]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * _return_ - Entries with corresponding _auto_id_
+    pub async fn basic_insert<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        use itertools::Itertools;
+        let mut param_id = 0;
+
+        let mut params: Vec<&(dyn ToSql + Sync)> =
+            Vec::with_capacity(rows.len() * SampleAutoIdRowData::NUM_FIELDS);
+        let value_params = rows
+            .iter()
+            .map(|row| {
+                let row_params = SampleAutoIdRowData::FIELD_NAMES
+                    .map(|_| {
+                        param_id += 1;
+                        format!("${param_id}")
+                    })
+                    .join(", ");
+
+                params.push(&row.the_name);
+                params.push(&row.the_large_int);
+                params.push(&row.the_date);
+                params.push(&row.nullable_name);
+
+                format!("({row_params})")
+            })
+            .join(",\n");
+
+        let insert_statement = format!(
+            r#"insert into sample_auto_id 
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    VALUES
+    {value_params}
+    returning auto_id
+    "#
+        );
+        let insert_result = client.query(&insert_statement, &params).await;
+
+        match insert_result {
+            Err(err) => {
+                tracing::error!("Failed basic_insert `sample_auto_id`");
+                Err(err)
+            }
+            Ok(insert_result) => {
+                tracing::debug!(
+                    "Finished basic insert of count({}) in `sample_auto_id`",
+                    insert_result.len()
+                );
+                Ok(insert_result
+                    .into_iter()
+                    .zip(rows)
+                    .map(|(row, data)| {
+                        let auto_id = row.get(0);
+                        SampleAutoIdEntry { auto_id, data }
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Insert large batch of [SampleAutoId] rows.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ - Entries with corresponding _auto_id_
+    pub async fn bulk_insert<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+
+        let bulk_insert_statement = r#"insert into sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::bigint[], $3::date[], $4::varchar[]
+    )
+    returning auto_id
+    "#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_large_int.push(row.the_large_int);
+                the_date.push(row.the_date);
+                nullable_name.push(&row.nullable_name);
+            }
+
+            let chunk_result = client
+                .query(
+                    bulk_insert_statement,
+                    &[&the_name, &the_large_int, &the_date, &nullable_name],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_insert `sample_auto_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk insert of size({}) in `sample_auto_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_large_int.clear();
+            the_date.clear();
+            nullable_name.clear();
+        }
+
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleAutoIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Insert large batch of [SampleAutoId] rows with `COPY ... FROM STDIN BINARY`.
+    /// Prefer over [bulk_insert] when the _auto id_ of inserted rows is not needed.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data to insert
+    ///   * _return_ - Number of rows copied in or tokio_postgres::Error
+    pub async fn copy_in<C>(
+        client: &C,
+        rows: &[SampleAutoIdRowData],
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [Type::VARCHAR, Type::INT8, Type::DATE, Type::VARCHAR];
+        let copy_in_statement = r#"COPY sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    FROM STDIN BINARY"#;
+        let sink = match client.client().copy_in(copy_in_statement).await {
+            Ok(sink) => sink,
+            Err(err) => {
+                tracing::error!("Failed copy_in `sample_auto_id` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut writer = std::pin::pin!(BinaryCopyInWriter::new(sink, &types));
+        for row in rows {
+            writer
+                .as_mut()
+                .write(&[
+                    &row.the_name,
+                    &row.the_large_int,
+                    &row.the_date,
+                    &row.nullable_name,
+                ])
+                .await?;
+        }
+        let copied = writer.finish().await?;
+        tracing::debug!("Finished copy_in of size({copied}) in `sample_auto_id`");
+        Ok(copied)
+    }
+
+    /// Export rows of `sample_auto_id` with provided where clause with `COPY ... TO STDOUT BINARY`.
+    /// `COPY` does not support parameters so the where clause is embedded as is and
+    /// must not contain untrusted input.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * _return_ - Copied out rows or the `tokio_postgres::Error`
+    pub async fn copy_out_where<C>(
+        client: &C,
+        where_clause: &str,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [
+            Type::INT8,
+            Type::VARCHAR,
+            Type::INT8,
+            Type::DATE,
+            Type::VARCHAR,
+        ];
+        let copy_out_statement = format!(
+            r#"COPY (SELECT
+    auto_id, the_name, the_large_int, the_date, nullable_name
+    FROM sample_auto_id
+    WHERE {where_clause}) TO STDOUT BINARY"#
+        );
+        let stream = match client.client().copy_out(&copy_out_statement).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("Failed copy_out_where `sample_auto_id` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut rows = std::pin::pin!(BinaryCopyOutStream::new(stream, &types));
+
+        let mut results = Vec::<SampleAutoIdEntry>::new();
+        while let Some(row) = rows.next().await {
+            let row = row?;
+            results.push(SampleAutoIdEntry {
+                auto_id: row.try_get(0)?,
+                data: SampleAutoIdRowData {
+                    the_name: row.try_get(1)?,
+                    the_large_int: row.try_get(2)?,
+                    the_date: row.try_get(3)?,
+                    nullable_name: row.try_get(4)?,
+                },
+            });
+        }
+        tracing::debug!(
+            "Finished copy_out_where of size({}) in `sample_auto_id`",
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Upsert large batch of [SampleAutoId] rows, resolving conflicts on (the_name).
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"WITH unnested AS (
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::bigint[], $3::date[], $4::varchar[]
+    ) WITH ORDINALITY AS unnested(the_name, the_large_int, the_date, nullable_name, ordinality)
+    ), upserted AS (
+    insert into sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    SELECT unnested.the_name, unnested.the_large_int, unnested.the_date, unnested.nullable_name
+    FROM unnested
+    ON CONFLICT (the_name)
+    DO UPDATE SET
+        the_large_int = EXCLUDED.the_large_int,
+    	the_date = EXCLUDED.the_date,
+    	nullable_name = EXCLUDED.nullable_name
+    RETURNING auto_id, the_name
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name
+    ORDER BY unnested.ordinality
+    "#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_large_int.push(row.the_large_int);
+                the_date.push(row.the_date);
+                nullable_name.push(&row.nullable_name);
+            }
+            let chunk_result = client
+                .query(
+                    bulk_upsert_statement,
+                    &[&the_name, &the_large_int, &the_date, &nullable_name],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert `sample_auto_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk upsert of size({}) in `sample_auto_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_large_int.clear();
+            the_date.clear();
+            nullable_name.clear();
+        }
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleAutoIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Insert large batch of [SampleAutoId] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_insert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleAutoIdRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<Vec<SampleAutoIdEntry>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_insert(&transaction, chunk_rows.to_vec(), chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_insert(&savepoint, chunk_rows.to_vec(), chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_insert_transactional chunk({chunk}) `sample_auto_id` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
+    /// Upsert large batch of [SampleAutoId] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to upsert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_upsert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleAutoIdRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<Vec<SampleAutoIdEntry>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_upsert(&transaction, chunk_rows.to_vec(), chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_upsert(&savepoint, chunk_rows.to_vec(), chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_upsert_transactional chunk({chunk}) `sample_auto_id` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
+    /// Delete all rows of `sample_auto_id`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn delete_all<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement = "DELETE FROM sample_auto_id";
+        client.execute(delete_statement, &[]).await
+    }
+
+    /// Create the table `sample_auto_id`, with any enum types and unique indices, if not present.
+    /// Runs the statements of [Self::CREATE_DDL], against the backdoor table name if set.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn create_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let create_statement = r#"CREATE TABLE IF NOT EXISTS sample_auto_id (
+    	auto_id bigserial NOT NULL,
+    	the_name varchar(255) NOT NULL,
+    	the_large_int bigint NOT NULL,
+    	the_date date NOT NULL,
+    	nullable_name varchar(255)
+    );
+    
+    CREATE UNIQUE INDEX IF NOT EXISTS sample_auto_id_the_name_unique ON sample_auto_id (the_name);"#;
+        match client.batch_execute(create_statement).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                tracing::error!("Failed create_table `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Drop the table `sample_auto_id` if present, leaving any enum types in place
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn drop_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let drop_statement = "DROP TABLE IF EXISTS sample_auto_id";
+        client.execute(drop_statement, &[]).await.map(|_| ())
+    }
+
+    /// Verify the live table matches the columns assumed in [Self::COLUMN_SCHEMA].
+    /// Drift is returned in the report rather than as an error.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - The report of any drift or the `tokio_postgres::Error` reading the schema
+    #[inline]
+    pub async fn verify_schema<C>(client: &C) -> Result<SchemaReport, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        verify_table_schema(client, "sample_auto_id", &Self::COLUMN_SCHEMA).await
+    }
+
+    /// Count rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Number of rows matched or the `tokio_postgres::Error`
+    pub async fn count_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<i64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT count(*) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed count_where `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Check for any row of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - True if any row matched or the `tokio_postgres::Error`
+    pub async fn exists_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<bool, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT EXISTS (SELECT 1 FROM sample_auto_id WHERE {where_clause})"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed exists_where `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `auto_id` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_auto_id<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(auto_id) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_auto_id `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `auto_id` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_auto_id<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(auto_id) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_auto_id `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_name` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_name) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_name `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_name` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_name) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_name `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_large_int` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_large_int) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_large_int `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_large_int` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_large_int) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_large_int `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_date) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_date) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_name` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_name) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_name `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_name` of rows of `sample_auto_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_name) FROM sample_auto_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_name `sample_auto_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Insert large batch of [SampleAutoId] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ - Entries with corresponding _auto_id_
+    pub async fn bulk_insert_prepared<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+
+        let bulk_insert_statement = r#"insert into sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::bigint[], $3::date[], $4::varchar[]
+    )
+    returning auto_id
+    "#;
+        let prepared = client.prepare_cached(bulk_insert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_large_int.push(row.the_large_int);
+                the_date.push(row.the_date);
+                nullable_name.push(&row.nullable_name);
+            }
+
+            let chunk_result = client
+                .query(
+                    &prepared,
+                    &[&the_name, &the_large_int, &the_date, &nullable_name],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_insert `sample_auto_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk insert of size({}) in `sample_auto_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_large_int.clear();
+            the_date.clear();
+            nullable_name.clear();
+        }
+
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleAutoIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Upsert large batch of [SampleAutoId] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert_prepared<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"WITH unnested AS (
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::bigint[], $3::date[], $4::varchar[]
+    ) WITH ORDINALITY AS unnested(the_name, the_large_int, the_date, nullable_name, ordinality)
+    ), upserted AS (
+    insert into sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    SELECT unnested.the_name, unnested.the_large_int, unnested.the_date, unnested.nullable_name
+    FROM unnested
+    ON CONFLICT (the_name)
+    DO UPDATE SET
+        the_large_int = EXCLUDED.the_large_int,
+    	the_date = EXCLUDED.the_date,
+    	nullable_name = EXCLUDED.nullable_name
+    RETURNING auto_id, the_name
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name
+    ORDER BY unnested.ordinality
+    "#;
+        let prepared = client.prepare_cached(bulk_upsert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_large_int.push(row.the_large_int);
+                the_date.push(row.the_date);
+                nullable_name.push(&row.nullable_name);
+            }
+            let chunk_result = client
+                .query(
+                    &prepared,
+                    &[&the_name, &the_large_int, &the_date, &nullable_name],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert `sample_auto_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk upsert of size({}) in `sample_auto_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_large_int.clear();
+            the_date.clear();
+            nullable_name.clear();
+        }
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleAutoIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Delete all rows of `sample_auto_id` with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn delete_all_prepared<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let delete_statement = "DELETE FROM sample_auto_id";
+        let prepared = client.prepare_cached(delete_statement).await?;
+        client.execute(&prepared, &[]).await
+    }
+
+    /// Select the row of `sample_auto_id` identified by [SampleAutoIdTheNameUnique]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_sample_auto_id_the_name_unique<C>(
+        client: &C,
+        key: &SampleAutoIdTheNameUnique,
+    ) -> Result<Option<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut selected =
+            Self::select_all_where(client, r#"the_name = $1"#, &[&key.the_name]).await?;
+        Ok(selected.pop())
+    }
+
+    /// Delete the row of `sample_auto_id` identified by [SampleAutoIdTheNameUnique]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - Number of rows deleted
+    pub async fn delete_by_sample_auto_id_the_name_unique<C>(
+        client: &C,
+        key: &SampleAutoIdTheNameUnique,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement = r#"DELETE FROM sample_auto_id WHERE the_name = $1"#;
+        client.execute(delete_statement, &[&key.the_name]).await
+    }
+
+    /// Upsert large batch of [SampleAutoId] rows, resolving conflicts on (the_name).
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert_by_sample_auto_id_the_name_unique<C>(
+        client: &C,
+        rows: Vec<SampleAutoIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleAutoIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"WITH unnested AS (
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::bigint[], $3::date[], $4::varchar[]
+    ) WITH ORDINALITY AS unnested(the_name, the_large_int, the_date, nullable_name, ordinality)
+    ), upserted AS (
+    insert into sample_auto_id
+    (
+    	the_name, the_large_int, the_date, nullable_name
+    )
+    SELECT unnested.the_name, unnested.the_large_int, unnested.the_date, unnested.nullable_name
+    FROM unnested
+    ON CONFLICT (the_name)
+    DO UPDATE SET
+        the_large_int = EXCLUDED.the_large_int,
+    	the_date = EXCLUDED.the_date,
+    	nullable_name = EXCLUDED.nullable_name
+    RETURNING auto_id, the_name
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name
+    ORDER BY unnested.ordinality
+    "#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_large_int.push(row.the_large_int);
+                the_date.push(row.the_date);
+                nullable_name.push(&row.nullable_name);
+            }
+            let chunk_result = client
+                .query(
+                    bulk_upsert_statement,
+                    &[&the_name, &the_large_int, &the_date, &nullable_name],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert_by_sample_auto_id_the_name_unique `sample_auto_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk upsert of size({}) in `sample_auto_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_large_int.clear();
+            the_date.clear();
+            nullable_name.clear();
+        }
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleAutoIdEntry { auto_id, data })
+            .collect())
+    }
+}
+
+impl SampleAutoIdColumn {
+    /// All columns of the table
+    pub const ALL: [SampleAutoIdColumn; 5] = [
+        SampleAutoIdColumn::AutoId,
+        SampleAutoIdColumn::TheName,
+        SampleAutoIdColumn::TheLargeInt,
+        SampleAutoIdColumn::TheDate,
+        SampleAutoIdColumn::NullableName,
+    ];
+}
+
+impl SampleAutoIdRowData {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 4;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] =
+        ["the_name", "the_large_int", "the_date", "nullable_name"];
+}
+
+impl TableSampleAutoId {
+    /// The total number of key and value columns
+    pub const COLUMN_COUNT: usize = 5;
+
+    /// Statements creating the table `sample_auto_id`, its enum types and unique indices
+    pub const CREATE_DDL: &str = r#"CREATE TABLE IF NOT EXISTS sample_auto_id (
+  	auto_id bigserial NOT NULL,
+  	the_name varchar(255) NOT NULL,
+  	the_large_int bigint NOT NULL,
+  	the_date date NOT NULL,
+  	nullable_name varchar(255)
+  );
+  
+  CREATE UNIQUE INDEX IF NOT EXISTS sample_auto_id_the_name_unique ON sample_auto_id (the_name);"#;
+
+    /// The columns of the table, in order, as assumed by the generated code
+    pub const COLUMN_SCHEMA: [ColumnSchema; Self::COLUMN_COUNT] = [
+        ColumnSchema {
+            name: "auto_id",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_name",
+            udt_name: "varchar",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_large_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date",
+            udt_name: "date",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "nullable_name",
+            udt_name: "varchar",
+            is_nullable: true,
+        },
+    ];
+}
+
+impl SampleAutoIdTheNameUnique {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 1;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = ["the_name"];
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SqlColumn for SampleAutoIdColumn {
+    /// The name of the column as it appears in sql
+    ///
+    ///   * _return_ - The column name
+    fn column_name(&self) -> &'static str {
+        match self {
+            SampleAutoIdColumn::AutoId => "auto_id",
+            SampleAutoIdColumn::TheName => "the_name",
+            SampleAutoIdColumn::TheLargeInt => "the_large_int",
+            SampleAutoIdColumn::TheDate => "the_date",
+            SampleAutoIdColumn::NullableName => "nullable_name",
+        }
+    }
+}
+
+// α <mod-def sample_auto_id>
+// ω <mod-def sample_auto_id>
@@ -5,15 +5,93 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use crate::batch::BatchMode;
+use crate::batch::BatchReport;
+use crate::batch::ChunkFailure;
+use crate::batch::ChunkSuccess;
+use crate::filter::Filter;
+use crate::filter::SqlColumn;
+use crate::page::Page;
+use crate::payload::SamplePayload;
+use crate::schema::verify_table_schema;
+use crate::schema::ColumnSchema;
+use crate::schema::SchemaReport;
+use crate::unsigned::U64;
+use crate::version::VersionedUpdate;
+use futures::StreamExt;
 #[allow(unused)]
 use std::sync::LazyLock;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::binary_copy::BinaryCopyOutStream;
+use tokio_postgres::types::Json;
 use tokio_postgres::types::ToSql;
+use tokio_postgres::types::Type;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// The columns of `sample_with_id`, with variants in the same order as the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleWithIdColumn {
+    /// The `auto_id` column
+    AutoId,
+    /// The `the_name` column
+    TheName,
+    /// The `the_boolean` column
+    TheBoolean,
+    /// The `the_small_int` column
+    TheSmallInt,
+    /// The `the_large_int` column
+    TheLargeInt,
+    /// The `the_big_int` column
+    TheBigInt,
+    /// The `the_date` column
+    TheDate,
+    /// The `the_general_int` column
+    TheGeneralInt,
+    /// The `the_date_time` column
+    TheDateTime,
+    /// The `the_uuid` column
+    TheUuid,
+    /// The `the_ulong` column
+    TheUlong,
+    /// The `the_json` column
+    TheJson,
+    /// The `the_jsonb` column
+    TheJsonb,
+    /// The `the_version` column
+    TheVersion,
+    /// The `nullable_name` column
+    NullableName,
+    /// The `nullable_boolean` column
+    NullableBoolean,
+    /// The `nullable_small_int` column
+    NullableSmallInt,
+    /// The `nullable_large_int` column
+    NullableLargeInt,
+    /// The `nullable_big_int` column
+    NullableBigInt,
+    /// The `nullable_date` column
+    NullableDate,
+    /// The `nullable_general_int` column
+    NullableGeneralInt,
+    /// The `nullable_date_time` column
+    NullableDateTime,
+    /// The `nullable_uuid` column
+    NullableUuid,
+    /// The `nullable_ulong` column
+    NullableUlong,
+    /// The `nullable_json` column
+    NullableJson,
+    /// The `nullable_jsonb` column
+    NullableJsonb,
+}
 
 ////////////////////////////////////////////////////////////////////////////////////
 // --- structs ---
 ////////////////////////////////////////////////////////////////////////////////////
 /// Primary data fields
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct SampleWithIdRowData {
     /// Field for column `the_name`
     pub the_name: String,
@@ -24,7 +102,7 @@ pub struct SampleWithIdRowData {
     /// Field for column `the_large_int`
     pub the_large_int: i64,
     /// Field for column `the_big_int`
-    pub the_big_int: i64,
+    pub the_big_int: U64,
     /// Field for column `the_date`
     pub the_date: chrono::NaiveDate,
     /// Field for column `the_general_int`
@@ -34,13 +112,15 @@ pub struct SampleWithIdRowData {
     /// Field for column `the_uuid`
     pub the_uuid: uuid::Uuid,
     /// Field for column `the_ulong`
-    pub the_ulong: i64,
+    pub the_ulong: U64,
     /// Field for column `the_json`
     pub the_json: serde_json::Value,
     /// Field for column `the_jsonb`
-    pub the_jsonb: serde_json::Value,
+    pub the_jsonb: SamplePayload,
+    /// Field for column `the_version`
+    pub the_version: i32,
     /// Field for column `nullable_name`
-    pub nullable_name: String,
+    pub nullable_name: Option<String>,
     /// Field for column `nullable_boolean`
     pub nullable_boolean: Option<bool>,
     /// Field for column `nullable_small_int`
@@ -48,7 +128,7 @@ pub struct SampleWithIdRowData {
     /// Field for column `nullable_large_int`
     pub nullable_large_int: Option<i64>,
     /// Field for column `nullable_big_int`
-    pub nullable_big_int: Option<i64>,
+    pub nullable_big_int: Option<U64>,
     /// Field for column `nullable_date`
     pub nullable_date: Option<chrono::NaiveDate>,
     /// Field for column `nullable_general_int`
@@ -58,11 +138,11 @@ pub struct SampleWithIdRowData {
     /// Field for column `nullable_uuid`
     pub nullable_uuid: Option<uuid::Uuid>,
     /// Field for column `nullable_ulong`
-    pub nullable_ulong: Option<i64>,
+    pub nullable_ulong: Option<U64>,
     /// Field for column `nullable_json`
     pub nullable_json: Option<serde_json::Value>,
     /// Field for column `nullable_jsonb`
-    pub nullable_jsonb: serde_json::Value,
+    pub nullable_jsonb: Option<serde_json::Value>,
 }
 
 /// All fields plus auto id for table `sample_with_id`.
@@ -86,21 +166,94 @@ pub struct SampleWithIdPkey {
 #[derive(Debug, Clone, Default)]
 pub struct TableSampleWithId {}
 
+/// Fields of unique index `sample_with_id_the_name_the_small_int_unique` of `SampleWithId`
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SampleWithIdTheNameTheSmallIntUnique {
+    /// Field for column `the_name`
+    pub the_name: String,
+    /// Field for column `the_small_int`
+    pub the_small_int: i16,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- type impls ---
 ////////////////////////////////////////////////////////////////////////////////////
+impl SampleWithIdEntry {
+    /// Get the primary key of the row
+    ///
+    ///   * _return_ - The key
+    #[inline]
+    pub fn pkey(&self) -> SampleWithIdPkey {
+        SampleWithIdPkey {
+            auto_id: self.auto_id,
+        }
+    }
+}
+
+impl SampleWithIdRowData {
+    /// Get the key of unique index `sample_with_id_the_name_the_small_int_unique` of the row
+    ///
+    ///   * _return_ - The key
+    #[inline]
+    pub fn sample_with_id_the_name_the_small_int_unique_key(
+        &self,
+    ) -> SampleWithIdTheNameTheSmallIntUnique {
+        SampleWithIdTheNameTheSmallIntUnique {
+            the_name: self.the_name.clone(),
+            the_small_int: self.the_small_int,
+        }
+    }
+}
+
 impl TableSampleWithId {
+    /// Decode a row of `sample_with_id` selected with all columns in table order
+    ///
+    ///   * **row** - The selected row
+    ///   * _return_ - The decoded row or the `tokio_postgres::Error`
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<SampleWithIdEntry, tokio_postgres::Error> {
+        Ok(SampleWithIdEntry {
+            auto_id: row.try_get(0)?,
+            data: SampleWithIdRowData {
+                the_name: row.try_get(1)?,
+                the_boolean: row.try_get(2)?,
+                the_small_int: row.try_get(3)?,
+                the_large_int: row.try_get(4)?,
+                the_big_int: row.try_get(5)?,
+                the_date: row.try_get(6)?,
+                the_general_int: row.try_get(7)?,
+                the_date_time: row.try_get(8)?,
+                the_uuid: row.try_get(9)?,
+                the_ulong: row.try_get(10)?,
+                the_json: row.try_get(11)?,
+                the_jsonb: row.try_get::<_, Json<SamplePayload>>(12)?.0,
+                the_version: row.try_get(13)?,
+                nullable_name: row.try_get(14)?,
+                nullable_boolean: row.try_get(15)?,
+                nullable_small_int: row.try_get(16)?,
+                nullable_large_int: row.try_get(17)?,
+                nullable_big_int: row.try_get(18)?,
+                nullable_date: row.try_get(19)?,
+                nullable_general_int: row.try_get(20)?,
+                nullable_date_time: row.try_get(21)?,
+                nullable_uuid: row.try_get(22)?,
+                nullable_ulong: row.try_get(23)?,
+                nullable_json: row.try_get(24)?,
+                nullable_jsonb: row.try_get(25)?,
+            },
+        })
+    }
+
     /// Select rows of `sample_with_id` with provided where clause
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **where_clause** - The where clause (sans `where` keyword)
     ///   * **params** - Any clause parameters
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     pub async fn select_all_where<C>(
         client: &C,
         where_clause: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Vec<SampleWithIdEntry>
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
@@ -108,68 +261,244 @@ impl TableSampleWithId {
             r#"SELECT 
     auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
     	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
-    	the_jsonb, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
-    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
-    	nullable_jsonb
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
     FROM sample_with_id
     WHERE {where_clause}"#
         );
         let rows = match client.query(&select_where_statement, params).await {
-            Ok(stmt) => stmt,
-            Err(e) => {
-                panic!("Error preparing statement: {e}");
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_all_where `sample_with_id` -> {err}");
+                return Err(err);
             }
         };
 
         let mut results = Vec::<SampleWithIdEntry>::with_capacity(rows.len());
 
         for row in rows {
-            results.push(SampleWithIdEntry {
-                auto_id: row.get(0),
-                data: SampleWithIdRowData {
-                    the_name: row.get(1),
-                    the_boolean: row.get(2),
-                    the_small_int: row.get(3),
-                    the_large_int: row.get(4),
-                    the_big_int: row.get(5),
-                    the_date: row.get(6),
-                    the_general_int: row.get(7),
-                    the_date_time: row.get(8),
-                    the_uuid: row.get(9),
-                    the_ulong: row.get(10),
-                    the_json: row.get(11),
-                    the_jsonb: row.get(12),
-                    nullable_name: row.get(13),
-                    nullable_boolean: row.get(14),
-                    nullable_small_int: row.get(15),
-                    nullable_large_int: row.get(16),
-                    nullable_big_int: row.get(17),
-                    nullable_date: row.get(18),
-                    nullable_general_int: row.get(19),
-                    nullable_date_time: row.get(20),
-                    nullable_uuid: row.get(21),
-                    nullable_ulong: row.get(22),
-                    nullable_json: row.get(23),
-                    nullable_jsonb: row.get(24),
-                },
-            });
+            results.push(Self::from_row(&row)?);
             tracing::trace!("{:?}", results.last().unwrap());
         }
-        results
+        Ok(results)
     }
 
     /// Select rows of `sample_with_id`
     ///
     ///   * **client** - The tokio postgresql client
-    ///   * _return_ - Selected rows
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
     #[inline]
-    pub async fn select_all<C>(client: &C) -> Vec<SampleWithIdEntry>
+    pub async fn select_all<C>(client: &C) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
     where
         C: tokio_postgres::GenericClient,
     {
         Self::select_all_where(client, "1=1", &[]).await
     }
 
+    /// Select rows of `sample_with_id` with provided where clause as a stream of rows.
+    /// The stream is not `Unpin`, so pin it (e.g. `std::pin::pin!`) to iterate with `next`.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Stream of selected rows or the `tokio_postgres::Error`
+    pub async fn select_stream_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<
+        impl futures::Stream<Item = Result<SampleWithIdEntry, tokio_postgres::Error>>,
+        tokio_postgres::Error,
+    >
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_where_statement = format!(
+            r#"SELECT 
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    WHERE {where_clause}"#
+        );
+        let rows = match client
+            .query_raw(&select_where_statement, params.iter().copied())
+            .await
+        {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::error!("Failed select_stream_where `sample_with_id` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(rows.map(|row| row.and_then(|row| Self::from_row(&row))))
+    }
+
+    /// Select rows of `sample_with_id` with provided where clause as a stream of chunks,
+    /// fetched through a portal (cursor) bound in `transaction`.
+    ///
+    ///   * **transaction** - The transaction binding the portal
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * **chunk_size** - Maximum number of rows fetched per chunk
+    ///   * _return_ - Stream of chunks of selected rows or the `tokio_postgres::Error`
+    pub async fn select_chunks_where<'a>(
+        transaction: &'a tokio_postgres::Transaction<'a>,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+        chunk_size: i32,
+    ) -> Result<
+        impl futures::Stream<Item = Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>> + 'a,
+        tokio_postgres::Error,
+    > {
+        let select_where_statement = format!(
+            r#"SELECT 
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    WHERE {where_clause}"#
+        );
+        let portal = match transaction.bind(&select_where_statement, params).await {
+            Ok(portal) => portal,
+            Err(err) => {
+                tracing::error!("Failed select_chunks_where `sample_with_id` -> {err}");
+                return Err(err);
+            }
+        };
+        Ok(futures::stream::try_unfold(
+            portal,
+            move |portal| async move {
+                let rows = transaction.query_portal(&portal, chunk_size).await?;
+                if rows.is_empty() {
+                    Ok(None)
+                } else {
+                    let chunk = rows
+                        .iter()
+                        .map(Self::from_row)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(Some((chunk, portal)))
+                }
+            },
+        ))
+    }
+
+    /// Select the page of rows of `sample_with_id` following `after` in the order of [SampleWithIdPkey].
+    /// Pass `None` for the first page, then the `next` cursor of each page for the page following.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **after** - Key of the last row of the previous page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the key of its last row as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_after<C>(
+        client: &C,
+        after: Option<&SampleWithIdPkey>,
+        limit: i64,
+    ) -> Result<Page<SampleWithIdEntry, SampleWithIdPkey>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let rows = match after {
+            None => {
+                let select_statement = r#"SELECT
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    ORDER BY auto_id
+    LIMIT $1"#;
+                client.query(select_statement, &[&limit]).await?
+            }
+            Some(key) => {
+                let select_statement = r#"SELECT
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    WHERE (auto_id) > ($1)
+    ORDER BY auto_id
+    LIMIT $2"#;
+                client
+                    .query(select_statement, &[&key.auto_id, &limit])
+                    .await?
+            }
+        };
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            rows.last().map(|last| last.pkey())
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample_with_id`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select the page of rows of `sample_with_id` at `offset` in the order of [SampleWithIdPkey].
+    /// Deep offsets scan the rows skipped, so prefer `select_page_after` to page through a table.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **offset** - Number of rows preceding the page
+    ///   * **limit** - Maximum number of rows in the page
+    ///   * _return_ - The page with the offset of the page following as cursor, if full, or the `tokio_postgres::Error`
+    pub async fn select_page_at_offset<C>(
+        client: &C,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Page<SampleWithIdEntry, i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let select_statement = r#"SELECT
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    ORDER BY auto_id
+    LIMIT $1 OFFSET $2"#;
+        let rows = client.query(select_statement, &[&limit, &offset]).await?;
+        let rows = rows
+            .iter()
+            .map(Self::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next = if rows.len() as i64 == limit {
+            Some(offset + limit)
+        } else {
+            None
+        };
+        tracing::debug!("Selected page of size({}) in `sample_with_id`", rows.len());
+        Ok(Page { rows, next })
+    }
+
+    /// Select rows of `sample_with_id` matching the typed `filter`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **filter** - The filter on the columns of the table
+    ///   * _return_ - Selected rows or the `tokio_postgres::Error`
+    pub async fn select_all_filtered<C>(
+        client: &C,
+        filter: &Filter<SampleWithIdColumn>,
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let (where_clause, params) = filter.render();
+        Self::select_all_where(client, &where_clause, &params).await
+    }
+
     /// Insert rows of `sample_with_id` by building parameterized statement.
     /// For large insertions prefer [bulk_insert]
     ///
@@ -185,11 +514,16 @@ impl TableSampleWithId {
     {
         use itertools::Itertools;
         let mut param_id = 0;
+        let the_jsonb_json = rows
+            .iter()
+            .map(|row| Json(&row.the_jsonb))
+            .collect::<Vec<_>>();
         let mut params: Vec<&(dyn ToSql + Sync)> =
             Vec::with_capacity(rows.len() * SampleWithIdRowData::NUM_FIELDS);
         let value_params = rows
             .iter()
-            .map(|row| {
+            .enumerate()
+            .map(|(i, row)| {
                 let row_params = SampleWithIdRowData::FIELD_NAMES
                     .map(|_| {
                         param_id += 1;
@@ -208,7 +542,8 @@ impl TableSampleWithId {
                 params.push(&row.the_uuid);
                 params.push(&row.the_ulong);
                 params.push(&row.the_json);
-                params.push(&row.the_jsonb);
+                params.push(&the_jsonb_json[i]);
+                params.push(&row.the_version);
                 params.push(&row.nullable_name);
                 params.push(&row.nullable_boolean);
                 params.push(&row.nullable_small_int);
@@ -231,8 +566,9 @@ impl TableSampleWithId {
     (
     	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
     	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
-    	nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int, nullable_date,
-    	nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
     )
     VALUES
     {value_params}
@@ -291,6 +627,7 @@ impl TableSampleWithId {
         let mut the_ulong = Vec::with_capacity(chunk_size);
         let mut the_json = Vec::with_capacity(chunk_size);
         let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
         let mut nullable_name = Vec::with_capacity(chunk_size);
         let mut nullable_boolean = Vec::with_capacity(chunk_size);
         let mut nullable_small_int = Vec::with_capacity(chunk_size);
@@ -308,15 +645,17 @@ impl TableSampleWithId {
     (
     	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
     	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
-    	nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int, nullable_date,
-    	nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
     )
     SELECT * FROM UNNEST
     (
     	$1::varchar[], $2::boolean[], $3::smallint[], $4::bigint[], $5::bigint[], $6::date[],
-    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::json[],
-    	$13::varchar[], $14::boolean[], $15::smallint[], $16::bigint[], $17::bigint[], $18::date[],
-    	$19::int[], $20::timestamp[], $21::uuid[], $22::bigint[], $23::json[], $24::json[]
+    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::jsonb[],
+    	$13::int[], $14::varchar[], $15::boolean[], $16::smallint[], $17::bigint[], $18::bigint[],
+    	$19::date[], $20::int[], $21::timestamp[], $22::uuid[], $23::bigint[], $24::json[],
+    	$25::jsonb[]
     )
     returning auto_id
     "#;
@@ -333,7 +672,8 @@ impl TableSampleWithId {
                 the_uuid.push(row.the_uuid);
                 the_ulong.push(row.the_ulong);
                 the_json.push(&row.the_json);
-                the_jsonb.push(&row.the_jsonb);
+                the_jsonb.push(Json(&row.the_jsonb));
+                the_version.push(row.the_version);
                 nullable_name.push(&row.nullable_name);
                 nullable_boolean.push(row.nullable_boolean);
                 nullable_small_int.push(row.nullable_small_int);
@@ -364,6 +704,7 @@ impl TableSampleWithId {
                         &the_ulong,
                         &the_json,
                         &the_jsonb,
+                        &the_version,
                         &nullable_name,
                         &nullable_boolean,
                         &nullable_small_int,
@@ -407,6 +748,7 @@ impl TableSampleWithId {
             the_ulong.clear();
             the_json.clear();
             the_jsonb.clear();
+            the_version.clear();
             nullable_name.clear();
             nullable_boolean.clear();
             nullable_small_int.clear();
@@ -423,12 +765,208 @@ impl TableSampleWithId {
 
         Ok(auto_id
             .into_iter()
-            .zip(rows.into_iter())
+            .zip(rows)
             .map(|(auto_id, data)| SampleWithIdEntry { auto_id, data })
             .collect())
     }
 
-    /// Upsert large batch of [SampleWithId] rows.
+    /// Insert large batch of [SampleWithId] rows with `COPY ... FROM STDIN BINARY`.
+    /// Prefer over [bulk_insert] when the _auto id_ of inserted rows is not needed.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data to insert
+    ///   * _return_ - Number of rows copied in or tokio_postgres::Error
+    pub async fn copy_in<C>(
+        client: &C,
+        rows: &[SampleWithIdRowData],
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [
+            Type::VARCHAR,
+            Type::BOOL,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+            Type::INT4,
+            Type::VARCHAR,
+            Type::BOOL,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+        ];
+        let copy_in_statement = r#"COPY sample_with_id
+    (
+    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
+    )
+    FROM STDIN BINARY"#;
+        let sink = match client.client().copy_in(copy_in_statement).await {
+            Ok(sink) => sink,
+            Err(err) => {
+                tracing::error!("Failed copy_in `sample_with_id` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut writer = std::pin::pin!(BinaryCopyInWriter::new(sink, &types));
+        for row in rows {
+            writer
+                .as_mut()
+                .write(&[
+                    &row.the_name,
+                    &row.the_boolean,
+                    &row.the_small_int,
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &row.the_json,
+                    &Json(&row.the_jsonb),
+                    &row.the_version,
+                    &row.nullable_name,
+                    &row.nullable_boolean,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb,
+                ])
+                .await?;
+        }
+        let copied = writer.finish().await?;
+        tracing::debug!("Finished copy_in of size({copied}) in `sample_with_id`");
+        Ok(copied)
+    }
+
+    /// Export rows of `sample_with_id` with provided where clause with `COPY ... TO STDOUT BINARY`.
+    /// `COPY` does not support parameters so the where clause is embedded as is and
+    /// must not contain untrusted input.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * _return_ - Copied out rows or the `tokio_postgres::Error`
+    pub async fn copy_out_where<C>(
+        client: &C,
+        where_clause: &str,
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let types = [
+            Type::INT4,
+            Type::VARCHAR,
+            Type::BOOL,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+            Type::INT4,
+            Type::VARCHAR,
+            Type::BOOL,
+            Type::INT2,
+            Type::INT8,
+            Type::INT8,
+            Type::DATE,
+            Type::INT4,
+            Type::TIMESTAMP,
+            Type::UUID,
+            Type::INT8,
+            Type::JSON,
+            Type::JSONB,
+        ];
+        let copy_out_statement = format!(
+            r#"COPY (SELECT
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    WHERE {where_clause}) TO STDOUT BINARY"#
+        );
+        let stream = match client.client().copy_out(&copy_out_statement).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("Failed copy_out_where `sample_with_id` -> {err}");
+                return Err(err);
+            }
+        };
+        let mut rows = std::pin::pin!(BinaryCopyOutStream::new(stream, &types));
+
+        let mut results = Vec::<SampleWithIdEntry>::new();
+        while let Some(row) = rows.next().await {
+            let row = row?;
+            results.push(SampleWithIdEntry {
+                auto_id: row.try_get(0)?,
+                data: SampleWithIdRowData {
+                    the_name: row.try_get(1)?,
+                    the_boolean: row.try_get(2)?,
+                    the_small_int: row.try_get(3)?,
+                    the_large_int: row.try_get(4)?,
+                    the_big_int: row.try_get(5)?,
+                    the_date: row.try_get(6)?,
+                    the_general_int: row.try_get(7)?,
+                    the_date_time: row.try_get(8)?,
+                    the_uuid: row.try_get(9)?,
+                    the_ulong: row.try_get(10)?,
+                    the_json: row.try_get(11)?,
+                    the_jsonb: row.try_get::<Json<SamplePayload>>(12)?.0,
+                    the_version: row.try_get(13)?,
+                    nullable_name: row.try_get(14)?,
+                    nullable_boolean: row.try_get(15)?,
+                    nullable_small_int: row.try_get(16)?,
+                    nullable_large_int: row.try_get(17)?,
+                    nullable_big_int: row.try_get(18)?,
+                    nullable_date: row.try_get(19)?,
+                    nullable_general_int: row.try_get(20)?,
+                    nullable_date_time: row.try_get(21)?,
+                    nullable_uuid: row.try_get(22)?,
+                    nullable_ulong: row.try_get(23)?,
+                    nullable_json: row.try_get(24)?,
+                    nullable_jsonb: row.try_get(25)?,
+                },
+            });
+        }
+        tracing::debug!(
+            "Finished copy_out_where of size({}) in `sample_with_id`",
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Upsert large batch of [SampleWithId] rows, resolving conflicts on (the_name, the_small_int).
     ///
     ///   * **client** - The tokio postgresql client
     ///   * **rows** - Row data, consumed but returned with ids
@@ -456,6 +994,7 @@ impl TableSampleWithId {
         let mut the_ulong = Vec::with_capacity(chunk_size);
         let mut the_json = Vec::with_capacity(chunk_size);
         let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
         let mut nullable_name = Vec::with_capacity(chunk_size);
         let mut nullable_boolean = Vec::with_capacity(chunk_size);
         let mut nullable_small_int = Vec::with_capacity(chunk_size);
@@ -468,25 +1007,37 @@ impl TableSampleWithId {
         let mut nullable_ulong = Vec::with_capacity(chunk_size);
         let mut nullable_json = Vec::with_capacity(chunk_size);
         let mut nullable_jsonb = Vec::with_capacity(chunk_size);
-        let bulk_upsert_statement = r#"insert into sample_with_id
-    (
-    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
-    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
-    	nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int, nullable_date,
-    	nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb
-    )
+        let bulk_upsert_statement = r#"WITH unnested AS (
     SELECT * FROM UNNEST
     (
     	$1::varchar[], $2::boolean[], $3::smallint[], $4::bigint[], $5::bigint[], $6::date[],
-    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::json[],
-    	$13::varchar[], $14::boolean[], $15::smallint[], $16::bigint[], $17::bigint[], $18::date[],
-    	$19::int[], $20::timestamp[], $21::uuid[], $22::bigint[], $23::json[], $24::json[]
+    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::jsonb[],
+    	$13::int[], $14::varchar[], $15::boolean[], $16::smallint[], $17::bigint[], $18::bigint[],
+    	$19::date[], $20::int[], $21::timestamp[], $22::uuid[], $23::bigint[], $24::json[],
+    	$25::jsonb[]
+    ) WITH ORDINALITY AS unnested(the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb, ordinality)
+    ), upserted AS (
+    insert into sample_with_id
+    (
+    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
     )
+    SELECT unnested.the_name, unnested.the_boolean, unnested.the_small_int, unnested.the_large_int, unnested.the_big_int, unnested.the_date,
+    	unnested.the_general_int, unnested.the_date_time, unnested.the_uuid, unnested.the_ulong, unnested.the_json, unnested.the_jsonb,
+    	unnested.the_version, unnested.nullable_name, unnested.nullable_boolean, unnested.nullable_small_int, unnested.nullable_large_int, unnested.nullable_big_int,
+    	unnested.nullable_date, unnested.nullable_general_int, unnested.nullable_date_time, unnested.nullable_uuid, unnested.nullable_ulong, unnested.nullable_json,
+    	unnested.nullable_jsonb
+    FROM unnested
     ON CONFLICT (the_name, the_small_int)
     DO UPDATE SET
-        the_name = EXCLUDED.the_name,
-    	the_boolean = EXCLUDED.the_boolean,
-    	the_small_int = EXCLUDED.the_small_int,
+        the_boolean = EXCLUDED.the_boolean,
     	the_large_int = EXCLUDED.the_large_int,
     	the_big_int = EXCLUDED.the_big_int,
     	the_date = EXCLUDED.the_date,
@@ -496,6 +1047,7 @@ impl TableSampleWithId {
     	the_ulong = EXCLUDED.the_ulong,
     	the_json = EXCLUDED.the_json,
     	the_jsonb = EXCLUDED.the_jsonb,
+    	the_version = EXCLUDED.the_version,
     	nullable_name = EXCLUDED.nullable_name,
     	nullable_boolean = EXCLUDED.nullable_boolean,
     	nullable_small_int = EXCLUDED.nullable_small_int,
@@ -508,7 +1060,11 @@ impl TableSampleWithId {
     	nullable_ulong = EXCLUDED.nullable_ulong,
     	nullable_json = EXCLUDED.nullable_json,
     	nullable_jsonb = EXCLUDED.nullable_jsonb
-    returning auto_id
+    RETURNING auto_id, the_name, the_small_int
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name AND unnested.the_small_int = upserted.the_small_int
+    ORDER BY unnested.ordinality
     "#;
         for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
             for row in chunk_rows.iter() {
@@ -523,7 +1079,8 @@ impl TableSampleWithId {
                 the_uuid.push(row.the_uuid);
                 the_ulong.push(row.the_ulong);
                 the_json.push(&row.the_json);
-                the_jsonb.push(&row.the_jsonb);
+                the_jsonb.push(Json(&row.the_jsonb));
+                the_version.push(row.the_version);
                 nullable_name.push(&row.nullable_name);
                 nullable_boolean.push(row.nullable_boolean);
                 nullable_small_int.push(row.nullable_small_int);
@@ -553,6 +1110,7 @@ impl TableSampleWithId {
                         &the_ulong,
                         &the_json,
                         &the_jsonb,
+                        &the_version,
                         &nullable_name,
                         &nullable_boolean,
                         &nullable_small_int,
@@ -596,6 +1154,7 @@ impl TableSampleWithId {
             the_ulong.clear();
             the_json.clear();
             the_jsonb.clear();
+            the_version.clear();
             nullable_name.clear();
             nullable_boolean.clear();
             nullable_small_int.clear();
@@ -611,11 +1170,177 @@ impl TableSampleWithId {
         }
         Ok(auto_id
             .into_iter()
-            .zip(rows.into_iter())
+            .zip(rows)
             .map(|(auto_id, data)| SampleWithIdEntry { auto_id, data })
             .collect())
     }
 
+    /// Insert large batch of [SampleWithId] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to insert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_insert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleWithIdRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<Vec<SampleWithIdEntry>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_insert(&transaction, chunk_rows.to_vec(), chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_insert(&savepoint, chunk_rows.to_vec(), chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_insert_transactional chunk({chunk}) `sample_with_id` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
+    /// Upsert large batch of [SampleWithId] rows in a transaction, reporting the outcome of each chunk.
+    /// With [BatchMode::AllOrNothing] the first failing chunk rolls back the batch, reporting every chunk failed in chunk order.
+    /// With [BatchMode::ChunkSavepoints] failing chunks roll back to their savepoint and the rest commit.
+    ///
+    ///   * **client** - The tokio postgresql client, beginning the transaction
+    ///   * **rows** - Row data to upsert
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * **mode** - How the chunks are committed
+    ///   * _return_ - Report of the chunks, or `tokio_postgres::Error` beginning or ending the transaction
+    pub async fn bulk_upsert_transactional<C>(
+        client: &mut C,
+        rows: &[SampleWithIdRowData],
+        chunk_size: usize,
+        mode: BatchMode,
+    ) -> Result<BatchReport<Vec<SampleWithIdEntry>>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut transaction = client.transaction().await?;
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            let chunk_range = chunk * chunk_size..chunk * chunk_size + chunk_rows.len();
+            let result = match mode {
+                BatchMode::AllOrNothing => {
+                    Self::bulk_upsert(&transaction, chunk_rows.to_vec(), chunk_size).await
+                }
+                BatchMode::ChunkSavepoints => {
+                    let savepoint = transaction.transaction().await?;
+                    match Self::bulk_upsert(&savepoint, chunk_rows.to_vec(), chunk_size).await {
+                        Ok(result) => savepoint.commit().await.map(|_| result),
+                        Err(err) => {
+                            savepoint.rollback().await?;
+                            Err(err)
+                        }
+                    }
+                }
+            };
+            match result {
+                Ok(result) => report.succeeded.push(ChunkSuccess {
+                    chunk,
+                    rows: chunk_range,
+                    result,
+                }),
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_upsert_transactional chunk({chunk}) `sample_with_id` -> {err}"
+                    );
+                    if mode == BatchMode::AllOrNothing {
+                        // Nothing is committed, so every chunk failed - only this one with an error
+                        transaction.rollback().await?;
+                        let mut error = Some(err);
+                        let failed = (0..rows.len().div_ceil(chunk_size))
+                            .map(|failed_chunk| ChunkFailure {
+                                chunk: failed_chunk,
+                                rows: failed_chunk * chunk_size
+                                    ..rows.len().min((failed_chunk + 1) * chunk_size),
+                                error: if failed_chunk == chunk {
+                                    error.take()
+                                } else {
+                                    None
+                                },
+                            })
+                            .collect();
+                        return Ok(BatchReport {
+                            succeeded: Vec::new(),
+                            failed,
+                        });
+                    }
+                    report.failed.push(ChunkFailure {
+                        chunk,
+                        rows: chunk_range,
+                        error: Some(err),
+                    });
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(report)
+    }
+
     /// Delete all rows of `sample_with_id`
     ///
     ///   * **client** - The tokio postgresql client
@@ -628,39 +1353,2212 @@ impl TableSampleWithId {
         let delete_statement = "DELETE FROM sample_with_id";
         client.execute(delete_statement, &[]).await
     }
-}
 
-impl SampleWithIdRowData {
-    /// Number of fields
-    pub const NUM_FIELDS: usize = 24;
+    /// Create the table `sample_with_id`, with any enum types and unique indices, if not present.
+    /// Runs the statements of [Self::CREATE_DDL], against the backdoor table name if set.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn create_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let create_statement = r#"CREATE TABLE IF NOT EXISTS sample_with_id (
+    	auto_id serial NOT NULL,
+    	the_name varchar(255) NOT NULL,
+    	the_boolean boolean NOT NULL,
+    	the_small_int smallint NOT NULL,
+    	the_large_int bigint NOT NULL,
+    	the_big_int bigint NOT NULL,
+    	the_date date NOT NULL,
+    	the_general_int int NOT NULL,
+    	the_date_time timestamp NOT NULL,
+    	the_uuid uuid NOT NULL,
+    	the_ulong bigint NOT NULL,
+    	the_json json NOT NULL,
+    	the_jsonb jsonb NOT NULL,
+    	the_version int NOT NULL,
+    	nullable_name varchar(255),
+    	nullable_boolean boolean,
+    	nullable_small_int smallint,
+    	nullable_large_int bigint,
+    	nullable_big_int bigint,
+    	nullable_date date,
+    	nullable_general_int int,
+    	nullable_date_time timestamp,
+    	nullable_uuid uuid,
+    	nullable_ulong bigint,
+    	nullable_json json,
+    	nullable_jsonb jsonb,
+    	PRIMARY KEY (auto_id)
+    );
+    
+    CREATE UNIQUE INDEX IF NOT EXISTS sample_with_id_the_name_the_small_int_unique ON sample_with_id (the_name, the_small_int);"#;
+        match client.batch_execute(create_statement).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                tracing::error!("Failed create_table `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
 
-    /// Names of fields
-    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
-        "the_name",
-        "the_boolean",
-        "the_small_int",
-        "the_large_int",
-        "the_big_int",
-        "the_date",
-        "the_general_int",
-        "the_date_time",
-        "the_uuid",
-        "the_ulong",
-        "the_json",
-        "the_jsonb",
-        "nullable_name",
-        "nullable_boolean",
-        "nullable_small_int",
-        "nullable_large_int",
-        "nullable_big_int",
-        "nullable_date",
-        "nullable_general_int",
-        "nullable_date_time",
-        "nullable_uuid",
-        "nullable_ulong",
-        "nullable_json",
-        "nullable_jsonb",
-    ];
+    /// Drop the table `sample_with_id` if present, leaving any enum types in place
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - Success or tokio_postgres::Error
+    #[inline]
+    pub async fn drop_table<C>(client: &C) -> Result<(), tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let drop_statement = "DROP TABLE IF EXISTS sample_with_id";
+        client.execute(drop_statement, &[]).await.map(|_| ())
+    }
+
+    /// Verify the live table matches the columns assumed in [Self::COLUMN_SCHEMA].
+    /// Drift is returned in the report rather than as an error.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * _return_ - The report of any drift or the `tokio_postgres::Error` reading the schema
+    #[inline]
+    pub async fn verify_schema<C>(client: &C) -> Result<SchemaReport, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        verify_table_schema(client, "sample_with_id", &Self::COLUMN_SCHEMA).await
+    }
+
+    /// Select the row of `sample_with_id` identified by [SampleWithIdPkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_pkey<C>(
+        client: &C,
+        key: &SampleWithIdPkey,
+    ) -> Result<Option<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut selected =
+            Self::select_all_where(client, r#"auto_id = $1"#, &[&key.auto_id]).await?;
+        Ok(selected.pop())
+    }
+
+    /// Select the rows of `sample_with_id` identified by the batch of [SampleWithIdPkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **keys** - The keys identifying the rows
+    ///   * _return_ - The rows found
+    pub async fn select_by_pkeys<C>(
+        client: &C,
+        keys: &[SampleWithIdPkey],
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let auto_id = keys.iter().map(|key| key.auto_id).collect::<Vec<_>>();
+        Self::select_all_where(
+            client,
+            r#"(auto_id) IN (SELECT * FROM UNNEST($1::int[]))"#,
+            &[&auto_id],
+        )
+        .await
+    }
+
+    /// Update the row of `sample_with_id` identified by [SampleWithIdPkey] with the non-key fields of `row`
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * **row** - The new values for the row
+    ///   * _return_ - Number of rows updated
+    pub async fn update_by_pkey<C>(
+        client: &C,
+        key: &SampleWithIdPkey,
+        row: &SampleWithIdRowData,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let update_statement = r#"UPDATE sample_with_id SET
+        the_name = $1,
+    	the_boolean = $2,
+    	the_small_int = $3,
+    	the_large_int = $4,
+    	the_big_int = $5,
+    	the_date = $6,
+    	the_general_int = $7,
+    	the_date_time = $8,
+    	the_uuid = $9,
+    	the_ulong = $10,
+    	the_json = $11,
+    	the_jsonb = $12,
+    	the_version = $13,
+    	nullable_name = $14,
+    	nullable_boolean = $15,
+    	nullable_small_int = $16,
+    	nullable_large_int = $17,
+    	nullable_big_int = $18,
+    	nullable_date = $19,
+    	nullable_general_int = $20,
+    	nullable_date_time = $21,
+    	nullable_uuid = $22,
+    	nullable_ulong = $23,
+    	nullable_json = $24,
+    	nullable_jsonb = $25
+    WHERE auto_id = $26"#;
+        client
+            .execute(
+                update_statement,
+                &[
+                    &row.the_name,
+                    &row.the_boolean,
+                    &row.the_small_int,
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &row.the_json,
+                    &Json(&row.the_jsonb),
+                    &row.the_version,
+                    &row.nullable_name,
+                    &row.nullable_boolean,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb,
+                    &key.auto_id,
+                ],
+            )
+            .await
+    }
+
+    /// Update the row of `sample_with_id` identified by [SampleWithIdPkey] with the non-key fields of `row`,
+    /// only if the row is still at the version `row.the_version` it was read at.
+    /// The version is advanced by the update.
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * **row** - The new values for the row, with the version read
+    ///   * _return_ - The new version, or the conflict if the row is not at the version read
+    pub async fn update_by_pkey_if_version<C>(
+        client: &C,
+        key: &SampleWithIdPkey,
+        row: &SampleWithIdRowData,
+    ) -> Result<VersionedUpdate<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let update_statement = r#"UPDATE sample_with_id SET
+        the_name = $1,
+    	the_boolean = $2,
+    	the_small_int = $3,
+    	the_large_int = $4,
+    	the_big_int = $5,
+    	the_date = $6,
+    	the_general_int = $7,
+    	the_date_time = $8,
+    	the_uuid = $9,
+    	the_ulong = $10,
+    	the_json = $11,
+    	the_jsonb = $12,
+    	nullable_name = $13,
+    	nullable_boolean = $14,
+    	nullable_small_int = $15,
+    	nullable_large_int = $16,
+    	nullable_big_int = $17,
+    	nullable_date = $18,
+    	nullable_general_int = $19,
+    	nullable_date_time = $20,
+    	nullable_uuid = $21,
+    	nullable_ulong = $22,
+    	nullable_json = $23,
+    	nullable_jsonb = $24,
+    	the_version = the_version + 1
+    WHERE auto_id = $25 AND the_version = $26
+    RETURNING the_version"#;
+        match client
+            .query_opt(
+                update_statement,
+                &[
+                    &row.the_name,
+                    &row.the_boolean,
+                    &row.the_small_int,
+                    &row.the_large_int,
+                    &row.the_big_int,
+                    &row.the_date,
+                    &row.the_general_int,
+                    &row.the_date_time,
+                    &row.the_uuid,
+                    &row.the_ulong,
+                    &row.the_json,
+                    &Json(&row.the_jsonb),
+                    &row.nullable_name,
+                    &row.nullable_boolean,
+                    &row.nullable_small_int,
+                    &row.nullable_large_int,
+                    &row.nullable_big_int,
+                    &row.nullable_date,
+                    &row.nullable_general_int,
+                    &row.nullable_date_time,
+                    &row.nullable_uuid,
+                    &row.nullable_ulong,
+                    &row.nullable_json,
+                    &row.nullable_jsonb,
+                    &key.auto_id,
+                    &row.the_version,
+                ],
+            )
+            .await?
+        {
+            Some(updated) => Ok(VersionedUpdate::Updated {
+                version: updated.try_get(0)?,
+            }),
+            None => {
+                tracing::debug!("Version conflict update_by_pkey_if_version `sample_with_id`");
+                Ok(VersionedUpdate::Conflict)
+            }
+        }
+    }
+
+    /// Delete the row of `sample_with_id` identified by [SampleWithIdPkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - Number of rows deleted
+    pub async fn delete_by_pkey<C>(
+        client: &C,
+        key: &SampleWithIdPkey,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement = r#"DELETE FROM sample_with_id WHERE auto_id = $1"#;
+        client.execute(delete_statement, &[&key.auto_id]).await
+    }
+
+    /// Update large batch of rows of `sample_with_id` identified by [SampleWithIdPkey] with their non-key fields
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - The rows with the new values
+    ///   * **chunk_size** - How to chunk the updates
+    ///   * _return_ - Number of rows updated
+    pub async fn bulk_update<C>(
+        client: &C,
+        rows: &[SampleWithIdEntry],
+        chunk_size: usize,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(chunk_size);
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_boolean = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_boolean = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let mut affected = 0;
+        let bulk_update_statement = r#"UPDATE sample_with_id AS t SET
+        the_name = u.the_name,
+    	the_boolean = u.the_boolean,
+    	the_small_int = u.the_small_int,
+    	the_large_int = u.the_large_int,
+    	the_big_int = u.the_big_int,
+    	the_date = u.the_date,
+    	the_general_int = u.the_general_int,
+    	the_date_time = u.the_date_time,
+    	the_uuid = u.the_uuid,
+    	the_ulong = u.the_ulong,
+    	the_json = u.the_json,
+    	the_jsonb = u.the_jsonb,
+    	the_version = u.the_version,
+    	nullable_name = u.nullable_name,
+    	nullable_boolean = u.nullable_boolean,
+    	nullable_small_int = u.nullable_small_int,
+    	nullable_large_int = u.nullable_large_int,
+    	nullable_big_int = u.nullable_big_int,
+    	nullable_date = u.nullable_date,
+    	nullable_general_int = u.nullable_general_int,
+    	nullable_date_time = u.nullable_date_time,
+    	nullable_uuid = u.nullable_uuid,
+    	nullable_ulong = u.nullable_ulong,
+    	nullable_json = u.nullable_json,
+    	nullable_jsonb = u.nullable_jsonb
+    FROM UNNEST($1::int[], $2::varchar[], $3::boolean[], $4::smallint[], $5::bigint[], $6::bigint[], $7::date[], $8::int[], $9::timestamp[], $10::uuid[], $11::bigint[], $12::json[], $13::jsonb[], $14::int[], $15::varchar[], $16::boolean[], $17::smallint[], $18::bigint[], $19::bigint[], $20::date[], $21::int[], $22::timestamp[], $23::uuid[], $24::bigint[], $25::json[], $26::jsonb[]) AS u(auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json, nullable_jsonb)
+    WHERE t.auto_id = u.auto_id"#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for item in chunk_rows.iter() {
+                auto_id.push(item.auto_id);
+                the_name.push(&item.data.the_name);
+                the_boolean.push(item.data.the_boolean);
+                the_small_int.push(item.data.the_small_int);
+                the_large_int.push(item.data.the_large_int);
+                the_big_int.push(item.data.the_big_int);
+                the_date.push(item.data.the_date);
+                the_general_int.push(item.data.the_general_int);
+                the_date_time.push(item.data.the_date_time);
+                the_uuid.push(item.data.the_uuid);
+                the_ulong.push(item.data.the_ulong);
+                the_json.push(&item.data.the_json);
+                the_jsonb.push(Json(&item.data.the_jsonb));
+                the_version.push(item.data.the_version);
+                nullable_name.push(&item.data.nullable_name);
+                nullable_boolean.push(item.data.nullable_boolean);
+                nullable_small_int.push(item.data.nullable_small_int);
+                nullable_large_int.push(item.data.nullable_large_int);
+                nullable_big_int.push(item.data.nullable_big_int);
+                nullable_date.push(item.data.nullable_date);
+                nullable_general_int.push(item.data.nullable_general_int);
+                nullable_date_time.push(item.data.nullable_date_time);
+                nullable_uuid.push(item.data.nullable_uuid);
+                nullable_ulong.push(item.data.nullable_ulong);
+                nullable_json.push(&item.data.nullable_json);
+                nullable_jsonb.push(&item.data.nullable_jsonb);
+            }
+
+            let chunk_result = client
+                .execute(
+                    bulk_update_statement,
+                    &[
+                        &auto_id,
+                        &the_name,
+                        &the_boolean,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_version,
+                        &nullable_name,
+                        &nullable_boolean,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_update `sample_with_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk update of size({}) in `sample_with_id`",
+                        chunk_result
+                    );
+                    affected += chunk_result;
+                }
+            }
+            auto_id.clear();
+            the_name.clear();
+            the_boolean.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_version.clear();
+            nullable_name.clear();
+            nullable_boolean.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+        }
+
+        Ok(affected)
+    }
+
+    /// Delete large batch of rows of `sample_with_id` identified by [SampleWithIdPkey]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **keys** - The keys identifying the rows
+    ///   * **chunk_size** - How to chunk the deletes
+    ///   * _return_ - Number of rows deleted
+    pub async fn bulk_delete_by_pkeys<C>(
+        client: &C,
+        keys: &[SampleWithIdPkey],
+        chunk_size: usize,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(chunk_size);
+        let mut affected = 0;
+        let bulk_delete_statement =
+            r#"DELETE FROM sample_with_id WHERE (auto_id) IN (SELECT * FROM UNNEST($1::int[]))"#;
+        for (chunk, chunk_keys) in keys.chunks(chunk_size).enumerate() {
+            for item in chunk_keys.iter() {
+                auto_id.push(item.auto_id);
+            }
+
+            let chunk_result = client.execute(bulk_delete_statement, &[&auto_id]).await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!(
+                        "Failed bulk_delete_by_pkeys `sample_with_id` chunk({chunk}) -> {err}"
+                    );
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk delete of size({}) in `sample_with_id`",
+                        chunk_result
+                    );
+                    affected += chunk_result;
+                }
+            }
+            auto_id.clear();
+        }
+
+        Ok(affected)
+    }
+
+    /// Count rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - Number of rows matched or the `tokio_postgres::Error`
+    pub async fn count_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<i64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT count(*) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed count_where `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Check for any row of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - True if any row matched or the `tokio_postgres::Error`
+    pub async fn exists_where<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<bool, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT EXISTS (SELECT 1 FROM sample_with_id WHERE {where_clause})"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed exists_where `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `auto_id` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_auto_id<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(auto_id) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_auto_id `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `auto_id` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_auto_id<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(auto_id) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_auto_id `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_name` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_name) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_name `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_name` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_name) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_name `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_small_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_small_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_small_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_small_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_small_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_small_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_large_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_large_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_large_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_large_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_large_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_large_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_big_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_big_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_big_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_big_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_big_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_big_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_date) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_date) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_general_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_general_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_general_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_general_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_general_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_general_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_date_time` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_date_time) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_date_time `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_date_time` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_date_time) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_date_time `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_ulong` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_ulong) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_ulong `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_ulong` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_ulong) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_ulong `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `the_version` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_the_version<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(the_version) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_the_version `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `the_version` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_the_version<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(the_version) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_the_version `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_name` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_name) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_name `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_name` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_name<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<String>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_name) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_name `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_small_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_small_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_small_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_small_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_small_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i16>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_small_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_small_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_large_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_large_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_large_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_large_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_large_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_large_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_large_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_big_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_big_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_big_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_big_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_big_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_big_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_big_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_date) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDate>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_date) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_general_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_general_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_general_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_general_int` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_general_int<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<i32>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_general_int) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_general_int `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_date_time` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_date_time) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_date_time `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_date_time` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_date_time<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<chrono::NaiveDateTime>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_date_time) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_date_time `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Least `nullable_ulong` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The min value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn min_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT min(nullable_ulong) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed min_nullable_ulong `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Greatest `nullable_ulong` of rows of `sample_with_id` with provided where clause
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **where_clause** - The where clause (sans `where` keyword)
+    ///   * **params** - Any clause parameters
+    ///   * _return_ - The max value, `None` if no rows match, or the `tokio_postgres::Error`
+    pub async fn max_nullable_ulong<C>(
+        client: &C,
+        where_clause: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<U64>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let aggregate_statement =
+            format!(r#"SELECT max(nullable_ulong) FROM sample_with_id WHERE {where_clause}"#);
+        match client.query_one(&aggregate_statement, params).await {
+            Ok(row) => row.try_get(0),
+            Err(err) => {
+                tracing::error!("Failed max_nullable_ulong `sample_with_id` -> {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Insert large batch of [SampleWithId] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ - Entries with corresponding _auto_id_
+    pub async fn bulk_insert_prepared<C>(
+        client: &C,
+        rows: Vec<SampleWithIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_boolean = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_boolean = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+
+        let bulk_insert_statement = r#"insert into sample_with_id
+    (
+    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
+    )
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::boolean[], $3::smallint[], $4::bigint[], $5::bigint[], $6::date[],
+    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::jsonb[],
+    	$13::int[], $14::varchar[], $15::boolean[], $16::smallint[], $17::bigint[], $18::bigint[],
+    	$19::date[], $20::int[], $21::timestamp[], $22::uuid[], $23::bigint[], $24::json[],
+    	$25::jsonb[]
+    )
+    returning auto_id
+    "#;
+        let prepared = client.prepare_cached(bulk_insert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_boolean.push(row.the_boolean);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(&row.the_json);
+                the_jsonb.push(Json(&row.the_jsonb));
+                the_version.push(row.the_version);
+                nullable_name.push(&row.nullable_name);
+                nullable_boolean.push(row.nullable_boolean);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(&row.nullable_jsonb);
+            }
+
+            let chunk_result = client
+                .query(
+                    &prepared,
+                    &[
+                        &the_name,
+                        &the_boolean,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_version,
+                        &nullable_name,
+                        &nullable_boolean,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_insert `sample_with_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk insert of size({}) in `sample_with_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_boolean.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_version.clear();
+            nullable_name.clear();
+            nullable_boolean.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+        }
+
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleWithIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Upsert large batch of [SampleWithId] rows with the statement cached by the connection of `client`.
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert_prepared<C>(
+        client: &C,
+        rows: Vec<SampleWithIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_boolean = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_boolean = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"WITH unnested AS (
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::boolean[], $3::smallint[], $4::bigint[], $5::bigint[], $6::date[],
+    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::jsonb[],
+    	$13::int[], $14::varchar[], $15::boolean[], $16::smallint[], $17::bigint[], $18::bigint[],
+    	$19::date[], $20::int[], $21::timestamp[], $22::uuid[], $23::bigint[], $24::json[],
+    	$25::jsonb[]
+    ) WITH ORDINALITY AS unnested(the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb, ordinality)
+    ), upserted AS (
+    insert into sample_with_id
+    (
+    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
+    )
+    SELECT unnested.the_name, unnested.the_boolean, unnested.the_small_int, unnested.the_large_int, unnested.the_big_int, unnested.the_date,
+    	unnested.the_general_int, unnested.the_date_time, unnested.the_uuid, unnested.the_ulong, unnested.the_json, unnested.the_jsonb,
+    	unnested.the_version, unnested.nullable_name, unnested.nullable_boolean, unnested.nullable_small_int, unnested.nullable_large_int, unnested.nullable_big_int,
+    	unnested.nullable_date, unnested.nullable_general_int, unnested.nullable_date_time, unnested.nullable_uuid, unnested.nullable_ulong, unnested.nullable_json,
+    	unnested.nullable_jsonb
+    FROM unnested
+    ON CONFLICT (the_name, the_small_int)
+    DO UPDATE SET
+        the_boolean = EXCLUDED.the_boolean,
+    	the_large_int = EXCLUDED.the_large_int,
+    	the_big_int = EXCLUDED.the_big_int,
+    	the_date = EXCLUDED.the_date,
+    	the_general_int = EXCLUDED.the_general_int,
+    	the_date_time = EXCLUDED.the_date_time,
+    	the_uuid = EXCLUDED.the_uuid,
+    	the_ulong = EXCLUDED.the_ulong,
+    	the_json = EXCLUDED.the_json,
+    	the_jsonb = EXCLUDED.the_jsonb,
+    	the_version = EXCLUDED.the_version,
+    	nullable_name = EXCLUDED.nullable_name,
+    	nullable_boolean = EXCLUDED.nullable_boolean,
+    	nullable_small_int = EXCLUDED.nullable_small_int,
+    	nullable_large_int = EXCLUDED.nullable_large_int,
+    	nullable_big_int = EXCLUDED.nullable_big_int,
+    	nullable_date = EXCLUDED.nullable_date,
+    	nullable_general_int = EXCLUDED.nullable_general_int,
+    	nullable_date_time = EXCLUDED.nullable_date_time,
+    	nullable_uuid = EXCLUDED.nullable_uuid,
+    	nullable_ulong = EXCLUDED.nullable_ulong,
+    	nullable_json = EXCLUDED.nullable_json,
+    	nullable_jsonb = EXCLUDED.nullable_jsonb
+    RETURNING auto_id, the_name, the_small_int
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name AND unnested.the_small_int = upserted.the_small_int
+    ORDER BY unnested.ordinality
+    "#;
+        let prepared = client.prepare_cached(bulk_upsert_statement).await?;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_boolean.push(row.the_boolean);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(&row.the_json);
+                the_jsonb.push(Json(&row.the_jsonb));
+                the_version.push(row.the_version);
+                nullable_name.push(&row.nullable_name);
+                nullable_boolean.push(row.nullable_boolean);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(&row.nullable_jsonb);
+            }
+            let chunk_result = client
+                .query(
+                    &prepared,
+                    &[
+                        &the_name,
+                        &the_boolean,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_version,
+                        &nullable_name,
+                        &nullable_boolean,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert `sample_with_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk upsert of size({}) in `sample_with_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_boolean.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_version.clear();
+            nullable_name.clear();
+            nullable_boolean.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+        }
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleWithIdEntry { auto_id, data })
+            .collect())
+    }
+
+    /// Delete all rows of `sample_with_id` with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * _return_ - Number of rows deleted
+    #[inline]
+    pub async fn delete_all_prepared<C>(client: &C) -> Result<u64, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let delete_statement = "DELETE FROM sample_with_id";
+        let prepared = client.prepare_cached(delete_statement).await?;
+        client.execute(&prepared, &[]).await
+    }
+
+    /// Select the row of `sample_with_id` identified by [SampleWithIdPkey] with the statement cached by the connection of `client`
+    ///
+    ///   * **client** - The pooled deadpool postgresql client, caching the statements prepared on its connection
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_pkey_prepared<C>(
+        client: &C,
+        key: &SampleWithIdPkey,
+    ) -> Result<Option<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: deadpool_postgres::GenericClient,
+    {
+        let select_statement = r#"SELECT
+    auto_id, the_name, the_boolean, the_small_int, the_large_int, the_big_int,
+    	the_date, the_general_int, the_date_time, the_uuid, the_ulong, the_json,
+    	the_jsonb, the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int,
+    	nullable_big_int, nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong,
+    	nullable_json, nullable_jsonb
+    FROM sample_with_id
+    WHERE auto_id = $1"#;
+        let prepared = client.prepare_cached(select_statement).await?;
+        client
+            .query_opt(&prepared, &[&key.auto_id])
+            .await?
+            .map(|row| Self::from_row(&row))
+            .transpose()
+    }
+
+    /// Select the row of `sample_with_id` identified by [SampleWithIdTheNameTheSmallIntUnique]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - The row if found
+    pub async fn select_by_sample_with_id_the_name_the_small_int_unique<C>(
+        client: &C,
+        key: &SampleWithIdTheNameTheSmallIntUnique,
+    ) -> Result<Option<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut selected = Self::select_all_where(
+            client,
+            r#"the_name = $1 AND the_small_int = $2"#,
+            &[&key.the_name, &key.the_small_int],
+        )
+        .await?;
+        Ok(selected.pop())
+    }
+
+    /// Delete the row of `sample_with_id` identified by [SampleWithIdTheNameTheSmallIntUnique]
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **key** - The key identifying the row
+    ///   * _return_ - Number of rows deleted
+    pub async fn delete_by_sample_with_id_the_name_the_small_int_unique<C>(
+        client: &C,
+        key: &SampleWithIdTheNameTheSmallIntUnique,
+    ) -> Result<u64, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let delete_statement =
+            r#"DELETE FROM sample_with_id WHERE the_name = $1 AND the_small_int = $2"#;
+        client
+            .execute(delete_statement, &[&key.the_name, &key.the_small_int])
+            .await
+    }
+
+    /// Upsert large batch of [SampleWithId] rows, resolving conflicts on (the_name, the_small_int).
+    ///
+    ///   * **client** - The tokio postgresql client
+    ///   * **rows** - Row data, consumed but returned with ids
+    ///   * **chunk_size** - How to chunk the inserts
+    ///   * _return_ -
+    pub async fn bulk_upsert_by_sample_with_id_the_name_the_small_int_unique<C>(
+        client: &C,
+        rows: Vec<SampleWithIdRowData>,
+        chunk_size: usize,
+    ) -> Result<Vec<SampleWithIdEntry>, tokio_postgres::Error>
+    where
+        C: tokio_postgres::GenericClient,
+    {
+        let mut auto_id = Vec::with_capacity(rows.len());
+
+        let mut the_name = Vec::with_capacity(chunk_size);
+        let mut the_boolean = Vec::with_capacity(chunk_size);
+        let mut the_small_int = Vec::with_capacity(chunk_size);
+        let mut the_large_int = Vec::with_capacity(chunk_size);
+        let mut the_big_int = Vec::with_capacity(chunk_size);
+        let mut the_date = Vec::with_capacity(chunk_size);
+        let mut the_general_int = Vec::with_capacity(chunk_size);
+        let mut the_date_time = Vec::with_capacity(chunk_size);
+        let mut the_uuid = Vec::with_capacity(chunk_size);
+        let mut the_ulong = Vec::with_capacity(chunk_size);
+        let mut the_json = Vec::with_capacity(chunk_size);
+        let mut the_jsonb = Vec::with_capacity(chunk_size);
+        let mut the_version = Vec::with_capacity(chunk_size);
+        let mut nullable_name = Vec::with_capacity(chunk_size);
+        let mut nullable_boolean = Vec::with_capacity(chunk_size);
+        let mut nullable_small_int = Vec::with_capacity(chunk_size);
+        let mut nullable_large_int = Vec::with_capacity(chunk_size);
+        let mut nullable_big_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date = Vec::with_capacity(chunk_size);
+        let mut nullable_general_int = Vec::with_capacity(chunk_size);
+        let mut nullable_date_time = Vec::with_capacity(chunk_size);
+        let mut nullable_uuid = Vec::with_capacity(chunk_size);
+        let mut nullable_ulong = Vec::with_capacity(chunk_size);
+        let mut nullable_json = Vec::with_capacity(chunk_size);
+        let mut nullable_jsonb = Vec::with_capacity(chunk_size);
+        let bulk_upsert_statement = r#"WITH unnested AS (
+    SELECT * FROM UNNEST
+    (
+    	$1::varchar[], $2::boolean[], $3::smallint[], $4::bigint[], $5::bigint[], $6::date[],
+    	$7::int[], $8::timestamp[], $9::uuid[], $10::bigint[], $11::json[], $12::jsonb[],
+    	$13::int[], $14::varchar[], $15::boolean[], $16::smallint[], $17::bigint[], $18::bigint[],
+    	$19::date[], $20::int[], $21::timestamp[], $22::uuid[], $23::bigint[], $24::json[],
+    	$25::jsonb[]
+    ) WITH ORDINALITY AS unnested(the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb, ordinality)
+    ), upserted AS (
+    insert into sample_with_id
+    (
+    	the_name, the_boolean, the_small_int, the_large_int, the_big_int, the_date,
+    	the_general_int, the_date_time, the_uuid, the_ulong, the_json, the_jsonb,
+    	the_version, nullable_name, nullable_boolean, nullable_small_int, nullable_large_int, nullable_big_int,
+    	nullable_date, nullable_general_int, nullable_date_time, nullable_uuid, nullable_ulong, nullable_json,
+    	nullable_jsonb
+    )
+    SELECT unnested.the_name, unnested.the_boolean, unnested.the_small_int, unnested.the_large_int, unnested.the_big_int, unnested.the_date,
+    	unnested.the_general_int, unnested.the_date_time, unnested.the_uuid, unnested.the_ulong, unnested.the_json, unnested.the_jsonb,
+    	unnested.the_version, unnested.nullable_name, unnested.nullable_boolean, unnested.nullable_small_int, unnested.nullable_large_int, unnested.nullable_big_int,
+    	unnested.nullable_date, unnested.nullable_general_int, unnested.nullable_date_time, unnested.nullable_uuid, unnested.nullable_ulong, unnested.nullable_json,
+    	unnested.nullable_jsonb
+    FROM unnested
+    ON CONFLICT (the_name, the_small_int)
+    DO UPDATE SET
+        the_boolean = EXCLUDED.the_boolean,
+    	the_large_int = EXCLUDED.the_large_int,
+    	the_big_int = EXCLUDED.the_big_int,
+    	the_date = EXCLUDED.the_date,
+    	the_general_int = EXCLUDED.the_general_int,
+    	the_date_time = EXCLUDED.the_date_time,
+    	the_uuid = EXCLUDED.the_uuid,
+    	the_ulong = EXCLUDED.the_ulong,
+    	the_json = EXCLUDED.the_json,
+    	the_jsonb = EXCLUDED.the_jsonb,
+    	the_version = EXCLUDED.the_version,
+    	nullable_name = EXCLUDED.nullable_name,
+    	nullable_boolean = EXCLUDED.nullable_boolean,
+    	nullable_small_int = EXCLUDED.nullable_small_int,
+    	nullable_large_int = EXCLUDED.nullable_large_int,
+    	nullable_big_int = EXCLUDED.nullable_big_int,
+    	nullable_date = EXCLUDED.nullable_date,
+    	nullable_general_int = EXCLUDED.nullable_general_int,
+    	nullable_date_time = EXCLUDED.nullable_date_time,
+    	nullable_uuid = EXCLUDED.nullable_uuid,
+    	nullable_ulong = EXCLUDED.nullable_ulong,
+    	nullable_json = EXCLUDED.nullable_json,
+    	nullable_jsonb = EXCLUDED.nullable_jsonb
+    RETURNING auto_id, the_name, the_small_int
+    )
+    SELECT upserted.auto_id
+    FROM unnested JOIN upserted ON unnested.the_name = upserted.the_name AND unnested.the_small_int = upserted.the_small_int
+    ORDER BY unnested.ordinality
+    "#;
+        for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+            for row in chunk_rows.iter() {
+                the_name.push(&row.the_name);
+                the_boolean.push(row.the_boolean);
+                the_small_int.push(row.the_small_int);
+                the_large_int.push(row.the_large_int);
+                the_big_int.push(row.the_big_int);
+                the_date.push(row.the_date);
+                the_general_int.push(row.the_general_int);
+                the_date_time.push(row.the_date_time);
+                the_uuid.push(row.the_uuid);
+                the_ulong.push(row.the_ulong);
+                the_json.push(&row.the_json);
+                the_jsonb.push(Json(&row.the_jsonb));
+                the_version.push(row.the_version);
+                nullable_name.push(&row.nullable_name);
+                nullable_boolean.push(row.nullable_boolean);
+                nullable_small_int.push(row.nullable_small_int);
+                nullable_large_int.push(row.nullable_large_int);
+                nullable_big_int.push(row.nullable_big_int);
+                nullable_date.push(row.nullable_date);
+                nullable_general_int.push(row.nullable_general_int);
+                nullable_date_time.push(row.nullable_date_time);
+                nullable_uuid.push(row.nullable_uuid);
+                nullable_ulong.push(row.nullable_ulong);
+                nullable_json.push(&row.nullable_json);
+                nullable_jsonb.push(&row.nullable_jsonb);
+            }
+            let chunk_result = client
+                .query(
+                    bulk_upsert_statement,
+                    &[
+                        &the_name,
+                        &the_boolean,
+                        &the_small_int,
+                        &the_large_int,
+                        &the_big_int,
+                        &the_date,
+                        &the_general_int,
+                        &the_date_time,
+                        &the_uuid,
+                        &the_ulong,
+                        &the_json,
+                        &the_jsonb,
+                        &the_version,
+                        &nullable_name,
+                        &nullable_boolean,
+                        &nullable_small_int,
+                        &nullable_large_int,
+                        &nullable_big_int,
+                        &nullable_date,
+                        &nullable_general_int,
+                        &nullable_date_time,
+                        &nullable_uuid,
+                        &nullable_ulong,
+                        &nullable_json,
+                        &nullable_jsonb,
+                    ],
+                )
+                .await;
+
+            match &chunk_result {
+                Err(err) => {
+                    tracing::error!("Failed bulk_upsert_by_sample_with_id_the_name_the_small_int_unique `sample_with_id` chunk({chunk}) -> {err}");
+                    chunk_result?;
+                }
+                Ok(chunk_result) => {
+                    tracing::debug!(
+                        "Finished bulk upsert of size({}) in `sample_with_id`",
+                        chunk_result.len()
+                    );
+                    chunk_result.iter().for_each(|result| {
+                        auto_id.push(result.get(0));
+                    });
+                }
+            }
+            the_name.clear();
+            the_boolean.clear();
+            the_small_int.clear();
+            the_large_int.clear();
+            the_big_int.clear();
+            the_date.clear();
+            the_general_int.clear();
+            the_date_time.clear();
+            the_uuid.clear();
+            the_ulong.clear();
+            the_json.clear();
+            the_jsonb.clear();
+            the_version.clear();
+            nullable_name.clear();
+            nullable_boolean.clear();
+            nullable_small_int.clear();
+            nullable_large_int.clear();
+            nullable_big_int.clear();
+            nullable_date.clear();
+            nullable_general_int.clear();
+            nullable_date_time.clear();
+            nullable_uuid.clear();
+            nullable_ulong.clear();
+            nullable_json.clear();
+            nullable_jsonb.clear();
+        }
+        Ok(auto_id
+            .into_iter()
+            .zip(rows)
+            .map(|(auto_id, data)| SampleWithIdEntry { auto_id, data })
+            .collect())
+    }
+}
+
+impl SampleWithIdColumn {
+    /// All columns of the table
+    pub const ALL: [SampleWithIdColumn; 26] = [
+        SampleWithIdColumn::AutoId,
+        SampleWithIdColumn::TheName,
+        SampleWithIdColumn::TheBoolean,
+        SampleWithIdColumn::TheSmallInt,
+        SampleWithIdColumn::TheLargeInt,
+        SampleWithIdColumn::TheBigInt,
+        SampleWithIdColumn::TheDate,
+        SampleWithIdColumn::TheGeneralInt,
+        SampleWithIdColumn::TheDateTime,
+        SampleWithIdColumn::TheUuid,
+        SampleWithIdColumn::TheUlong,
+        SampleWithIdColumn::TheJson,
+        SampleWithIdColumn::TheJsonb,
+        SampleWithIdColumn::TheVersion,
+        SampleWithIdColumn::NullableName,
+        SampleWithIdColumn::NullableBoolean,
+        SampleWithIdColumn::NullableSmallInt,
+        SampleWithIdColumn::NullableLargeInt,
+        SampleWithIdColumn::NullableBigInt,
+        SampleWithIdColumn::NullableDate,
+        SampleWithIdColumn::NullableGeneralInt,
+        SampleWithIdColumn::NullableDateTime,
+        SampleWithIdColumn::NullableUuid,
+        SampleWithIdColumn::NullableUlong,
+        SampleWithIdColumn::NullableJson,
+        SampleWithIdColumn::NullableJsonb,
+    ];
+}
+
+impl SampleWithIdRowData {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 25;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = [
+        "the_name",
+        "the_boolean",
+        "the_small_int",
+        "the_large_int",
+        "the_big_int",
+        "the_date",
+        "the_general_int",
+        "the_date_time",
+        "the_uuid",
+        "the_ulong",
+        "the_json",
+        "the_jsonb",
+        "the_version",
+        "nullable_name",
+        "nullable_boolean",
+        "nullable_small_int",
+        "nullable_large_int",
+        "nullable_big_int",
+        "nullable_date",
+        "nullable_general_int",
+        "nullable_date_time",
+        "nullable_uuid",
+        "nullable_ulong",
+        "nullable_json",
+        "nullable_jsonb",
+    ];
 }
 
 impl SampleWithIdPkey {
@@ -673,7 +3571,221 @@ impl SampleWithIdPkey {
 
 impl TableSampleWithId {
     /// The total number of key and value columns
-    pub const COLUMN_COUNT: usize = 25;
+    pub const COLUMN_COUNT: usize = 26;
+
+    /// Statements creating the table `sample_with_id`, its enum types and unique indices
+    pub const CREATE_DDL: &str = r#"CREATE TABLE IF NOT EXISTS sample_with_id (
+  	auto_id serial NOT NULL,
+  	the_name varchar(255) NOT NULL,
+  	the_boolean boolean NOT NULL,
+  	the_small_int smallint NOT NULL,
+  	the_large_int bigint NOT NULL,
+  	the_big_int bigint NOT NULL,
+  	the_date date NOT NULL,
+  	the_general_int int NOT NULL,
+  	the_date_time timestamp NOT NULL,
+  	the_uuid uuid NOT NULL,
+  	the_ulong bigint NOT NULL,
+  	the_json json NOT NULL,
+  	the_jsonb jsonb NOT NULL,
+  	the_version int NOT NULL,
+  	nullable_name varchar(255),
+  	nullable_boolean boolean,
+  	nullable_small_int smallint,
+  	nullable_large_int bigint,
+  	nullable_big_int bigint,
+  	nullable_date date,
+  	nullable_general_int int,
+  	nullable_date_time timestamp,
+  	nullable_uuid uuid,
+  	nullable_ulong bigint,
+  	nullable_json json,
+  	nullable_jsonb jsonb,
+  	PRIMARY KEY (auto_id)
+  );
+  
+  CREATE UNIQUE INDEX IF NOT EXISTS sample_with_id_the_name_the_small_int_unique ON sample_with_id (the_name, the_small_int);"#;
+
+    /// The columns of the table, in order, as assumed by the generated code
+    pub const COLUMN_SCHEMA: [ColumnSchema; Self::COLUMN_COUNT] = [
+        ColumnSchema {
+            name: "auto_id",
+            udt_name: "int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_name",
+            udt_name: "varchar",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_boolean",
+            udt_name: "bool",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_small_int",
+            udt_name: "int2",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_large_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_big_int",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date",
+            udt_name: "date",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_general_int",
+            udt_name: "int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_date_time",
+            udt_name: "timestamp",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_uuid",
+            udt_name: "uuid",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_ulong",
+            udt_name: "int8",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_json",
+            udt_name: "json",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_jsonb",
+            udt_name: "jsonb",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "the_version",
+            udt_name: "int4",
+            is_nullable: false,
+        },
+        ColumnSchema {
+            name: "nullable_name",
+            udt_name: "varchar",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_boolean",
+            udt_name: "bool",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_small_int",
+            udt_name: "int2",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_large_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_big_int",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date",
+            udt_name: "date",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_general_int",
+            udt_name: "int4",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_date_time",
+            udt_name: "timestamp",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_uuid",
+            udt_name: "uuid",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_ulong",
+            udt_name: "int8",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_json",
+            udt_name: "json",
+            is_nullable: true,
+        },
+        ColumnSchema {
+            name: "nullable_jsonb",
+            udt_name: "jsonb",
+            is_nullable: true,
+        },
+    ];
+}
+
+impl SampleWithIdTheNameTheSmallIntUnique {
+    /// Number of fields
+    pub const NUM_FIELDS: usize = 2;
+
+    /// Names of fields
+    pub const FIELD_NAMES: [&'static str; Self::NUM_FIELDS] = ["the_name", "the_small_int"];
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- trait impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SqlColumn for SampleWithIdColumn {
+    /// The name of the column as it appears in sql
+    ///
+    ///   * _return_ - The column name
+    fn column_name(&self) -> &'static str {
+        match self {
+            SampleWithIdColumn::AutoId => "auto_id",
+            SampleWithIdColumn::TheName => "the_name",
+            SampleWithIdColumn::TheBoolean => "the_boolean",
+            SampleWithIdColumn::TheSmallInt => "the_small_int",
+            SampleWithIdColumn::TheLargeInt => "the_large_int",
+            SampleWithIdColumn::TheBigInt => "the_big_int",
+            SampleWithIdColumn::TheDate => "the_date",
+            SampleWithIdColumn::TheGeneralInt => "the_general_int",
+            SampleWithIdColumn::TheDateTime => "the_date_time",
+            SampleWithIdColumn::TheUuid => "the_uuid",
+            SampleWithIdColumn::TheUlong => "the_ulong",
+            SampleWithIdColumn::TheJson => "the_json",
+            SampleWithIdColumn::TheJsonb => "the_jsonb",
+            SampleWithIdColumn::TheVersion => "the_version",
+            SampleWithIdColumn::NullableName => "nullable_name",
+            SampleWithIdColumn::NullableBoolean => "nullable_boolean",
+            SampleWithIdColumn::NullableSmallInt => "nullable_small_int",
+            SampleWithIdColumn::NullableLargeInt => "nullable_large_int",
+            SampleWithIdColumn::NullableBigInt => "nullable_big_int",
+            SampleWithIdColumn::NullableDate => "nullable_date",
+            SampleWithIdColumn::NullableGeneralInt => "nullable_general_int",
+            SampleWithIdColumn::NullableDateTime => "nullable_date_time",
+            SampleWithIdColumn::NullableUuid => "nullable_uuid",
+            SampleWithIdColumn::NullableUlong => "nullable_ulong",
+            SampleWithIdColumn::NullableJson => "nullable_json",
+            SampleWithIdColumn::NullableJsonb => "nullable_jsonb",
+        }
+    }
 }
 
 // α <mod-def sample_with_id>
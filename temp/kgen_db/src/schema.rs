@@ -0,0 +1,163 @@
+//! Verification of the columns assumed by the table gateways against the live schema
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A difference between the columns assumed by the generated code and those of the live table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDrift {
+    /// Column assumed by the generated code is not in the table
+    MissingColumn {
+        /// The column name
+        column: &'static str,
+    },
+    /// Column of the table unknown to the generated code
+    UnexpectedColumn {
+        /// The column name
+        column: String,
+        /// The postgres type of the column
+        udt_name: String,
+    },
+    /// Column is at a different position than assumed, so reading rows by index is off
+    MovedColumn {
+        /// The column name
+        column: &'static str,
+        /// Index assumed by the generated code
+        expected_index: usize,
+        /// Index in the table
+        actual_index: usize,
+    },
+    /// Column has a different postgres type than assumed
+    TypeMismatch {
+        /// The column name
+        column: &'static str,
+        /// The assumed `udt_name`
+        expected: &'static str,
+        /// The `udt_name` of the table
+        actual: String,
+    },
+    /// Column nullability differs from that assumed
+    NullabilityMismatch {
+        /// The column name
+        column: &'static str,
+        /// True if the generated code assumed the column nullable
+        expected_nullable: bool,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- structs ---
+////////////////////////////////////////////////////////////////////////////////////
+/// A column of a table as assumed by the generated table gateway
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSchema {
+    /// Name of the column
+    pub name: &'static str,
+    /// Postgres type as named by `information_schema.columns.udt_name`
+    pub udt_name: &'static str,
+    /// True if the column admits `NULL`
+    pub is_nullable: bool,
+}
+
+/// Differences between the columns assumed by a table gateway and the live table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaReport {
+    /// The table verified
+    pub table_name: String,
+    /// Every difference found, empty if consistent
+    pub drifts: Vec<SchemaDrift>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Compare the columns assumed for `table_name` with those in `information_schema.columns`
+/// of the current schema, by name, position, type and nullability.
+///
+///   * **client** - The tokio postgresql client
+///   * **table_name** - The table to verify
+///   * **expected** - The columns assumed, in order
+///   * _return_ - The report of any drift or the `tokio_postgres::Error` reading the schema
+pub async fn verify_table_schema<C>(
+    client: &C,
+    table_name: &str,
+    expected: &[ColumnSchema],
+) -> Result<SchemaReport, tokio_postgres::Error>
+where
+    C: tokio_postgres::GenericClient,
+{
+    let columns_statement = r#"SELECT column_name::text, udt_name::text, is_nullable = 'YES'
+FROM information_schema.columns
+WHERE table_schema = current_schema() AND table_name = $1
+ORDER BY ordinal_position"#;
+    let actual = client
+        .query(columns_statement, &[&table_name])
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok((
+                row.try_get::<_, String>(0)?,
+                row.try_get::<_, String>(1)?,
+                row.try_get::<_, bool>(2)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, tokio_postgres::Error>>()?;
+
+    let mut drifts = Vec::new();
+    for (expected_index, column) in expected.iter().enumerate() {
+        match actual.iter().position(|(name, _, _)| name == column.name) {
+            None => drifts.push(SchemaDrift::MissingColumn {
+                column: column.name,
+            }),
+            Some(actual_index) => {
+                let (_, udt_name, is_nullable) = &actual[actual_index];
+                if actual_index != expected_index {
+                    drifts.push(SchemaDrift::MovedColumn {
+                        column: column.name,
+                        expected_index,
+                        actual_index,
+                    });
+                }
+                if udt_name != column.udt_name {
+                    drifts.push(SchemaDrift::TypeMismatch {
+                        column: column.name,
+                        expected: column.udt_name,
+                        actual: udt_name.clone(),
+                    });
+                }
+                if *is_nullable != column.is_nullable {
+                    drifts.push(SchemaDrift::NullabilityMismatch {
+                        column: column.name,
+                        expected_nullable: column.is_nullable,
+                    });
+                }
+            }
+        }
+    }
+    drifts.extend(
+        actual
+            .into_iter()
+            .filter(|(name, _, _)| !expected.iter().any(|column| column.name == name))
+            .map(|(column, udt_name, _)| SchemaDrift::UnexpectedColumn { column, udt_name }),
+    );
+    Ok(SchemaReport {
+        table_name: table_name.into(),
+        drifts,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- type impls ---
+////////////////////////////////////////////////////////////////////////////////////
+impl SchemaReport {
+    /// True if no drift was found
+    ///
+    ///   * _return_ - True if consistent
+    #[inline]
+    pub fn is_consistent(&self) -> bool {
+        self.drifts.is_empty()
+    }
+}
+
+// α <mod-def schema>
+// ω <mod-def schema>
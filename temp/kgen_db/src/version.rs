@@ -0,0 +1,19 @@
+//! Outcomes of the optimistic concurrency functions of the table gateways
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- enums ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Outcome of an update guarded by the version column of the row
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionedUpdate<V> {
+    /// The row was at the expected version and is updated
+    Updated {
+        /// The new version of the row
+        version: V,
+    },
+    /// No row with the key is at the expected version - it was updated or deleted since read
+    Conflict,
+}
+
+// α <mod-def version>
+// ω <mod-def version>
@@ -6,6 +6,7 @@
 pub mod support;
 pub mod test_keyless;
 pub mod test_sample;
+pub mod test_sample_auto_id;
 pub mod test_sample_with_id;
 
 // α <mod-def db_tests>
@@ -3,14 +3,24 @@
 ////////////////////////////////////////////////////////////////////////////////////
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
+use chrono::DateTime;
 use chrono::Duration;
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::Utc;
 use deadpool_postgres::Config;
 use deadpool_postgres::ManagerConfig;
 use deadpool_postgres::Pool;
 use deadpool_postgres::RecyclingMethod;
 use deadpool_postgres::Runtime;
+use kgen_db::interval::Interval;
+use kgen_db::pg_enum::SampleColor;
+use kgen_db::unsigned::U64;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Number;
 use serde_json::Value;
 use std::ops::Add;
 use tokio_postgres::NoTls;
@@ -57,6 +67,18 @@ pub async fn initialize_db_pool() -> Pool {
     cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap()
 }
 
+/// Mutate a typed json payload as its `Value`, requiring no `MutateValue` impl of the payload type
+///
+///   * **payload** - The payload to mutate
+pub fn mutate_json<T>(payload: &mut T)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut value = serde_json::to_value(&*payload).unwrap();
+    value.mutate_value();
+    *payload = serde_json::from_value(value).unwrap();
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 // --- trait impls ---
 ////////////////////////////////////////////////////////////////////////////////////
@@ -130,31 +152,67 @@ impl MutateValue for Option<i16> {
     }
 }
 
+impl MutateValue for i8 {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self = self.add(1);
+    }
+}
+
+impl MutateValue for Option<i8> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        self.as_mut().map(|v| v.add(1));
+    }
+}
+
 impl MutateValue for String {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.push_str("*");
+        self.push('*');
     }
 }
 
 impl MutateValue for Option<String> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|v| v.push_str("*"));
+        if let Some(v) = self.as_mut() {
+            v.push('*')
+        }
     }
 }
 
 impl MutateValue for Value {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        // Need easy mutation
+        match self {
+            Value::Null => {}
+            Value::Bool(value) => *value = !*value,
+            Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    *number = value.wrapping_add(1).into();
+                } else if let Some(value) = number.as_u64() {
+                    *number = value.wrapping_add(1).into();
+                } else if let Some(value) = number
+                    .as_f64()
+                    .and_then(|value| Number::from_f64(value + 1.0))
+                {
+                    *number = value;
+                }
+            }
+            Value::String(value) => value.push('*'),
+            Value::Array(values) => values.iter_mut().for_each(|value| value.mutate_value()),
+            Value::Object(values) => values.values_mut().for_each(|value| value.mutate_value()),
+        }
     }
 }
 
 impl MutateValue for Option<Value> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        // Need easy way to mutate
+        if let Some(v) = self.as_mut() {
+            v.mutate_value()
+        }
     }
 }
 
@@ -168,7 +226,9 @@ impl MutateValue for char {
 impl MutateValue for Option<char> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|c| *c = (*c as u8 + 1) as char);
+        if let Some(c) = self.as_mut() {
+            *c = (*c as u8 + 1) as char
+        }
     }
 }
 
@@ -182,35 +242,143 @@ impl MutateValue for bool {
 impl MutateValue for Option<bool> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|v| *v = !*v);
+        if let Some(v) = self.as_mut() {
+            *v = !*v
+        }
     }
 }
 
 impl MutateValue for NaiveDate {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        *self = *self + Duration::days(1);
+        *self += Duration::days(1);
     }
 }
 
 impl MutateValue for Option<NaiveDate> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|v| *v = *v + Duration::days(1));
+        if let Some(v) = self.as_mut() {
+            *v += Duration::days(1)
+        }
     }
 }
 
 impl MutateValue for NaiveDateTime {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        *self = *self + Duration::days(1);
+        *self += Duration::days(1);
     }
 }
 
 impl MutateValue for Option<NaiveDateTime> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|v| *v = *v + Duration::days(1));
+        if let Some(v) = self.as_mut() {
+            *v += Duration::days(1)
+        }
+    }
+}
+
+impl MutateValue for f64 {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self += 1.0;
+    }
+}
+
+impl MutateValue for Option<f64> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            *v += 1.0
+        }
+    }
+}
+
+impl<T> MutateValue for Vec<T>
+where
+    T: Default,
+{
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        self.push(T::default());
+    }
+}
+
+impl<T> MutateValue for Option<Vec<T>>
+where
+    T: Default,
+{
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            v.push(T::default())
+        }
+    }
+}
+
+impl MutateValue for Decimal {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self += Decimal::ONE;
+    }
+}
+
+impl MutateValue for Option<Decimal> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            *v += Decimal::ONE
+        }
+    }
+}
+
+impl MutateValue for NaiveTime {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self += Duration::minutes(1);
+    }
+}
+
+impl MutateValue for Option<NaiveTime> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            *v += Duration::minutes(1)
+        }
+    }
+}
+
+impl MutateValue for DateTime<Utc> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self += Duration::days(1);
+    }
+}
+
+impl MutateValue for Option<DateTime<Utc>> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            *v += Duration::days(1)
+        }
+    }
+}
+
+impl MutateValue for Interval {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        self.0.days += 1;
+    }
+}
+
+impl MutateValue for Option<Interval> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            v.0.days += 1
+        }
     }
 }
 
@@ -226,7 +394,45 @@ impl MutateValue for Uuid {
 impl MutateValue for Option<Uuid> {
     /// Change the value in some deterministic way
     fn mutate_value(&mut self) {
-        self.as_mut().map(|u| u.mutate_value());
+        if let Some(u) = self.as_mut() {
+            u.mutate_value()
+        }
+    }
+}
+
+impl MutateValue for U64 {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        self.0 ^= 1;
+    }
+}
+
+impl MutateValue for Option<U64> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            v.0 ^= 1
+        }
+    }
+}
+
+impl MutateValue for SampleColor {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        *self = match self {
+            SampleColor::Red => SampleColor::Green,
+            SampleColor::Green => SampleColor::Blue,
+            SampleColor::Blue => SampleColor::Red,
+        };
+    }
+}
+
+impl MutateValue for Option<SampleColor> {
+    /// Change the value in some deterministic way
+    fn mutate_value(&mut self) {
+        if let Some(v) = self.as_mut() {
+            v.mutate_value()
+        }
     }
 }
 
@@ -5,7 +5,10 @@
 ////////////////////////////////////////////////////////////////////////////////////
 use super::support::get_pool;
 use super::support::MutateValue;
+use futures::TryStreamExt;
+use kgen_db::filter::Filter;
 use kgen_db::keyless::*;
+use kgen_db::unsigned::U64;
 use std::collections::BTreeSet;
 #[allow(unused_imports)]
 use std::ops::Deref;
@@ -16,6 +19,7 @@ use tokio_postgres::GenericClient;
 ////////////////////////////////////////////////////////////////////////////////////
 /// Mutate fields not appearing in pkey or unique keys for test purposes with pkey.
 /// Only fields not appearing in unique keys allows stability in the uniqueness of rows.
+/// Fields set by the server are left as is.
 ///
 ///   * **row_data** - Data to mutate
 pub fn mutate_row_data(row_data: &mut KeylessRowData) {
@@ -48,11 +52,11 @@ pub fn mutate_row_data(row_data: &mut KeylessRowData) {
 ///   * **client** - The pool connection
 ///   * **values** - Values to compare to selected
 ///   * **label** - Label for assert
-pub async fn select_and_compare_assert<T>(client: &T, values: &Vec<KeylessRowData>, label: &str)
+pub async fn select_and_compare_assert<T>(client: &T, values: &[KeylessRowData], label: &str)
 where
     T: GenericClient,
 {
-    let selected = TableKeyless::select_all(client).await;
+    let selected = TableKeyless::select_all(client).await.unwrap();
     assert_eq!(selected.len(), values.len());
     get_sample_rows_sorted(&selected)
         .iter()
@@ -67,17 +71,33 @@ where
                     format!("Mismatch\n{a:?}\n---\n{b:?}")
                 }
             );
-            assert_eq!(true, matched);
+            assert!(matched);
         });
 }
 
+/// Clear the fields set by the server, which the samples can not predict
+///
+///   * **row_data** - Data to clear
+#[inline]
+pub fn clear_server_managed(row_data: &mut KeylessRowData) {
+    row_data.created_at = Default::default();
+    row_data.updated_at = Default::default();
+    row_data.deleted_at = Default::default();
+}
+
 /// Get the sample rows as a set
 ///
 ///   * **rows** - The rows to stringify and sort
 ///   * _return_ - The samples as set
 #[inline]
 pub fn get_sample_rows_sorted(rows: &[KeylessRowData]) -> BTreeSet<String> {
-    rows.iter().cloned().map(|r| format!("{r:?}")).collect()
+    rows.iter()
+        .cloned()
+        .map(|mut r| {
+            clear_server_managed(&mut r);
+            format!("{r:?}")
+        })
+        .collect()
 }
 
 /// Get a set of sample rows for testing
@@ -89,7 +109,7 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             the_name: "a".into(),
             the_small_int: -32768,
             the_large_int: -2147483648,
-            the_big_int: -2147483648,
+            the_big_int: U64(9223372036854774807),
             the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483648,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -98,13 +118,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
-            the_ulong: -2147483648,
+            the_ulong: U64(9223372036854774807),
             the_json: "{ value: 1 }".into(),
             the_jsonb: "{ value: 1 }".into(),
-            nullable_name: "a".into(),
+            nullable_name: Some("a".into()),
             nullable_small_int: Some(-32768),
             nullable_large_int: Some(-2147483648),
-            nullable_big_int: Some(-2147483648),
+            nullable_big_int: Some(U64(9223372036854774807)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             ),
@@ -116,15 +136,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483648),
+            nullable_ulong: Some(U64(9223372036854774807)),
             nullable_json: Some("{ value: 1 }".into()),
-            nullable_jsonb: "{ value: 1 }".into(),
+            nullable_jsonb: Some("{ value: 1 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "b".into(),
             the_small_int: -32767,
             the_large_int: -2147483647,
-            the_big_int: -2147483647,
+            the_big_int: U64(9223372036854774808),
             the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483647,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -133,13 +161,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
-            the_ulong: -2147483647,
+            the_ulong: U64(9223372036854774808),
             the_json: "{ value: 2 }".into(),
             the_jsonb: "{ value: 2 }".into(),
-            nullable_name: "b".into(),
+            nullable_name: Some("b".into()),
             nullable_small_int: Some(-32767),
             nullable_large_int: Some(-2147483647),
-            nullable_big_int: Some(-2147483647),
+            nullable_big_int: Some(U64(9223372036854774808)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             ),
@@ -151,15 +179,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483647),
+            nullable_ulong: Some(U64(9223372036854774808)),
             nullable_json: Some("{ value: 2 }".into()),
-            nullable_jsonb: "{ value: 2 }".into(),
+            nullable_jsonb: Some("{ value: 2 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "c".into(),
             the_small_int: -32766,
             the_large_int: -2147483646,
-            the_big_int: -2147483646,
+            the_big_int: U64(9223372036854774809),
             the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483646,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -168,13 +204,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
-            the_ulong: -2147483646,
+            the_ulong: U64(9223372036854774809),
             the_json: "{ value: 3 }".into(),
             the_jsonb: "{ value: 3 }".into(),
-            nullable_name: "c".into(),
+            nullable_name: Some("c".into()),
             nullable_small_int: Some(-32766),
             nullable_large_int: Some(-2147483646),
-            nullable_big_int: Some(-2147483646),
+            nullable_big_int: Some(U64(9223372036854774809)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             ),
@@ -186,15 +222,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
             ),
-            nullable_ulong: Some(-2147483646),
+            nullable_ulong: Some(U64(9223372036854774809)),
             nullable_json: Some("{ value: 3 }".into()),
-            nullable_jsonb: "{ value: 3 }".into(),
+            nullable_jsonb: Some("{ value: 3 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "d".into(),
             the_small_int: -32765,
             the_large_int: -2147483645,
-            the_big_int: -2147483645,
+            the_big_int: U64(9223372036854774810),
             the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483645,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -203,13 +247,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
-            the_ulong: -2147483645,
+            the_ulong: U64(9223372036854774810),
             the_json: "{ value: 4 }".into(),
             the_jsonb: "{ value: 4 }".into(),
-            nullable_name: "d".into(),
+            nullable_name: Some("d".into()),
             nullable_small_int: Some(-32765),
             nullable_large_int: Some(-2147483645),
-            nullable_big_int: Some(-2147483645),
+            nullable_big_int: Some(U64(9223372036854774810)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             ),
@@ -221,15 +265,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
             ),
-            nullable_ulong: Some(-2147483645),
+            nullable_ulong: Some(U64(9223372036854774810)),
             nullable_json: Some("{ value: 4 }".into()),
-            nullable_jsonb: "{ value: 4 }".into(),
+            nullable_jsonb: Some("{ value: 4 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "e".into(),
             the_small_int: -32764,
             the_large_int: -2147483644,
-            the_big_int: -2147483644,
+            the_big_int: U64(9223372036854774811),
             the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483644,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -238,13 +290,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
-            the_ulong: -2147483644,
+            the_ulong: U64(9223372036854774811),
             the_json: "{ value: 5 }".into(),
             the_jsonb: "{ value: 5 }".into(),
-            nullable_name: "e".into(),
+            nullable_name: Some("e".into()),
             nullable_small_int: Some(-32764),
             nullable_large_int: Some(-2147483644),
-            nullable_big_int: Some(-2147483644),
+            nullable_big_int: Some(U64(9223372036854774811)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             ),
@@ -256,15 +308,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
             ),
-            nullable_ulong: Some(-2147483644),
+            nullable_ulong: Some(U64(9223372036854774811)),
             nullable_json: Some("{ value: 5 }".into()),
-            nullable_jsonb: "{ value: 5 }".into(),
+            nullable_jsonb: Some("{ value: 5 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "f".into(),
             the_small_int: -32763,
             the_large_int: -2147483643,
-            the_big_int: -2147483643,
+            the_big_int: U64(9223372036854774812),
             the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483643,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -273,13 +333,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
-            the_ulong: -2147483643,
+            the_ulong: U64(9223372036854774812),
             the_json: "{ value: 6 }".into(),
             the_jsonb: "{ value: 6 }".into(),
-            nullable_name: "f".into(),
+            nullable_name: Some("f".into()),
             nullable_small_int: Some(-32763),
             nullable_large_int: Some(-2147483643),
-            nullable_big_int: Some(-2147483643),
+            nullable_big_int: Some(U64(9223372036854774812)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             ),
@@ -291,15 +351,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483643),
+            nullable_ulong: Some(U64(9223372036854774812)),
             nullable_json: Some("{ value: 6 }".into()),
-            nullable_jsonb: "{ value: 6 }".into(),
+            nullable_jsonb: Some("{ value: 6 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "g".into(),
             the_small_int: -32762,
             the_large_int: -2147483642,
-            the_big_int: -2147483642,
+            the_big_int: U64(9223372036854774813),
             the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483642,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -308,13 +376,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
-            the_ulong: -2147483642,
+            the_ulong: U64(9223372036854774813),
             the_json: "{ value: 7 }".into(),
             the_jsonb: "{ value: 7 }".into(),
-            nullable_name: "g".into(),
+            nullable_name: Some("g".into()),
             nullable_small_int: Some(-32762),
             nullable_large_int: Some(-2147483642),
-            nullable_big_int: Some(-2147483642),
+            nullable_big_int: Some(U64(9223372036854774813)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             ),
@@ -326,15 +394,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483642),
+            nullable_ulong: Some(U64(9223372036854774813)),
             nullable_json: Some("{ value: 7 }".into()),
-            nullable_jsonb: "{ value: 7 }".into(),
+            nullable_jsonb: Some("{ value: 7 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "h".into(),
             the_small_int: -32761,
             the_large_int: -2147483641,
-            the_big_int: -2147483641,
+            the_big_int: U64(9223372036854774814),
             the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483641,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -343,13 +419,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
-            the_ulong: -2147483641,
+            the_ulong: U64(9223372036854774814),
             the_json: "{ value: 8 }".into(),
             the_jsonb: "{ value: 8 }".into(),
-            nullable_name: "h".into(),
+            nullable_name: Some("h".into()),
             nullable_small_int: Some(-32761),
             nullable_large_int: Some(-2147483641),
-            nullable_big_int: Some(-2147483641),
+            nullable_big_int: Some(U64(9223372036854774814)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             ),
@@ -361,15 +437,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
             ),
-            nullable_ulong: Some(-2147483641),
+            nullable_ulong: Some(U64(9223372036854774814)),
             nullable_json: Some("{ value: 8 }".into()),
-            nullable_jsonb: "{ value: 8 }".into(),
+            nullable_jsonb: Some("{ value: 8 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "i".into(),
             the_small_int: -32760,
             the_large_int: -2147483640,
-            the_big_int: -2147483640,
+            the_big_int: U64(9223372036854774815),
             the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483640,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -378,13 +462,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
-            the_ulong: -2147483640,
+            the_ulong: U64(9223372036854774815),
             the_json: "{ value: 9 }".into(),
             the_jsonb: "{ value: 9 }".into(),
-            nullable_name: "i".into(),
+            nullable_name: Some("i".into()),
             nullable_small_int: Some(-32760),
             nullable_large_int: Some(-2147483640),
-            nullable_big_int: Some(-2147483640),
+            nullable_big_int: Some(U64(9223372036854774815)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             ),
@@ -396,15 +480,23 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
             ),
-            nullable_ulong: Some(-2147483640),
+            nullable_ulong: Some(U64(9223372036854774815)),
             nullable_json: Some("{ value: 9 }".into()),
-            nullable_jsonb: "{ value: 9 }".into(),
+            nullable_jsonb: Some("{ value: 9 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
         KeylessRowData {
             the_name: "j".into(),
             the_small_int: -32759,
             the_large_int: -2147483639,
-            the_big_int: -2147483639,
+            the_big_int: U64(9223372036854774816),
             the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483639,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -413,13 +505,13 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
-            the_ulong: -2147483639,
+            the_ulong: U64(9223372036854774816),
             the_json: "{ value: 10 }".into(),
             the_jsonb: "{ value: 10 }".into(),
-            nullable_name: "j".into(),
+            nullable_name: Some("j".into()),
             nullable_small_int: Some(-32759),
             nullable_large_int: Some(-2147483639),
-            nullable_big_int: Some(-2147483639),
+            nullable_big_int: Some(U64(9223372036854774816)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             ),
@@ -431,9 +523,17 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
             ),
-            nullable_ulong: Some(-2147483639),
+            nullable_ulong: Some(U64(9223372036854774816)),
             nullable_json: Some("{ value: 10 }".into()),
-            nullable_jsonb: "{ value: 10 }".into(),
+            nullable_jsonb: Some("{ value: 10 }".into()),
+            created_at: chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            updated_at: chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                .unwrap(),
+            deleted_at: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap(),
+            ),
         },
     ]
 }
@@ -445,6 +545,12 @@ pub fn get_sample_rows() -> Vec<KeylessRowData> {
 async fn test_crud() {
     let resource = get_pool().await.get().await.unwrap();
     let client = resource.client();
+    // Provision the schema from the generated DDL. The table is shared, so it is only dropped and
+    // recreated when `KGEN_DB_RECREATE_TABLES` opts in, otherwise created if missing.
+    if std::env::var("KGEN_DB_RECREATE_TABLES").is_ok() {
+        TableKeyless::drop_table(client).await.unwrap();
+    }
+    TableKeyless::create_table(client).await.unwrap();
     // First delete all, assuming it worked
     let deleted = TableKeyless::delete_all(client).await.unwrap();
     tracing::info!("Initialize phase deleted {deleted}");
@@ -453,7 +559,7 @@ async fn test_crud() {
       Validate that delete work by selecting back an empty set
     */
     {
-        assert_eq!(0, TableKeyless::select_all(client).await.len());
+        assert_eq!(0, TableKeyless::select_all(client).await.unwrap().len());
     }
     let samples = get_sample_rows();
 
@@ -469,35 +575,243 @@ async fn test_crud() {
           Select back out the inserted data and compare to samples
         */
         {
-            select_and_compare_assert(
-                client,
-                &get_sample_rows().iter().cloned().collect(),
-                "Basic Ins Cmp",
-            )
-            .await;
+            select_and_compare_assert(client, &get_sample_rows(), "Basic Ins Cmp").await;
         }
         let deleted = TableKeyless::delete_all(client).await.unwrap();
         tracing::info!("Basic insert phase deleted {deleted}");
         assert_eq!(samples.len(), deleted as usize);
     }
 
+    /*
+      Test NULL round trips for every nullable column
+    */
+    {
+        let null_samples = get_sample_rows()
+            .into_iter()
+            .map(|mut row| {
+                row.nullable_name = None;
+                row.nullable_small_int = None;
+                row.nullable_large_int = None;
+                row.nullable_big_int = None;
+                row.nullable_date = None;
+                row.nullable_general_int = None;
+                row.nullable_date_time = None;
+                row.nullable_uuid = None;
+                row.nullable_ulong = None;
+                row.nullable_json = None;
+                row.nullable_jsonb = None;
+                row.deleted_at = None;
+                row
+            })
+            .collect::<Vec<_>>();
+        TableKeyless::basic_insert(client, &null_samples)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Round Trip Cmp").await;
+        let deleted = TableKeyless::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+        /*
+          Same through `UNNEST` of bulk insert, which passes arrays as text literals
+        */
+        TableKeyless::bulk_insert(client, &null_samples, 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Bulk Round Trip Cmp").await;
+        let deleted = TableKeyless::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+    }
+
     /*
       Test the bulk insert functionality
     */
     {
-        let inserted = TableKeyless::bulk_insert(client, &samples, 4)
+        TableKeyless::bulk_insert(client, &samples, 4)
             .await
             .unwrap();
-        tracing::debug!("Inserted with `bulk_insert` -> {inserted:?}");
         /*
           Select back out the inserted data and compare to samples
         */
-        select_and_compare_assert(
+        select_and_compare_assert(client, &get_sample_rows(), "Blk Ins Cmp").await;
+    }
+
+    /*
+      Test soft delete hides rows from the selects until hard deleted
+    */
+    {
+        let live = TableKeyless::select_all(client).await.unwrap().len();
+        assert_eq!(
+            live,
+            TableKeyless::delete_all(client).await.unwrap() as usize
+        );
+        assert_eq!(0, TableKeyless::select_all(client).await.unwrap().len());
+        let including_deleted =
+            TableKeyless::select_all_where_including_deleted(client, "1=1", &[])
+                .await
+                .unwrap();
+        assert!(including_deleted.len() >= samples.len());
+        assert!(including_deleted.iter().all(|row| row.deleted_at.is_some()));
+        assert_eq!(
+            including_deleted.len() as u64,
+            TableKeyless::hard_delete_all(client).await.unwrap()
+        );
+        assert!(
+            TableKeyless::select_all_where_including_deleted(client, "1=1", &[])
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        TableKeyless::bulk_insert(client, &samples, 4)
+            .await
+            .unwrap();
+    }
+
+    /*
+      Test the functions running the prepared statements
+    */
+    {
+        let deleted = TableKeyless::delete_all_prepared(&resource).await.unwrap();
+        assert_eq!(samples.len(), deleted as usize);
+        TableKeyless::bulk_insert_prepared(&resource, &samples, 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Ins Cmp").await;
+    }
+
+    /*
+      Test copy in reloads the bulk inserted data and copy out matches select
+    */
+    {
+        let bulk_inserted = TableKeyless::select_all(client).await.unwrap();
+        let deleted = TableKeyless::delete_all(client).await.unwrap();
+        assert_eq!(bulk_inserted.len(), deleted as usize);
+        let copy_in_rows = bulk_inserted
+            .iter()
+            .cloned()
+            .map(|mut row| {
+                clear_server_managed(&mut row);
+                row
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bulk_inserted.len() as u64,
+            TableKeyless::copy_in(client, &copy_in_rows).await.unwrap()
+        );
+        select_and_compare_assert(client, &bulk_inserted, "Copy In Cmp").await;
+        for row in TableKeyless::select_all(client).await.unwrap() {
+            assert_ne!(<chrono::NaiveDateTime>::default(), row.created_at);
+            assert_ne!(<chrono::NaiveDateTime>::default(), row.updated_at);
+        }
+
+        let selected = TableKeyless::select_all(client).await.unwrap();
+        let copied_out = TableKeyless::copy_out_where(client, "1=1").await.unwrap();
+        assert_eq!(
+            selected
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>(),
+            copied_out
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>()
+        );
+    }
+
+    /*
+      Test the streaming selects
+    */
+    {
+        let selected_count = TableKeyless::count_where(client, "1=1", &[]).await.unwrap() as usize;
+        let streamed = TableKeyless::select_stream_where(client, "1=1", &[])
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(selected_count, streamed.len());
+
+        let mut chunk_resource = get_pool().await.get().await.unwrap();
+        let transaction = chunk_resource.transaction().await.unwrap();
+        let chunks = TableKeyless::select_chunks_where(&transaction, "1=1", &[], 3)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 3));
+        assert_eq!(
+            selected_count,
+            chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+        );
+        transaction.commit().await.unwrap();
+    }
+
+    /*
+      Test the aggregates
+    */
+    {
+        assert_eq!(
+            samples.len() as i64,
+            TableKeyless::count_where(client, "1=1", &[]).await.unwrap()
+        );
+        assert_eq!(
+            0,
+            TableKeyless::count_where(client, "1=0", &[]).await.unwrap()
+        );
+        assert!(TableKeyless::exists_where(client, "1=1", &[])
+            .await
+            .unwrap());
+        assert!(!TableKeyless::exists_where(client, "1=0", &[])
+            .await
+            .unwrap());
+        let min = TableKeyless::min_the_name(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        let max = TableKeyless::max_the_name(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(min <= max);
+        assert!(
+            TableKeyless::count_where(client, r#"the_name = $1"#, &[&min])
+                .await
+                .unwrap()
+                >= 1
+        );
+        assert_eq!(
+            None,
+            TableKeyless::max_the_name(client, "1=0", &[])
+                .await
+                .unwrap()
+        );
+    }
+
+    /*
+      Test the typed filter
+    */
+    {
+        let value = samples[0].the_name.clone();
+        let expected = samples.iter().filter(|row| row.the_name == value).count();
+        let matched =
+            TableKeyless::select_all_filtered(client, &Filter::eq(KeylessColumn::TheName, value))
+                .await
+                .unwrap();
+        assert_eq!(expected, matched.len());
+        let unmatched = TableKeyless::select_all_filtered(
             client,
-            &get_sample_rows().iter().cloned().collect(),
-            "Blk Ins Cmp",
+            &Filter::ne(KeylessColumn::TheName, samples[0].the_name.clone())
+                .or(Filter::is_null(KeylessColumn::TheName)),
         )
-        .await;
+        .await
+        .unwrap();
+        assert_eq!(samples.len(), matched.len() + unmatched.len());
+        assert_eq!(
+            samples.len(),
+            TableKeyless::select_all_filtered(client, &Filter::And(vec![]))
+                .await
+                .unwrap()
+                .len()
+        );
     }
 
     /*
@@ -507,10 +821,27 @@ async fn test_crud() {
         let deleted = TableKeyless::delete_all(client).await.unwrap();
         tracing::info!("Deleted all {deleted} TableKeyless entries");
         assert_eq!(deleted as usize, samples.len());
-        let selected = TableKeyless::select_all(client).await;
+        let selected = TableKeyless::select_all(client).await.unwrap();
         assert_eq!(0, selected.len());
     }
 }
 
+/// Test the schema provisioned from the generated DDL matches that assumed by keyless
+#[tracing_test::traced_test]
+#[serial_test::serial]
+#[tokio::test]
+async fn test_verify_schema() {
+    let resource = get_pool().await.get().await.unwrap();
+    let client = resource.client();
+    TableKeyless::create_table(client).await.unwrap();
+    let report = TableKeyless::verify_schema(client).await.unwrap();
+    assert!(
+        report.is_consistent(),
+        "Schema drift in `{}`: {:?}",
+        report.table_name,
+        report.drifts
+    );
+}
+
 // α <mod-def test_keyless>
 // ω <mod-def test_keyless>
@@ -4,8 +4,15 @@
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
 use super::support::get_pool;
+use super::support::mutate_json;
 use super::support::MutateValue;
+use futures::TryStreamExt;
+use kgen_db::batch::BatchMode;
+use kgen_db::batch::SyncSummary;
+use kgen_db::filter::Filter;
+use kgen_db::interval::Interval;
 use kgen_db::sample::*;
+use kgen_db::unsigned::U64;
 use std::collections::BTreeSet;
 #[allow(unused_imports)]
 use std::ops::Deref;
@@ -16,6 +23,7 @@ use tokio_postgres::GenericClient;
 ////////////////////////////////////////////////////////////////////////////////////
 /// Mutate fields not appearing in pkey or unique keys for test purposes with pkey.
 /// Only fields not appearing in unique keys allows stability in the uniqueness of rows.
+/// Fields set by the server are left as is.
 ///
 ///   * **row_data** - Data to mutate
 pub fn mutate_row_data(row_data: &mut SampleRowData) {
@@ -26,8 +34,18 @@ pub fn mutate_row_data(row_data: &mut SampleRowData) {
     row_data.the_date_time.mutate_value();
     row_data.the_uuid.mutate_value();
     row_data.the_ulong.mutate_value();
-    row_data.the_json.mutate_value();
+    mutate_json(&mut row_data.the_json);
     row_data.the_jsonb.mutate_value();
+    row_data.the_double.mutate_value();
+    row_data.the_bytes.mutate_value();
+    row_data.the_numeric.mutate_value();
+    row_data.the_time.mutate_value();
+    row_data.the_timestamp.mutate_value();
+    row_data.the_interval.mutate_value();
+    row_data.the_color.mutate_value();
+    row_data.the_tags.mutate_value();
+    row_data.the_scores.mutate_value();
+    row_data.the_uuids.mutate_value();
     row_data.nullable_name.mutate_value();
     row_data.nullable_small_int.mutate_value();
     row_data.nullable_large_int.mutate_value();
@@ -38,7 +56,18 @@ pub fn mutate_row_data(row_data: &mut SampleRowData) {
     row_data.nullable_uuid.mutate_value();
     row_data.nullable_ulong.mutate_value();
     row_data.nullable_json.mutate_value();
-    row_data.nullable_jsonb.mutate_value();
+    if let Some(payload) = row_data.nullable_jsonb.as_mut() {
+        mutate_json(payload);
+    }
+    row_data.nullable_double.mutate_value();
+    row_data.nullable_bytes.mutate_value();
+    row_data.nullable_numeric.mutate_value();
+    row_data.nullable_time.mutate_value();
+    row_data.nullable_timestamp.mutate_value();
+    row_data.nullable_interval.mutate_value();
+    row_data.nullable_color.mutate_value();
+    row_data.nullable_tags.mutate_value();
+    row_data.nullable_scores.mutate_value();
 }
 
 /// Select all from the database and assert they compare to [values]
@@ -46,11 +75,11 @@ pub fn mutate_row_data(row_data: &mut SampleRowData) {
 ///   * **client** - The pool connection
 ///   * **values** - Values to compare to selected
 ///   * **label** - Label for assert
-pub async fn select_and_compare_assert<T>(client: &T, values: &Vec<SampleRowData>, label: &str)
+pub async fn select_and_compare_assert<T>(client: &T, values: &[SampleRowData], label: &str)
 where
     T: GenericClient,
 {
-    let selected = TableSample::select_all(client).await;
+    let selected = TableSample::select_all(client).await.unwrap();
     assert_eq!(selected.len(), values.len());
     get_sample_rows_sorted(&selected)
         .iter()
@@ -65,7 +94,7 @@ where
                     format!("Mismatch\n{a:?}\n---\n{b:?}")
                 }
             );
-            assert_eq!(true, matched);
+            assert!(matched);
         });
 }
 
@@ -75,7 +104,7 @@ where
 ///   * _return_ - The samples as set
 #[inline]
 pub fn get_sample_rows_sorted(rows: &[SampleRowData]) -> BTreeSet<String> {
-    rows.iter().cloned().map(|r| format!("{r:?}")).collect()
+    rows.iter().map(|r| format!("{r:?}")).collect()
 }
 
 /// Get a set of sample rows for testing
@@ -87,7 +116,7 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             the_name: "a".into(),
             the_small_int: -32768,
             the_large_int: -2147483648,
-            the_big_int: -2147483648,
+            the_big_int: U64(9223372036854774807),
             the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483648,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -96,13 +125,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
-            the_ulong: -2147483648,
-            the_json: "{ value: 1 }".into(),
+            the_ulong: U64(9223372036854774807),
+            the_json: Default::default(),
             the_jsonb: "{ value: 1 }".into(),
-            nullable_name: "a".into(),
+            the_double: 0.0,
+            the_bytes: vec![
+                26, 204, 239, 57, 64, 32, 191, 103, 202, 63, 171, 231, 220, 49, 71, 249,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("1.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("01:01", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-01-01T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 1, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("a".into()),
             nullable_small_int: Some(-32768),
             nullable_large_int: Some(-2147483648),
-            nullable_big_int: Some(-2147483648),
+            nullable_big_int: Some(U64(9223372036854774807)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             ),
@@ -114,15 +160,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483648),
+            nullable_ulong: Some(U64(9223372036854774807)),
             nullable_json: Some("{ value: 1 }".into()),
-            nullable_jsonb: "{ value: 1 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(0.0),
+            nullable_bytes: Some(vec![
+                26, 204, 239, 57, 64, 32, 191, 103, 202, 63, 171, 231, 220, 49, 71, 249,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("1.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("01:01", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-01-01T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 1, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
         },
         SampleRowData {
             the_name: "b".into(),
             the_small_int: -32767,
             the_large_int: -2147483647,
-            the_big_int: -2147483647,
+            the_big_int: U64(9223372036854774808),
             the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483647,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -131,13 +192,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
-            the_ulong: -2147483647,
-            the_json: "{ value: 2 }".into(),
+            the_ulong: U64(9223372036854774808),
+            the_json: Default::default(),
             the_jsonb: "{ value: 2 }".into(),
-            nullable_name: "b".into(),
+            the_double: 1.0,
+            the_bytes: vec![
+                32, 170, 189, 38, 66, 9, 114, 248, 216, 12, 72, 250, 123, 169, 0, 47,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("2.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("02:02", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-02-03T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 2, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["a".into()],
+            the_scores: vec![-2147483648],
+            the_uuids: vec![uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap()],
+            nullable_name: Some("b".into()),
             nullable_small_int: Some(-32767),
             nullable_large_int: Some(-2147483647),
-            nullable_big_int: Some(-2147483647),
+            nullable_big_int: Some(U64(9223372036854774808)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             ),
@@ -149,15 +227,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483647),
+            nullable_ulong: Some(U64(9223372036854774808)),
             nullable_json: Some("{ value: 2 }".into()),
-            nullable_jsonb: "{ value: 2 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(1.0),
+            nullable_bytes: Some(vec![
+                32, 170, 189, 38, 66, 9, 114, 248, 216, 12, 72, 250, 123, 169, 0, 47,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("2.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("02:02", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-02-03T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 2, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["a".into()]),
+            nullable_scores: Some(vec![-2147483648]),
         },
         SampleRowData {
             the_name: "c".into(),
             the_small_int: -32766,
             the_large_int: -2147483646,
-            the_big_int: -2147483646,
+            the_big_int: U64(9223372036854774809),
             the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483646,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -166,13 +259,33 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
-            the_ulong: -2147483646,
-            the_json: "{ value: 3 }".into(),
+            the_ulong: U64(9223372036854774809),
+            the_json: Default::default(),
             the_jsonb: "{ value: 3 }".into(),
-            nullable_name: "c".into(),
+            the_double: 2.0,
+            the_bytes: vec![
+                25, 2, 182, 151, 198, 19, 45, 172, 223, 191, 90, 75, 31, 183, 157, 186,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("3.7500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("03:03", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-03-05T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 3, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["b".into(), "c".into()],
+            the_scores: vec![-2147483647, -2147483646],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
+                uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
+            ],
+            nullable_name: Some("c".into()),
             nullable_small_int: Some(-32766),
             nullable_large_int: Some(-2147483646),
-            nullable_big_int: Some(-2147483646),
+            nullable_big_int: Some(U64(9223372036854774809)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             ),
@@ -184,15 +297,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
             ),
-            nullable_ulong: Some(-2147483646),
+            nullable_ulong: Some(U64(9223372036854774809)),
             nullable_json: Some("{ value: 3 }".into()),
-            nullable_jsonb: "{ value: 3 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(2.0),
+            nullable_bytes: Some(vec![
+                25, 2, 182, 151, 198, 19, 45, 172, 223, 191, 90, 75, 31, 183, 157, 186,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("3.7500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("03:03", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-03-05T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 3, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["b".into(), "c".into()]),
+            nullable_scores: Some(vec![-2147483647, -2147483646]),
         },
         SampleRowData {
             the_name: "d".into(),
             the_small_int: -32765,
             the_large_int: -2147483645,
-            the_big_int: -2147483645,
+            the_big_int: U64(9223372036854774810),
             the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483645,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -201,13 +329,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
-            the_ulong: -2147483645,
-            the_json: "{ value: 4 }".into(),
+            the_ulong: U64(9223372036854774810),
+            the_json: Default::default(),
             the_jsonb: "{ value: 4 }".into(),
-            nullable_name: "d".into(),
+            the_double: 3.0,
+            the_bytes: vec![
+                116, 59, 90, 241, 225, 242, 211, 151, 97, 36, 9, 29, 181, 25, 226, 21,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("5.0000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("04:04", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-04-07T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 4, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("d".into()),
             nullable_small_int: Some(-32765),
             nullable_large_int: Some(-2147483645),
-            nullable_big_int: Some(-2147483645),
+            nullable_big_int: Some(U64(9223372036854774810)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             ),
@@ -219,15 +364,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
             ),
-            nullable_ulong: Some(-2147483645),
+            nullable_ulong: Some(U64(9223372036854774810)),
             nullable_json: Some("{ value: 4 }".into()),
-            nullable_jsonb: "{ value: 4 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(3.0),
+            nullable_bytes: Some(vec![
+                116, 59, 90, 241, 225, 242, 211, 151, 97, 36, 9, 29, 181, 25, 226, 21,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("5.0000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("04:04", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-04-07T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 4, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
         },
         SampleRowData {
             the_name: "e".into(),
             the_small_int: -32764,
             the_large_int: -2147483644,
-            the_big_int: -2147483644,
+            the_big_int: U64(9223372036854774811),
             the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483644,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -236,13 +396,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
-            the_ulong: -2147483644,
-            the_json: "{ value: 5 }".into(),
+            the_ulong: U64(9223372036854774811),
+            the_json: Default::default(),
             the_jsonb: "{ value: 5 }".into(),
-            nullable_name: "e".into(),
+            the_double: 4.0,
+            the_bytes: vec![
+                50, 102, 169, 162, 150, 248, 28, 123, 69, 255, 222, 205, 15, 114, 1, 73,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("6.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("05:05", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-05-09T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 5, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["d".into()],
+            the_scores: vec![-2147483645],
+            the_uuids: vec![uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap()],
+            nullable_name: Some("e".into()),
             nullable_small_int: Some(-32764),
             nullable_large_int: Some(-2147483644),
-            nullable_big_int: Some(-2147483644),
+            nullable_big_int: Some(U64(9223372036854774811)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             ),
@@ -254,15 +431,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
             ),
-            nullable_ulong: Some(-2147483644),
+            nullable_ulong: Some(U64(9223372036854774811)),
             nullable_json: Some("{ value: 5 }".into()),
-            nullable_jsonb: "{ value: 5 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(4.0),
+            nullable_bytes: Some(vec![
+                50, 102, 169, 162, 150, 248, 28, 123, 69, 255, 222, 205, 15, 114, 1, 73,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("6.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("05:05", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-05-09T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 5, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["d".into()]),
+            nullable_scores: Some(vec![-2147483645]),
         },
         SampleRowData {
             the_name: "f".into(),
             the_small_int: -32763,
             the_large_int: -2147483643,
-            the_big_int: -2147483643,
+            the_big_int: U64(9223372036854774812),
             the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483643,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -271,13 +463,33 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
-            the_ulong: -2147483643,
-            the_json: "{ value: 6 }".into(),
+            the_ulong: U64(9223372036854774812),
+            the_json: Default::default(),
             the_jsonb: "{ value: 6 }".into(),
-            nullable_name: "f".into(),
+            the_double: 5.0,
+            the_bytes: vec![
+                95, 35, 236, 123, 98, 85, 76, 81, 45, 194, 17, 179, 200, 13, 56, 93,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("7.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("06:06", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-06-11T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 6, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["e".into(), "f".into()],
+            the_scores: vec![-2147483644, -2147483643],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
+                uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
+            ],
+            nullable_name: Some("f".into()),
             nullable_small_int: Some(-32763),
             nullable_large_int: Some(-2147483643),
-            nullable_big_int: Some(-2147483643),
+            nullable_big_int: Some(U64(9223372036854774812)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             ),
@@ -289,15 +501,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483643),
+            nullable_ulong: Some(U64(9223372036854774812)),
             nullable_json: Some("{ value: 6 }".into()),
-            nullable_jsonb: "{ value: 6 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(5.0),
+            nullable_bytes: Some(vec![
+                95, 35, 236, 123, 98, 85, 76, 81, 45, 194, 17, 179, 200, 13, 56, 93,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("7.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("06:06", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-06-11T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 6, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["e".into(), "f".into()]),
+            nullable_scores: Some(vec![-2147483644, -2147483643]),
         },
         SampleRowData {
             the_name: "g".into(),
             the_small_int: -32762,
             the_large_int: -2147483642,
-            the_big_int: -2147483642,
+            the_big_int: U64(9223372036854774813),
             the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483642,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -306,13 +533,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
-            the_ulong: -2147483642,
-            the_json: "{ value: 7 }".into(),
+            the_ulong: U64(9223372036854774813),
+            the_json: Default::default(),
             the_jsonb: "{ value: 7 }".into(),
-            nullable_name: "g".into(),
+            the_double: 6.0,
+            the_bytes: vec![
+                80, 180, 149, 113, 18, 42, 146, 116, 126, 24, 126, 244, 177, 140, 66, 4,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("8.7500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("07:07", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-07-13T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 7, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("g".into()),
             nullable_small_int: Some(-32762),
             nullable_large_int: Some(-2147483642),
-            nullable_big_int: Some(-2147483642),
+            nullable_big_int: Some(U64(9223372036854774813)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             ),
@@ -324,15 +568,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483642),
+            nullable_ulong: Some(U64(9223372036854774813)),
             nullable_json: Some("{ value: 7 }".into()),
-            nullable_jsonb: "{ value: 7 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(6.0),
+            nullable_bytes: Some(vec![
+                80, 180, 149, 113, 18, 42, 146, 116, 126, 24, 126, 244, 177, 140, 66, 4,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("8.7500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("07:07", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-07-13T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 7, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
         },
         SampleRowData {
             the_name: "h".into(),
             the_small_int: -32761,
             the_large_int: -2147483641,
-            the_big_int: -2147483641,
+            the_big_int: U64(9223372036854774814),
             the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483641,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -341,13 +600,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
-            the_ulong: -2147483641,
-            the_json: "{ value: 8 }".into(),
+            the_ulong: U64(9223372036854774814),
+            the_json: Default::default(),
             the_jsonb: "{ value: 8 }".into(),
-            nullable_name: "h".into(),
+            the_double: 7.0,
+            the_bytes: vec![
+                48, 49, 97, 232, 119, 250, 155, 56, 109, 90, 219, 5, 232, 125, 108, 64,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("10.0000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("08:08", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-08-15T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 8, 0)),
+            the_color: SampleColor::Green,
+            the_tags: vec!["g".into()],
+            the_scores: vec![-2147483642],
+            the_uuids: vec![uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap()],
+            nullable_name: Some("h".into()),
             nullable_small_int: Some(-32761),
             nullable_large_int: Some(-2147483641),
-            nullable_big_int: Some(-2147483641),
+            nullable_big_int: Some(U64(9223372036854774814)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             ),
@@ -359,15 +635,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
             ),
-            nullable_ulong: Some(-2147483641),
+            nullable_ulong: Some(U64(9223372036854774814)),
             nullable_json: Some("{ value: 8 }".into()),
-            nullable_jsonb: "{ value: 8 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(7.0),
+            nullable_bytes: Some(vec![
+                48, 49, 97, 232, 119, 250, 155, 56, 109, 90, 219, 5, 232, 125, 108, 64,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("10.0000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("08:08", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-08-15T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 8, 0))),
+            nullable_color: Some(SampleColor::Green),
+            nullable_tags: Some(vec!["g".into()]),
+            nullable_scores: Some(vec![-2147483642]),
         },
         SampleRowData {
             the_name: "i".into(),
             the_small_int: -32760,
             the_large_int: -2147483640,
-            the_big_int: -2147483640,
+            the_big_int: U64(9223372036854774815),
             the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483640,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -376,13 +667,33 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
-            the_ulong: -2147483640,
-            the_json: "{ value: 9 }".into(),
+            the_ulong: U64(9223372036854774815),
+            the_json: Default::default(),
             the_jsonb: "{ value: 9 }".into(),
-            nullable_name: "i".into(),
+            the_double: 8.0,
+            the_bytes: vec![
+                56, 90, 204, 130, 58, 22, 58, 199, 235, 131, 29, 146, 156, 11, 53, 36,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("11.2500").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("09:09", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-09-17T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 9, 0)),
+            the_color: SampleColor::Blue,
+            the_tags: vec!["h".into(), "i".into()],
+            the_scores: vec![-2147483641, -2147483640],
+            the_uuids: vec![
+                uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
+                uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
+            ],
+            nullable_name: Some("i".into()),
             nullable_small_int: Some(-32760),
             nullable_large_int: Some(-2147483640),
-            nullable_big_int: Some(-2147483640),
+            nullable_big_int: Some(U64(9223372036854774815)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             ),
@@ -394,15 +705,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
             ),
-            nullable_ulong: Some(-2147483640),
+            nullable_ulong: Some(U64(9223372036854774815)),
             nullable_json: Some("{ value: 9 }".into()),
-            nullable_jsonb: "{ value: 9 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(8.0),
+            nullable_bytes: Some(vec![
+                56, 90, 204, 130, 58, 22, 58, 199, 235, 131, 29, 146, 156, 11, 53, 36,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("11.2500").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("09:09", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-09-17T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 9, 0))),
+            nullable_color: Some(SampleColor::Blue),
+            nullable_tags: Some(vec!["h".into(), "i".into()]),
+            nullable_scores: Some(vec![-2147483641, -2147483640]),
         },
         SampleRowData {
             the_name: "j".into(),
             the_small_int: -32759,
             the_large_int: -2147483639,
-            the_big_int: -2147483639,
+            the_big_int: U64(9223372036854774816),
             the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483639,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -411,13 +737,30 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
-            the_ulong: -2147483639,
-            the_json: "{ value: 10 }".into(),
+            the_ulong: U64(9223372036854774816),
+            the_json: Default::default(),
             the_jsonb: "{ value: 10 }".into(),
-            nullable_name: "j".into(),
+            the_double: 9.0,
+            the_bytes: vec![
+                254, 130, 37, 250, 73, 83, 88, 152, 29, 252, 27, 65, 210, 245, 25, 177,
+            ],
+            the_numeric: rust_decimal::Decimal::from_str_exact("12.5000").unwrap(),
+            the_time: chrono::NaiveTime::parse_from_str("10:10", "%H:%M").unwrap(),
+            the_timestamp: chrono::NaiveDateTime::parse_from_str(
+                "2000-10-19T01:01",
+                "%Y-%m-%dT%H:%M",
+            )
+            .unwrap()
+            .and_utc(),
+            the_interval: Interval(pg_interval::Interval::new(0, 10, 0)),
+            the_color: SampleColor::Red,
+            the_tags: vec![],
+            the_scores: vec![],
+            the_uuids: vec![],
+            nullable_name: Some("j".into()),
             nullable_small_int: Some(-32759),
             nullable_large_int: Some(-2147483639),
-            nullable_big_int: Some(-2147483639),
+            nullable_big_int: Some(U64(9223372036854774816)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             ),
@@ -429,9 +772,24 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
             ),
-            nullable_ulong: Some(-2147483639),
+            nullable_ulong: Some(U64(9223372036854774816)),
             nullable_json: Some("{ value: 10 }".into()),
-            nullable_jsonb: "{ value: 10 }".into(),
+            nullable_jsonb: Some(Default::default()),
+            nullable_double: Some(9.0),
+            nullable_bytes: Some(vec![
+                254, 130, 37, 250, 73, 83, 88, 152, 29, 252, 27, 65, 210, 245, 25, 177,
+            ]),
+            nullable_numeric: Some(rust_decimal::Decimal::from_str_exact("12.5000").unwrap()),
+            nullable_time: Some(chrono::NaiveTime::parse_from_str("10:10", "%H:%M").unwrap()),
+            nullable_timestamp: Some(
+                chrono::NaiveDateTime::parse_from_str("2000-10-19T01:01", "%Y-%m-%dT%H:%M")
+                    .unwrap()
+                    .and_utc(),
+            ),
+            nullable_interval: Some(Interval(pg_interval::Interval::new(0, 10, 0))),
+            nullable_color: Some(SampleColor::Red),
+            nullable_tags: Some(vec![]),
+            nullable_scores: Some(vec![]),
         },
     ]
 }
@@ -443,6 +801,12 @@ pub fn get_sample_rows() -> Vec<SampleRowData> {
 async fn test_crud() {
     let resource = get_pool().await.get().await.unwrap();
     let client = resource.client();
+    // Provision the schema from the generated DDL. The table is shared, so it is only dropped and
+    // recreated when `KGEN_DB_RECREATE_TABLES` opts in, otherwise created if missing.
+    if std::env::var("KGEN_DB_RECREATE_TABLES").is_ok() {
+        TableSample::drop_table(client).await.unwrap();
+    }
+    TableSample::create_table(client).await.unwrap();
     // First delete all, assuming it worked
     let deleted = TableSample::delete_all(client).await.unwrap();
     tracing::info!("Initialize phase deleted {deleted}");
@@ -451,7 +815,7 @@ async fn test_crud() {
       Validate that delete work by selecting back an empty set
     */
     {
-        assert_eq!(0, TableSample::select_all(client).await.len());
+        assert_eq!(0, TableSample::select_all(client).await.unwrap().len());
     }
     let mut samples = get_sample_rows();
 
@@ -467,45 +831,471 @@ async fn test_crud() {
           Select back out the inserted data and compare to samples
         */
         {
-            select_and_compare_assert(
-                client,
-                &get_sample_rows().iter().cloned().collect(),
-                "Basic Ins Cmp",
-            )
-            .await;
+            select_and_compare_assert(client, &get_sample_rows(), "Basic Ins Cmp").await;
         }
         let deleted = TableSample::delete_all(client).await.unwrap();
         tracing::info!("Basic insert phase deleted {deleted}");
         assert_eq!(samples.len(), deleted as usize);
     }
 
+    /*
+      Test NULL round trips for every nullable column
+    */
+    {
+        let null_samples = get_sample_rows()
+            .into_iter()
+            .map(|mut row| {
+                row.nullable_name = None;
+                row.nullable_small_int = None;
+                row.nullable_large_int = None;
+                row.nullable_big_int = None;
+                row.nullable_date = None;
+                row.nullable_general_int = None;
+                row.nullable_date_time = None;
+                row.nullable_uuid = None;
+                row.nullable_ulong = None;
+                row.nullable_json = None;
+                row.nullable_jsonb = None;
+                row.nullable_double = None;
+                row.nullable_bytes = None;
+                row.nullable_numeric = None;
+                row.nullable_time = None;
+                row.nullable_timestamp = None;
+                row.nullable_interval = None;
+                row.nullable_color = None;
+                row.nullable_tags = None;
+                row.nullable_scores = None;
+                row
+            })
+            .collect::<Vec<_>>();
+        TableSample::basic_insert(client, &null_samples)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Round Trip Cmp").await;
+        let deleted = TableSample::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+        /*
+          Same through `UNNEST` of bulk insert, which passes arrays as text literals
+        */
+        TableSample::bulk_insert(client, &null_samples, 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Bulk Round Trip Cmp").await;
+        let deleted = TableSample::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+    }
+
+    /*
+      Test transactional bulk insert, where duplicates of the first samples fail the trailing chunks
+    */
+    {
+        let mut batch_resource = get_pool().await.get().await.unwrap();
+        let samples = get_sample_rows();
+        let chunk_size = 4;
+        let rows = samples
+            .iter()
+            .chain(samples.iter().take(chunk_size))
+            .cloned()
+            .collect::<Vec<_>>();
+        let chunk_count = rows.len().div_ceil(chunk_size);
+        let committed_chunks = samples.len() / chunk_size;
+        let report = TableSample::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::ChunkSavepoints,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            (0..committed_chunks).collect::<Vec<_>>(),
+            report
+                .succeeded
+                .iter()
+                .map(|success| success.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks * chunk_size) as i64,
+            TableSample::count_where(client, "1=1", &[]).await.unwrap()
+        );
+        TableSample::delete_all(client).await.unwrap();
+
+        let report = TableSample::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.succeeded.is_empty());
+        assert_eq!(
+            (0..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            1,
+            report
+                .failed
+                .iter()
+                .filter(|failure| failure.error.is_some())
+                .count()
+        );
+        assert!(!TableSample::exists_where(client, "1=1", &[]).await.unwrap());
+
+        let report = TableSample::bulk_insert_transactional(
+            &mut **batch_resource,
+            &samples,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.is_complete());
+        assert_eq!(
+            samples.len(),
+            TableSample::select_all(client).await.unwrap().len()
+        );
+        TableSample::delete_all(client).await.unwrap();
+    }
+
     /*
       Test the bulk insert functionality
     */
     {
-        let inserted = TableSample::bulk_insert(client, &samples, 4).await.unwrap();
-        tracing::debug!("Inserted with `bulk_insert` -> {inserted:?}");
+        TableSample::bulk_insert(client, &samples, 4).await.unwrap();
         /*
           Select back out the inserted data and compare to samples
         */
-        select_and_compare_assert(
+        select_and_compare_assert(client, &get_sample_rows(), "Blk Ins Cmp").await;
+    }
+
+    /*
+      Test the functions running the prepared statements
+    */
+    {
+        let deleted = TableSample::delete_all_prepared(&resource).await.unwrap();
+        assert_eq!(samples.len(), deleted as usize);
+        TableSample::bulk_insert_prepared(&resource, &samples, 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Ins Cmp").await;
+        TableSample::bulk_upsert_prepared(&resource, &samples, 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Upsert Cmp").await;
+        for row in TableSample::select_all(client).await.unwrap() {
+            let selected = TableSample::select_by_pkey_prepared(&resource, &row.pkey())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+    }
+
+    /*
+      Test copy in reloads the bulk inserted data and copy out matches select
+    */
+    {
+        let bulk_inserted = TableSample::select_all(client).await.unwrap();
+        let deleted = TableSample::delete_all(client).await.unwrap();
+        assert_eq!(bulk_inserted.len(), deleted as usize);
+        let copy_in_rows = bulk_inserted.clone();
+        assert_eq!(
+            bulk_inserted.len() as u64,
+            TableSample::copy_in(client, &copy_in_rows).await.unwrap()
+        );
+        select_and_compare_assert(client, &bulk_inserted, "Copy In Cmp").await;
+
+        let selected = TableSample::select_all(client).await.unwrap();
+        let copied_out = TableSample::copy_out_where(client, "1=1").await.unwrap();
+        assert_eq!(
+            selected
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>(),
+            copied_out
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>()
+        );
+    }
+
+    /*
+      Test the streaming selects
+    */
+    {
+        let selected_count = TableSample::count_where(client, "1=1", &[]).await.unwrap() as usize;
+        let streamed = TableSample::select_stream_where(client, "1=1", &[])
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(selected_count, streamed.len());
+
+        let mut chunk_resource = get_pool().await.get().await.unwrap();
+        let transaction = chunk_resource.transaction().await.unwrap();
+        let chunks = TableSample::select_chunks_where(&transaction, "1=1", &[], 3)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 3));
+        assert_eq!(
+            selected_count,
+            chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+        );
+        transaction.commit().await.unwrap();
+    }
+
+    /*
+      Test paging by keyset and by offset
+    */
+    {
+        let selected_count = TableSample::count_where(client, "1=1", &[]).await.unwrap() as usize;
+        let mut keyset_rows = Vec::new();
+        let mut after = None;
+        loop {
+            let page = TableSample::select_page_after(client, after.as_ref(), 3)
+                .await
+                .unwrap();
+            assert!(page.rows.len() <= 3);
+            keyset_rows.extend(page.rows);
+            after = page.next;
+            if after.is_none() {
+                break;
+            }
+        }
+        let mut offset_rows = Vec::new();
+        let mut offset = Some(0);
+        while let Some(at) = offset {
+            let page = TableSample::select_page_at_offset(client, at, 3)
+                .await
+                .unwrap();
+            offset_rows.extend(page.rows);
+            offset = page.next;
+        }
+        assert_eq!(selected_count, keyset_rows.len());
+        assert_eq!(format!("{keyset_rows:?}"), format!("{offset_rows:?}"));
+    }
+
+    /*
+      Test the aggregates
+    */
+    {
+        assert_eq!(
+            samples.len() as i64,
+            TableSample::count_where(client, "1=1", &[]).await.unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSample::count_where(client, "1=0", &[]).await.unwrap()
+        );
+        assert!(TableSample::exists_where(client, "1=1", &[]).await.unwrap());
+        assert!(!TableSample::exists_where(client, "1=0", &[]).await.unwrap());
+        let min = TableSample::min_the_name(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        let max = TableSample::max_the_name(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(min <= max);
+        assert!(
+            TableSample::count_where(client, r#"the_name = $1"#, &[&min])
+                .await
+                .unwrap()
+                >= 1
+        );
+        assert_eq!(
+            None,
+            TableSample::max_the_name(client, "1=0", &[]).await.unwrap()
+        );
+    }
+
+    /*
+      Test the typed filter
+    */
+    {
+        let value = samples[0].the_name.clone();
+        let expected = samples.iter().filter(|row| row.the_name == value).count();
+        let matched =
+            TableSample::select_all_filtered(client, &Filter::eq(SampleColumn::TheName, value))
+                .await
+                .unwrap();
+        assert_eq!(expected, matched.len());
+        let unmatched = TableSample::select_all_filtered(
             client,
-            &get_sample_rows().iter().cloned().collect(),
-            "Blk Ins Cmp",
+            &Filter::ne(SampleColumn::TheName, samples[0].the_name.clone())
+                .or(Filter::is_null(SampleColumn::TheName)),
         )
-        .await;
+        .await
+        .unwrap();
+        assert_eq!(samples.len(), matched.len() + unmatched.len());
+        assert_eq!(
+            samples.len(),
+            TableSample::select_all_filtered(client, &Filter::And(vec![]))
+                .await
+                .unwrap()
+                .len()
+        );
+    }
+
+    /*
+      Test the primary key functions
+    */
+    {
+        let selected_all = TableSample::select_all(client).await.unwrap();
+        let keys = selected_all
+            .iter()
+            .map(|row| row.pkey())
+            .collect::<Vec<_>>();
+        for (row, key) in selected_all.iter().zip(keys.iter()) {
+            let selected = TableSample::select_by_pkey(client, key)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+        assert_eq!(
+            keys.len(),
+            TableSample::select_by_pkeys(client, &keys)
+                .await
+                .unwrap()
+                .len()
+        );
+
+        let key = &keys[0];
+        let mut updated = TableSample::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .unwrap();
+        mutate_row_data(&mut updated);
+        assert_eq!(
+            1,
+            TableSample::update_by_pkey(client, key, &updated)
+                .await
+                .unwrap()
+        );
+        let reselected = TableSample::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(format!("{updated:?}"), format!("{reselected:?}"));
+        assert_eq!(1, TableSample::delete_by_pkey(client, key).await.unwrap());
+        assert!(TableSample::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(0, TableSample::delete_by_pkey(client, key).await.unwrap());
+    }
+
+    /*
+      Test the bulk update and delete by primary key
+    */
+    {
+        let mut bulk_rows = TableSample::select_all(client).await.unwrap();
+        bulk_rows.iter_mut().for_each(mutate_row_data);
+        assert_eq!(
+            bulk_rows.len() as u64,
+            TableSample::bulk_update(client, &bulk_rows, 4)
+                .await
+                .unwrap()
+        );
+        select_and_compare_assert(client, &bulk_rows, "Blk Update Cmp").await;
+        let keys = bulk_rows.iter().map(|row| row.pkey()).collect::<Vec<_>>();
+        assert_eq!(
+            keys.len() as u64,
+            TableSample::bulk_delete_by_pkeys(client, &keys, 4)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSample::count_where(client, "1=1", &[]).await.unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSample::bulk_delete_by_pkeys(client, &keys, 4)
+                .await
+                .unwrap()
+        );
+        TableSample::bulk_upsert(client, &bulk_rows, 4)
+            .await
+            .unwrap();
+    }
+
+    /*
+      Test the sync of the table to rows
+    */
+    {
+        let mut sync_resource = get_pool().await.get().await.unwrap();
+        let mut target = TableSample::select_all(client).await.unwrap();
+        assert_eq!(
+            1,
+            TableSample::delete_by_pkey(client, &target[0].pkey())
+                .await
+                .unwrap()
+        );
+        mutate_row_data(&mut target[1]);
+        let removed = target.remove(2);
+        let summary = TableSample::sync_rows(&mut **sync_resource, &target)
+            .await
+            .unwrap();
+        assert_eq!(
+            SyncSummary {
+                inserted: 1,
+                updated: 1,
+                deleted: 1,
+                unchanged: target.len() as u64 - 2,
+            },
+            summary
+        );
+        select_and_compare_assert(client, &target, "Sync Cmp").await;
+        let mut repeated = target.clone();
+        let mut stale = target[0].clone();
+        mutate_row_data(&mut stale);
+        repeated.insert(0, stale);
+        let summary = TableSample::sync_rows(&mut **sync_resource, &repeated)
+            .await
+            .unwrap();
+        assert_eq!(
+            SyncSummary {
+                unchanged: target.len() as u64,
+                ..Default::default()
+            },
+            summary
+        );
+        TableSample::bulk_upsert(client, &[removed], 4)
+            .await
+            .unwrap();
     }
 
     /*
       Mutate the data, and bulk upsert.
     */
     {
-        samples.iter_mut().for_each(|data| mutate_row_data(data));
+        samples.iter_mut().for_each(mutate_row_data);
         tracing::debug!("Mutated Samples: {samples:?}");
-        let upserted = TableSample::bulk_upsert(client, &samples, 4).await.unwrap();
-        tracing::debug!("Inserted with `bulk_upsert` -> {upserted:?}");
-        select_and_compare_assert(client, &samples.iter().cloned().collect(), "Blk Upsert Cmp")
-            .await;
+        TableSample::bulk_upsert(client, &samples, 4).await.unwrap();
+        select_and_compare_assert(client, &samples, "Blk Upsert Cmp").await;
     }
 
     /*
@@ -515,10 +1305,27 @@ async fn test_crud() {
         let deleted = TableSample::delete_all(client).await.unwrap();
         tracing::info!("Deleted all {deleted} TableSample entries");
         assert_eq!(deleted as usize, samples.len());
-        let selected = TableSample::select_all(client).await;
+        let selected = TableSample::select_all(client).await.unwrap();
         assert_eq!(0, selected.len());
     }
 }
 
+/// Test the schema provisioned from the generated DDL matches that assumed by sample
+#[tracing_test::traced_test]
+#[serial_test::serial]
+#[tokio::test]
+async fn test_verify_schema() {
+    let resource = get_pool().await.get().await.unwrap();
+    let client = resource.client();
+    TableSample::create_table(client).await.unwrap();
+    let report = TableSample::verify_schema(client).await.unwrap();
+    assert!(
+        report.is_consistent(),
+        "Schema drift in `{}`: {:?}",
+        report.table_name,
+        report.drifts
+    );
+}
+
 // α <mod-def test_sample>
 // ω <mod-def test_sample>
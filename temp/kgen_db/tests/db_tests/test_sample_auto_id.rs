@@ -0,0 +1,682 @@
+//! Tests for sample_auto_id table
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- module uses ---
+////////////////////////////////////////////////////////////////////////////////////
+use super::support::get_pool;
+use super::support::MutateValue;
+use futures::TryStreamExt;
+use kgen_db::batch::BatchMode;
+use kgen_db::filter::Filter;
+use kgen_db::sample_auto_id::*;
+use std::collections::BTreeSet;
+#[allow(unused_imports)]
+use std::ops::Deref;
+use tokio_postgres::GenericClient;
+
+////////////////////////////////////////////////////////////////////////////////////
+// --- functions ---
+////////////////////////////////////////////////////////////////////////////////////
+/// Mutate fields not appearing in pkey or unique keys for test purposes with pkey.
+/// Only fields not appearing in unique keys allows stability in the uniqueness of rows.
+/// Fields set by the server are left as is.
+///
+///   * **row_data** - Data to mutate
+pub fn mutate_row_data(row_data: &mut SampleAutoIdRowData) {
+    row_data.the_large_int.mutate_value();
+    row_data.the_date.mutate_value();
+    row_data.nullable_name.mutate_value();
+}
+
+/// Select all from the database and assert they compare to [values]
+///
+///   * **client** - The pool connection
+///   * **values** - Values to compare to selected
+///   * **label** - Label for assert
+pub async fn select_and_compare_assert<T>(client: &T, values: &[SampleAutoIdRowData], label: &str)
+where
+    T: GenericClient,
+{
+    let selected_entries = TableSampleAutoId::select_all(client).await.unwrap();
+    let selected = entries_to_row_data(&selected_entries);
+    assert_eq!(selected.len(), values.len());
+    get_sample_rows_sorted(&selected)
+        .iter()
+        .zip(get_sample_rows_sorted(values).iter())
+        .for_each(|(a, b)| {
+            let matched = a == b;
+            tracing::debug!(
+                "{label}: {}",
+                if matched {
+                    format!("Match({a:?})")
+                } else {
+                    format!("Mismatch\n{a:?}\n---\n{b:?}")
+                }
+            );
+            assert!(matched);
+        });
+}
+
+/// Convert entries that include both auto-id and data into the data portion
+///
+///   * **entries** - The entries
+///   * _return_ - The data portion of the entries
+#[inline]
+pub fn entries_to_row_data(entries: &[SampleAutoIdEntry]) -> Vec<SampleAutoIdRowData> {
+    entries.iter().cloned().map(|e| e.data).collect()
+}
+
+/// Get the sample rows as a set
+///
+///   * **rows** - The rows to stringify and sort
+///   * _return_ - The samples as set
+#[inline]
+pub fn get_sample_rows_sorted(rows: &[SampleAutoIdRowData]) -> BTreeSet<String> {
+    rows.iter().map(|r| format!("{r:?}")).collect()
+}
+
+/// Get a set of sample rows for testing
+///
+///   * _return_ - Set of sample rows to test CRUD methods
+pub fn get_sample_rows() -> Vec<SampleAutoIdRowData> {
+    vec![
+        SampleAutoIdRowData {
+            the_name: "a".into(),
+            the_large_int: -2147483648,
+            the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("a".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "b".into(),
+            the_large_int: -2147483647,
+            the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("b".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "c".into(),
+            the_large_int: -2147483646,
+            the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("c".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "d".into(),
+            the_large_int: -2147483645,
+            the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("d".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "e".into(),
+            the_large_int: -2147483644,
+            the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("e".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "f".into(),
+            the_large_int: -2147483643,
+            the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("f".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "g".into(),
+            the_large_int: -2147483642,
+            the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("g".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "h".into(),
+            the_large_int: -2147483641,
+            the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("h".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "i".into(),
+            the_large_int: -2147483640,
+            the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("i".into()),
+        },
+        SampleAutoIdRowData {
+            the_name: "j".into(),
+            the_large_int: -2147483639,
+            the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
+            nullable_name: Some("j".into()),
+        },
+    ]
+}
+
+/// Test by delete, bulk insert, select, bulk upsert, then delete for sample_auto_id
+#[tracing_test::traced_test]
+#[serial_test::serial]
+#[tokio::test]
+async fn test_crud() {
+    let resource = get_pool().await.get().await.unwrap();
+    let client = resource.client();
+    // Provision the schema from the generated DDL. The table is shared, so it is only dropped and
+    // recreated when `KGEN_DB_RECREATE_TABLES` opts in, otherwise created if missing.
+    if std::env::var("KGEN_DB_RECREATE_TABLES").is_ok() {
+        TableSampleAutoId::drop_table(client).await.unwrap();
+    }
+    TableSampleAutoId::create_table(client).await.unwrap();
+    // First delete all, assuming it worked
+    let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+    tracing::info!("Initialize phase deleted {deleted}");
+
+    /*
+      Validate that delete work by selecting back an empty set
+    */
+    {
+        assert_eq!(
+            0,
+            TableSampleAutoId::select_all(client).await.unwrap().len()
+        );
+    }
+    let mut samples = get_sample_rows();
+
+    /*
+      Test the basic insert functionality
+    */
+    {
+        let inserted = TableSampleAutoId::basic_insert(client, samples.clone())
+            .await
+            .unwrap();
+
+        tracing::debug!("Inserted with `basic_insert` -> {inserted:?}");
+
+        /*
+          Select back out the inserted data and compare to samples
+        */
+        {
+            select_and_compare_assert(
+                client,
+                &inserted.into_iter().map(|r| r.data).collect::<Vec<_>>(),
+                "Basic Ins Cmp",
+            )
+            .await;
+        }
+        let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+        tracing::info!("Basic insert phase deleted {deleted}");
+        assert_eq!(samples.len(), deleted as usize);
+    }
+
+    /*
+      Test NULL round trips for every nullable column
+    */
+    {
+        let null_samples = get_sample_rows()
+            .into_iter()
+            .map(|mut row| {
+                row.nullable_name = None;
+                row
+            })
+            .collect::<Vec<_>>();
+        TableSampleAutoId::basic_insert(client, null_samples.clone())
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Round Trip Cmp").await;
+        let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+        /*
+          Same through `UNNEST` of bulk insert, which passes arrays as text literals
+        */
+        TableSampleAutoId::bulk_insert(client, null_samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Bulk Round Trip Cmp").await;
+        let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+    }
+
+    /*
+      Test transactional bulk insert, where duplicates of the first samples fail the trailing chunks
+    */
+    {
+        let mut batch_resource = get_pool().await.get().await.unwrap();
+        let samples = get_sample_rows();
+        let chunk_size = 4;
+        let rows = samples
+            .iter()
+            .chain(samples.iter().take(chunk_size))
+            .cloned()
+            .collect::<Vec<_>>();
+        let chunk_count = rows.len().div_ceil(chunk_size);
+        let committed_chunks = samples.len() / chunk_size;
+        let report = TableSampleAutoId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::ChunkSavepoints,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            (0..committed_chunks).collect::<Vec<_>>(),
+            report
+                .succeeded
+                .iter()
+                .map(|success| success.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks * chunk_size) as i64,
+            TableSampleAutoId::count_where(client, "1=1", &[])
+                .await
+                .unwrap()
+        );
+        TableSampleAutoId::delete_all(client).await.unwrap();
+
+        let report = TableSampleAutoId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.succeeded.is_empty());
+        assert_eq!(
+            (0..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            1,
+            report
+                .failed
+                .iter()
+                .filter(|failure| failure.error.is_some())
+                .count()
+        );
+        assert!(!TableSampleAutoId::exists_where(client, "1=1", &[])
+            .await
+            .unwrap());
+
+        let report = TableSampleAutoId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &samples,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.is_complete());
+        assert_eq!(
+            samples.len(),
+            TableSampleAutoId::select_all(client).await.unwrap().len()
+        );
+        TableSampleAutoId::delete_all(client).await.unwrap();
+    }
+
+    /*
+      Test the bulk insert functionality
+    */
+    {
+        let inserted = TableSampleAutoId::bulk_insert(client, samples.clone(), 4)
+            .await
+            .unwrap();
+        tracing::debug!("Inserted with `bulk_insert` -> {inserted:?}");
+        /*
+          Select back out the inserted data and compare to samples
+        */
+        select_and_compare_assert(
+            client,
+            &inserted.into_iter().map(|r| r.data).collect::<Vec<_>>(),
+            "Blk Ins Cmp",
+        )
+        .await;
+    }
+
+    /*
+      Test the functions running the prepared statements
+    */
+    {
+        let deleted = TableSampleAutoId::delete_all_prepared(&resource)
+            .await
+            .unwrap();
+        assert_eq!(samples.len(), deleted as usize);
+        TableSampleAutoId::bulk_insert_prepared(&resource, samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Ins Cmp").await;
+        TableSampleAutoId::bulk_upsert_prepared(&resource, samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Upsert Cmp").await;
+    }
+
+    /*
+      Test copy in reloads the bulk inserted data and copy out matches select
+    */
+    {
+        let bulk_inserted = TableSampleAutoId::select_all(client)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| row.data)
+            .collect::<Vec<_>>();
+        let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+        assert_eq!(bulk_inserted.len(), deleted as usize);
+        let copy_in_rows = bulk_inserted.clone();
+        assert_eq!(
+            bulk_inserted.len() as u64,
+            TableSampleAutoId::copy_in(client, &copy_in_rows)
+                .await
+                .unwrap()
+        );
+        select_and_compare_assert(client, &bulk_inserted, "Copy In Cmp").await;
+
+        let selected = TableSampleAutoId::select_all(client).await.unwrap();
+        let copied_out = TableSampleAutoId::copy_out_where(client, "1=1")
+            .await
+            .unwrap();
+        assert_eq!(
+            selected
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>(),
+            copied_out
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>()
+        );
+    }
+
+    /*
+      Test the streaming selects
+    */
+    {
+        let selected_count = TableSampleAutoId::count_where(client, "1=1", &[])
+            .await
+            .unwrap() as usize;
+        let streamed = TableSampleAutoId::select_stream_where(client, "1=1", &[])
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(selected_count, streamed.len());
+
+        let mut chunk_resource = get_pool().await.get().await.unwrap();
+        let transaction = chunk_resource.transaction().await.unwrap();
+        let chunks = TableSampleAutoId::select_chunks_where(&transaction, "1=1", &[], 3)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 3));
+        assert_eq!(
+            selected_count,
+            chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+        );
+        transaction.commit().await.unwrap();
+    }
+
+    /*
+      Test paging by keyset and by offset
+    */
+    {
+        let selected_count = TableSampleAutoId::count_where(client, "1=1", &[])
+            .await
+            .unwrap() as usize;
+        let mut keyset_rows = Vec::new();
+        let mut after = None;
+        loop {
+            let page = TableSampleAutoId::select_page_after(client, after.as_ref(), 3)
+                .await
+                .unwrap();
+            assert!(page.rows.len() <= 3);
+            keyset_rows.extend(page.rows);
+            after = page.next;
+            if after.is_none() {
+                break;
+            }
+        }
+        let mut offset_rows = Vec::new();
+        let mut offset = Some(0);
+        while let Some(at) = offset {
+            let page = TableSampleAutoId::select_page_at_offset(client, at, 3)
+                .await
+                .unwrap();
+            offset_rows.extend(page.rows);
+            offset = page.next;
+        }
+        assert_eq!(selected_count, keyset_rows.len());
+        assert_eq!(format!("{keyset_rows:?}"), format!("{offset_rows:?}"));
+    }
+
+    /*
+      Test the aggregates
+    */
+    {
+        assert_eq!(
+            samples.len() as i64,
+            TableSampleAutoId::count_where(client, "1=1", &[])
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSampleAutoId::count_where(client, "1=0", &[])
+                .await
+                .unwrap()
+        );
+        assert!(TableSampleAutoId::exists_where(client, "1=1", &[])
+            .await
+            .unwrap());
+        assert!(!TableSampleAutoId::exists_where(client, "1=0", &[])
+            .await
+            .unwrap());
+        let min = TableSampleAutoId::min_auto_id(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        let max = TableSampleAutoId::max_auto_id(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(min <= max);
+        assert!(
+            TableSampleAutoId::count_where(client, r#"auto_id = $1"#, &[&min])
+                .await
+                .unwrap()
+                >= 1
+        );
+        assert_eq!(
+            None,
+            TableSampleAutoId::max_auto_id(client, "1=0", &[])
+                .await
+                .unwrap()
+        );
+    }
+
+    /*
+      Test the typed filter
+    */
+    {
+        let value = samples[0].the_name.clone();
+        let expected = samples.iter().filter(|row| row.the_name == value).count();
+        let matched = TableSampleAutoId::select_all_filtered(
+            client,
+            &Filter::eq(SampleAutoIdColumn::TheName, value),
+        )
+        .await
+        .unwrap();
+        assert_eq!(expected, matched.len());
+        let unmatched = TableSampleAutoId::select_all_filtered(
+            client,
+            &Filter::ne(SampleAutoIdColumn::TheName, samples[0].the_name.clone())
+                .or(Filter::is_null(SampleAutoIdColumn::TheName)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(samples.len(), matched.len() + unmatched.len());
+        assert_eq!(
+            samples.len(),
+            TableSampleAutoId::select_all_filtered(client, &Filter::And(vec![]))
+                .await
+                .unwrap()
+                .len()
+        );
+    }
+
+    /*
+      Test the unique index `sample_auto_id_the_name_unique` functions
+    */
+    {
+        let selected_all = TableSampleAutoId::select_all(client).await.unwrap();
+        for row in selected_all.iter() {
+            let key = row.data.sample_auto_id_the_name_unique_key();
+            let selected =
+                TableSampleAutoId::select_by_sample_auto_id_the_name_unique(client, &key)
+                    .await
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+
+        let mut upsert_rows = selected_all
+            .into_iter()
+            .map(|row| row.data)
+            .collect::<Vec<_>>();
+        upsert_rows.iter_mut().for_each(mutate_row_data);
+        TableSampleAutoId::bulk_upsert_by_sample_auto_id_the_name_unique(
+            client,
+            upsert_rows.clone(),
+            4,
+        )
+        .await
+        .unwrap();
+        select_and_compare_assert(
+            client,
+            &upsert_rows,
+            "Upsert By sample_auto_id_the_name_unique Cmp",
+        )
+        .await;
+
+        let key = upsert_rows[0].sample_auto_id_the_name_unique_key();
+        assert_eq!(
+            1,
+            TableSampleAutoId::delete_by_sample_auto_id_the_name_unique(client, &key)
+                .await
+                .unwrap()
+        );
+        assert!(
+            TableSampleAutoId::select_by_sample_auto_id_the_name_unique(client, &key)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        TableSampleAutoId::bulk_upsert_by_sample_auto_id_the_name_unique(
+            client,
+            upsert_rows.clone(),
+            4,
+        )
+        .await
+        .unwrap();
+    }
+
+    /*
+      Test bulk upsert entries of rows inserted and rows updated
+    */
+    {
+        let existing = TableSampleAutoId::select_all(client).await.unwrap();
+        let (deleted, kept) = existing.split_at(existing.len() / 2);
+        for entry in deleted.iter() {
+            assert_eq!(
+                1,
+                TableSampleAutoId::delete_by_sample_auto_id_the_name_unique(
+                    client,
+                    &entry.data.sample_auto_id_the_name_unique_key()
+                )
+                .await
+                .unwrap()
+            );
+        }
+        let mut upsert_rows = kept
+            .iter()
+            .chain(deleted.iter())
+            .rev()
+            .map(|entry| entry.data.clone())
+            .collect::<Vec<_>>();
+        upsert_rows.iter_mut().for_each(mutate_row_data);
+        let upserted = TableSampleAutoId::bulk_upsert(client, upsert_rows.clone(), 4)
+            .await
+            .unwrap();
+        assert_eq!(upsert_rows.len(), upserted.len());
+        for entry in upserted.iter() {
+            let selected = TableSampleAutoId::select_by_sample_auto_id_the_name_unique(
+                client,
+                &entry.data.sample_auto_id_the_name_unique_key(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            assert_eq!(selected.auto_id, entry.auto_id);
+        }
+        for entry in kept.iter() {
+            let selected = TableSampleAutoId::select_by_sample_auto_id_the_name_unique(
+                client,
+                &entry.data.sample_auto_id_the_name_unique_key(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            assert_eq!(entry.auto_id, selected.auto_id);
+        }
+        select_and_compare_assert(client, &upsert_rows, "Upsert Entries Cmp").await;
+    }
+
+    /*
+      Mutate the data, and bulk upsert.
+    */
+    {
+        samples.iter_mut().for_each(mutate_row_data);
+        tracing::debug!("Mutated Samples: {samples:?}");
+        let upserted = TableSampleAutoId::bulk_upsert(client, samples.clone(), 4)
+            .await
+            .unwrap();
+        tracing::debug!("Inserted with `bulk_upsert` -> {upserted:?}");
+        select_and_compare_assert(client, &samples, "Blk Upsert Cmp").await;
+    }
+
+    /*
+      Deleted all entries
+    */
+    {
+        let deleted = TableSampleAutoId::delete_all(client).await.unwrap();
+        tracing::info!("Deleted all {deleted} TableSampleAutoId entries");
+        assert_eq!(deleted as usize, samples.len());
+        let selected = TableSampleAutoId::select_all(client).await.unwrap();
+        assert_eq!(0, selected.len());
+    }
+}
+
+/// Test the schema provisioned from the generated DDL matches that assumed by sample_auto_id
+#[tracing_test::traced_test]
+#[serial_test::serial]
+#[tokio::test]
+async fn test_verify_schema() {
+    let resource = get_pool().await.get().await.unwrap();
+    let client = resource.client();
+    TableSampleAutoId::create_table(client).await.unwrap();
+    let report = TableSampleAutoId::verify_schema(client).await.unwrap();
+    assert!(
+        report.is_consistent(),
+        "Schema drift in `{}`: {:?}",
+        report.table_name,
+        report.drifts
+    );
+}
+
+// α <mod-def test_sample_auto_id>
+// ω <mod-def test_sample_auto_id>
@@ -4,8 +4,14 @@
 // --- module uses ---
 ////////////////////////////////////////////////////////////////////////////////////
 use super::support::get_pool;
+use super::support::mutate_json;
 use super::support::MutateValue;
+use futures::TryStreamExt;
+use kgen_db::batch::BatchMode;
+use kgen_db::filter::Filter;
 use kgen_db::sample_with_id::*;
+use kgen_db::unsigned::U64;
+use kgen_db::version::VersionedUpdate;
 use std::collections::BTreeSet;
 #[allow(unused_imports)]
 use std::ops::Deref;
@@ -16,6 +22,7 @@ use tokio_postgres::GenericClient;
 ////////////////////////////////////////////////////////////////////////////////////
 /// Mutate fields not appearing in pkey or unique keys for test purposes with pkey.
 /// Only fields not appearing in unique keys allows stability in the uniqueness of rows.
+/// Fields set by the server are left as is.
 ///
 ///   * **row_data** - Data to mutate
 pub fn mutate_row_data(row_data: &mut SampleWithIdRowData) {
@@ -28,7 +35,8 @@ pub fn mutate_row_data(row_data: &mut SampleWithIdRowData) {
     row_data.the_uuid.mutate_value();
     row_data.the_ulong.mutate_value();
     row_data.the_json.mutate_value();
-    row_data.the_jsonb.mutate_value();
+    mutate_json(&mut row_data.the_jsonb);
+    row_data.the_version.mutate_value();
     row_data.nullable_name.mutate_value();
     row_data.nullable_boolean.mutate_value();
     row_data.nullable_small_int.mutate_value();
@@ -48,14 +56,11 @@ pub fn mutate_row_data(row_data: &mut SampleWithIdRowData) {
 ///   * **client** - The pool connection
 ///   * **values** - Values to compare to selected
 ///   * **label** - Label for assert
-pub async fn select_and_compare_assert<T>(
-    client: &T,
-    values: &Vec<SampleWithIdRowData>,
-    label: &str,
-) where
+pub async fn select_and_compare_assert<T>(client: &T, values: &[SampleWithIdRowData], label: &str)
+where
     T: GenericClient,
 {
-    let selected_entries = TableSampleWithId::select_all(client).await;
+    let selected_entries = TableSampleWithId::select_all(client).await.unwrap();
     let selected = entries_to_row_data(&selected_entries);
     assert_eq!(selected.len(), values.len());
     get_sample_rows_sorted(&selected)
@@ -71,7 +76,7 @@ pub async fn select_and_compare_assert<T>(
                     format!("Mismatch\n{a:?}\n---\n{b:?}")
                 }
             );
-            assert_eq!(true, matched);
+            assert!(matched);
         });
 }
 
@@ -90,7 +95,7 @@ pub fn entries_to_row_data(entries: &[SampleWithIdEntry]) -> Vec<SampleWithIdRow
 ///   * _return_ - The samples as set
 #[inline]
 pub fn get_sample_rows_sorted(rows: &[SampleWithIdRowData]) -> BTreeSet<String> {
-    rows.iter().cloned().map(|r| format!("{r:?}")).collect()
+    rows.iter().map(|r| format!("{r:?}")).collect()
 }
 
 /// Get a set of sample rows for testing
@@ -103,7 +108,7 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             the_boolean: false,
             the_small_int: -32768,
             the_large_int: -2147483648,
-            the_big_int: -2147483648,
+            the_big_int: U64(9223372036854774807),
             the_date: chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483648,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -112,14 +117,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
-            the_ulong: -2147483648,
+            the_ulong: U64(9223372036854774807),
             the_json: "{ value: 1 }".into(),
-            the_jsonb: "{ value: 1 }".into(),
-            nullable_name: "a".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483648,
+            nullable_name: Some("a".into()),
             nullable_boolean: Some(false),
             nullable_small_int: Some(-32768),
             nullable_large_int: Some(-2147483648),
-            nullable_big_int: Some(-2147483648),
+            nullable_big_int: Some(U64(9223372036854774807)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-01-01", "%Y-%m-%d").unwrap(),
             ),
@@ -131,16 +137,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("528f97ee-afb7-349d-ae9d-228f407501d5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483648),
+            nullable_ulong: Some(U64(9223372036854774807)),
             nullable_json: Some("{ value: 1 }".into()),
-            nullable_jsonb: "{ value: 1 }".into(),
+            nullable_jsonb: Some("{ value: 1 }".into()),
         },
         SampleWithIdRowData {
             the_name: "b".into(),
             the_boolean: true,
             the_small_int: -32767,
             the_large_int: -2147483647,
-            the_big_int: -2147483647,
+            the_big_int: U64(9223372036854774808),
             the_date: chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483647,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -149,14 +155,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
-            the_ulong: -2147483647,
+            the_ulong: U64(9223372036854774808),
             the_json: "{ value: 2 }".into(),
-            the_jsonb: "{ value: 2 }".into(),
-            nullable_name: "b".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483647,
+            nullable_name: Some("b".into()),
             nullable_boolean: Some(true),
             nullable_small_int: Some(-32767),
             nullable_large_int: Some(-2147483647),
-            nullable_big_int: Some(-2147483647),
+            nullable_big_int: Some(U64(9223372036854774808)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-02-03", "%Y-%m-%d").unwrap(),
             ),
@@ -168,16 +175,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("2d41f1a3-e690-3d91-8a3e-cce82beaf5a5").unwrap(),
             ),
-            nullable_ulong: Some(-2147483647),
+            nullable_ulong: Some(U64(9223372036854774808)),
             nullable_json: Some("{ value: 2 }".into()),
-            nullable_jsonb: "{ value: 2 }".into(),
+            nullable_jsonb: Some("{ value: 2 }".into()),
         },
         SampleWithIdRowData {
             the_name: "c".into(),
             the_boolean: false,
             the_small_int: -32766,
             the_large_int: -2147483646,
-            the_big_int: -2147483646,
+            the_big_int: U64(9223372036854774809),
             the_date: chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483646,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -186,14 +193,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
-            the_ulong: -2147483646,
+            the_ulong: U64(9223372036854774809),
             the_json: "{ value: 3 }".into(),
-            the_jsonb: "{ value: 3 }".into(),
-            nullable_name: "c".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483646,
+            nullable_name: Some("c".into()),
             nullable_boolean: Some(false),
             nullable_small_int: Some(-32766),
             nullable_large_int: Some(-2147483646),
-            nullable_big_int: Some(-2147483646),
+            nullable_big_int: Some(U64(9223372036854774809)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-03-05", "%Y-%m-%d").unwrap(),
             ),
@@ -205,16 +213,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("01ff6bbb-b780-3928-addf-5f189dc96802").unwrap(),
             ),
-            nullable_ulong: Some(-2147483646),
+            nullable_ulong: Some(U64(9223372036854774809)),
             nullable_json: Some("{ value: 3 }".into()),
-            nullable_jsonb: "{ value: 3 }".into(),
+            nullable_jsonb: Some("{ value: 3 }".into()),
         },
         SampleWithIdRowData {
             the_name: "d".into(),
             the_boolean: true,
             the_small_int: -32765,
             the_large_int: -2147483645,
-            the_big_int: -2147483645,
+            the_big_int: U64(9223372036854774810),
             the_date: chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483645,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -223,14 +231,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
-            the_ulong: -2147483645,
+            the_ulong: U64(9223372036854774810),
             the_json: "{ value: 4 }".into(),
-            the_jsonb: "{ value: 4 }".into(),
-            nullable_name: "d".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483645,
+            nullable_name: Some("d".into()),
             nullable_boolean: Some(true),
             nullable_small_int: Some(-32765),
             nullable_large_int: Some(-2147483645),
-            nullable_big_int: Some(-2147483645),
+            nullable_big_int: Some(U64(9223372036854774810)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-04-07", "%Y-%m-%d").unwrap(),
             ),
@@ -242,16 +251,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("7fa8058e-840e-362e-a8e3-9d1b75f39fe8").unwrap(),
             ),
-            nullable_ulong: Some(-2147483645),
+            nullable_ulong: Some(U64(9223372036854774810)),
             nullable_json: Some("{ value: 4 }".into()),
-            nullable_jsonb: "{ value: 4 }".into(),
+            nullable_jsonb: Some("{ value: 4 }".into()),
         },
         SampleWithIdRowData {
             the_name: "e".into(),
             the_boolean: false,
             the_small_int: -32764,
             the_large_int: -2147483644,
-            the_big_int: -2147483644,
+            the_big_int: U64(9223372036854774811),
             the_date: chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483644,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -260,14 +269,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
-            the_ulong: -2147483644,
+            the_ulong: U64(9223372036854774811),
             the_json: "{ value: 5 }".into(),
-            the_jsonb: "{ value: 5 }".into(),
-            nullable_name: "e".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483644,
+            nullable_name: Some("e".into()),
             nullable_boolean: Some(false),
             nullable_small_int: Some(-32764),
             nullable_large_int: Some(-2147483644),
-            nullable_big_int: Some(-2147483644),
+            nullable_big_int: Some(U64(9223372036854774811)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-05-09", "%Y-%m-%d").unwrap(),
             ),
@@ -279,16 +289,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0edc1aeb-3200-3d26-b2be-77c5039aecf3").unwrap(),
             ),
-            nullable_ulong: Some(-2147483644),
+            nullable_ulong: Some(U64(9223372036854774811)),
             nullable_json: Some("{ value: 5 }".into()),
-            nullable_jsonb: "{ value: 5 }".into(),
+            nullable_jsonb: Some("{ value: 5 }".into()),
         },
         SampleWithIdRowData {
             the_name: "f".into(),
             the_boolean: true,
             the_small_int: -32763,
             the_large_int: -2147483643,
-            the_big_int: -2147483643,
+            the_big_int: U64(9223372036854774812),
             the_date: chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483643,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -297,14 +307,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
-            the_ulong: -2147483643,
+            the_ulong: U64(9223372036854774812),
             the_json: "{ value: 6 }".into(),
-            the_jsonb: "{ value: 6 }".into(),
-            nullable_name: "f".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483643,
+            nullable_name: Some("f".into()),
             nullable_boolean: Some(true),
             nullable_small_int: Some(-32763),
             nullable_large_int: Some(-2147483643),
-            nullable_big_int: Some(-2147483643),
+            nullable_big_int: Some(U64(9223372036854774812)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-06-11", "%Y-%m-%d").unwrap(),
             ),
@@ -316,16 +327,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9d6a2a8e-4852-33e4-ade0-7aaadb41066d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483643),
+            nullable_ulong: Some(U64(9223372036854774812)),
             nullable_json: Some("{ value: 6 }".into()),
-            nullable_jsonb: "{ value: 6 }".into(),
+            nullable_jsonb: Some("{ value: 6 }".into()),
         },
         SampleWithIdRowData {
             the_name: "g".into(),
             the_boolean: false,
             the_small_int: -32762,
             the_large_int: -2147483642,
-            the_big_int: -2147483642,
+            the_big_int: U64(9223372036854774813),
             the_date: chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483642,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -334,14 +345,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
-            the_ulong: -2147483642,
+            the_ulong: U64(9223372036854774813),
             the_json: "{ value: 7 }".into(),
-            the_jsonb: "{ value: 7 }".into(),
-            nullable_name: "g".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483642,
+            nullable_name: Some("g".into()),
             nullable_boolean: Some(false),
             nullable_small_int: Some(-32762),
             nullable_large_int: Some(-2147483642),
-            nullable_big_int: Some(-2147483642),
+            nullable_big_int: Some(U64(9223372036854774813)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-07-13", "%Y-%m-%d").unwrap(),
             ),
@@ -353,16 +365,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("f89673f7-afd4-3121-b58d-c3b683a88e4d").unwrap(),
             ),
-            nullable_ulong: Some(-2147483642),
+            nullable_ulong: Some(U64(9223372036854774813)),
             nullable_json: Some("{ value: 7 }".into()),
-            nullable_jsonb: "{ value: 7 }".into(),
+            nullable_jsonb: Some("{ value: 7 }".into()),
         },
         SampleWithIdRowData {
             the_name: "h".into(),
             the_boolean: true,
             the_small_int: -32761,
             the_large_int: -2147483641,
-            the_big_int: -2147483641,
+            the_big_int: U64(9223372036854774814),
             the_date: chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483641,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -371,14 +383,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
-            the_ulong: -2147483641,
+            the_ulong: U64(9223372036854774814),
             the_json: "{ value: 8 }".into(),
-            the_jsonb: "{ value: 8 }".into(),
-            nullable_name: "h".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483641,
+            nullable_name: Some("h".into()),
             nullable_boolean: Some(true),
             nullable_small_int: Some(-32761),
             nullable_large_int: Some(-2147483641),
-            nullable_big_int: Some(-2147483641),
+            nullable_big_int: Some(U64(9223372036854774814)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-08-15", "%Y-%m-%d").unwrap(),
             ),
@@ -390,16 +403,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("9f56050f-c322-3efa-9e1f-96f4d3217930").unwrap(),
             ),
-            nullable_ulong: Some(-2147483641),
+            nullable_ulong: Some(U64(9223372036854774814)),
             nullable_json: Some("{ value: 8 }".into()),
-            nullable_jsonb: "{ value: 8 }".into(),
+            nullable_jsonb: Some("{ value: 8 }".into()),
         },
         SampleWithIdRowData {
             the_name: "i".into(),
             the_boolean: false,
             the_small_int: -32760,
             the_large_int: -2147483640,
-            the_big_int: -2147483640,
+            the_big_int: U64(9223372036854774815),
             the_date: chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483640,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -408,14 +421,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
-            the_ulong: -2147483640,
+            the_ulong: U64(9223372036854774815),
             the_json: "{ value: 9 }".into(),
-            the_jsonb: "{ value: 9 }".into(),
-            nullable_name: "i".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483640,
+            nullable_name: Some("i".into()),
             nullable_boolean: Some(false),
             nullable_small_int: Some(-32760),
             nullable_large_int: Some(-2147483640),
-            nullable_big_int: Some(-2147483640),
+            nullable_big_int: Some(U64(9223372036854774815)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-09-17", "%Y-%m-%d").unwrap(),
             ),
@@ -427,16 +441,16 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("caa9705c-21b1-387a-afd8-de69e0698cb7").unwrap(),
             ),
-            nullable_ulong: Some(-2147483640),
+            nullable_ulong: Some(U64(9223372036854774815)),
             nullable_json: Some("{ value: 9 }".into()),
-            nullable_jsonb: "{ value: 9 }".into(),
+            nullable_jsonb: Some("{ value: 9 }".into()),
         },
         SampleWithIdRowData {
             the_name: "j".into(),
             the_boolean: true,
             the_small_int: -32759,
             the_large_int: -2147483639,
-            the_big_int: -2147483639,
+            the_big_int: U64(9223372036854774816),
             the_date: chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             the_general_int: -2147483639,
             the_date_time: chrono::NaiveDateTime::parse_from_str(
@@ -445,14 +459,15 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             )
             .unwrap(),
             the_uuid: uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
-            the_ulong: -2147483639,
+            the_ulong: U64(9223372036854774816),
             the_json: "{ value: 10 }".into(),
-            the_jsonb: "{ value: 10 }".into(),
-            nullable_name: "j".into(),
+            the_jsonb: Default::default(),
+            the_version: -2147483639,
+            nullable_name: Some("j".into()),
             nullable_boolean: Some(true),
             nullable_small_int: Some(-32759),
             nullable_large_int: Some(-2147483639),
-            nullable_big_int: Some(-2147483639),
+            nullable_big_int: Some(U64(9223372036854774816)),
             nullable_date: Some(
                 chrono::NaiveDate::parse_from_str("2000-10-19", "%Y-%m-%d").unwrap(),
             ),
@@ -464,9 +479,9 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
             nullable_uuid: Some(
                 uuid::Uuid::parse_str("0455e422-6d84-35e3-9db8-1eb8c06af841").unwrap(),
             ),
-            nullable_ulong: Some(-2147483639),
+            nullable_ulong: Some(U64(9223372036854774816)),
             nullable_json: Some("{ value: 10 }".into()),
-            nullable_jsonb: "{ value: 10 }".into(),
+            nullable_jsonb: Some("{ value: 10 }".into()),
         },
     ]
 }
@@ -478,6 +493,12 @@ pub fn get_sample_rows() -> Vec<SampleWithIdRowData> {
 async fn test_crud() {
     let resource = get_pool().await.get().await.unwrap();
     let client = resource.client();
+    // Provision the schema from the generated DDL. The table is shared, so it is only dropped and
+    // recreated when `KGEN_DB_RECREATE_TABLES` opts in, otherwise created if missing.
+    if std::env::var("KGEN_DB_RECREATE_TABLES").is_ok() {
+        TableSampleWithId::drop_table(client).await.unwrap();
+    }
+    TableSampleWithId::create_table(client).await.unwrap();
     // First delete all, assuming it worked
     let deleted = TableSampleWithId::delete_all(client).await.unwrap();
     tracing::info!("Initialize phase deleted {deleted}");
@@ -486,7 +507,10 @@ async fn test_crud() {
       Validate that delete work by selecting back an empty set
     */
     {
-        assert_eq!(0, TableSampleWithId::select_all(client).await.len());
+        assert_eq!(
+            0,
+            TableSampleWithId::select_all(client).await.unwrap().len()
+        );
     }
     let mut samples = get_sample_rows();
 
@@ -506,7 +530,7 @@ async fn test_crud() {
         {
             select_and_compare_assert(
                 client,
-                &inserted.into_iter().map(|r| r.data).collect(),
+                &inserted.into_iter().map(|r| r.data).collect::<Vec<_>>(),
                 "Basic Ins Cmp",
             )
             .await;
@@ -516,6 +540,136 @@ async fn test_crud() {
         assert_eq!(samples.len(), deleted as usize);
     }
 
+    /*
+      Test NULL round trips for every nullable column
+    */
+    {
+        let null_samples = get_sample_rows()
+            .into_iter()
+            .map(|mut row| {
+                row.nullable_name = None;
+                row.nullable_boolean = None;
+                row.nullable_small_int = None;
+                row.nullable_large_int = None;
+                row.nullable_big_int = None;
+                row.nullable_date = None;
+                row.nullable_general_int = None;
+                row.nullable_date_time = None;
+                row.nullable_uuid = None;
+                row.nullable_ulong = None;
+                row.nullable_json = None;
+                row.nullable_jsonb = None;
+                row
+            })
+            .collect::<Vec<_>>();
+        TableSampleWithId::basic_insert(client, null_samples.clone())
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Round Trip Cmp").await;
+        let deleted = TableSampleWithId::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+        /*
+          Same through `UNNEST` of bulk insert, which passes arrays as text literals
+        */
+        TableSampleWithId::bulk_insert(client, null_samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &null_samples, "Null Bulk Round Trip Cmp").await;
+        let deleted = TableSampleWithId::delete_all(client).await.unwrap();
+        assert_eq!(null_samples.len(), deleted as usize);
+    }
+
+    /*
+      Test transactional bulk insert, where duplicates of the first samples fail the trailing chunks
+    */
+    {
+        let mut batch_resource = get_pool().await.get().await.unwrap();
+        let samples = get_sample_rows();
+        let chunk_size = 4;
+        let rows = samples
+            .iter()
+            .chain(samples.iter().take(chunk_size))
+            .cloned()
+            .collect::<Vec<_>>();
+        let chunk_count = rows.len().div_ceil(chunk_size);
+        let committed_chunks = samples.len() / chunk_size;
+        let report = TableSampleWithId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::ChunkSavepoints,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            (0..committed_chunks).collect::<Vec<_>>(),
+            report
+                .succeeded
+                .iter()
+                .map(|success| success.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (committed_chunks * chunk_size) as i64,
+            TableSampleWithId::count_where(client, "1=1", &[])
+                .await
+                .unwrap()
+        );
+        TableSampleWithId::delete_all(client).await.unwrap();
+
+        let report = TableSampleWithId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &rows,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.succeeded.is_empty());
+        assert_eq!(
+            (0..chunk_count).collect::<Vec<_>>(),
+            report
+                .failed
+                .iter()
+                .map(|failure| failure.chunk)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            1,
+            report
+                .failed
+                .iter()
+                .filter(|failure| failure.error.is_some())
+                .count()
+        );
+        assert!(!TableSampleWithId::exists_where(client, "1=1", &[])
+            .await
+            .unwrap());
+
+        let report = TableSampleWithId::bulk_insert_transactional(
+            &mut **batch_resource,
+            &samples,
+            chunk_size,
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .unwrap();
+        assert!(report.is_complete());
+        assert_eq!(
+            samples.len(),
+            TableSampleWithId::select_all(client).await.unwrap().len()
+        );
+        TableSampleWithId::delete_all(client).await.unwrap();
+    }
+
     /*
       Test the bulk insert functionality
     */
@@ -529,24 +683,493 @@ async fn test_crud() {
         */
         select_and_compare_assert(
             client,
-            &inserted.into_iter().map(|r| r.data).collect(),
+            &inserted.into_iter().map(|r| r.data).collect::<Vec<_>>(),
             "Blk Ins Cmp",
         )
         .await;
     }
 
+    /*
+      Test the functions running the prepared statements
+    */
+    {
+        let deleted = TableSampleWithId::delete_all_prepared(&resource)
+            .await
+            .unwrap();
+        assert_eq!(samples.len(), deleted as usize);
+        TableSampleWithId::bulk_insert_prepared(&resource, samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Ins Cmp").await;
+        TableSampleWithId::bulk_upsert_prepared(&resource, samples.clone(), 4)
+            .await
+            .unwrap();
+        select_and_compare_assert(client, &samples, "Prepared Blk Upsert Cmp").await;
+        for row in TableSampleWithId::select_all(client).await.unwrap() {
+            let selected = TableSampleWithId::select_by_pkey_prepared(&resource, &row.pkey())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+    }
+
+    /*
+      Test copy in reloads the bulk inserted data and copy out matches select
+    */
+    {
+        let bulk_inserted = TableSampleWithId::select_all(client)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| row.data)
+            .collect::<Vec<_>>();
+        let deleted = TableSampleWithId::delete_all(client).await.unwrap();
+        assert_eq!(bulk_inserted.len(), deleted as usize);
+        let copy_in_rows = bulk_inserted.clone();
+        assert_eq!(
+            bulk_inserted.len() as u64,
+            TableSampleWithId::copy_in(client, &copy_in_rows)
+                .await
+                .unwrap()
+        );
+        select_and_compare_assert(client, &bulk_inserted, "Copy In Cmp").await;
+
+        let selected = TableSampleWithId::select_all(client).await.unwrap();
+        let copied_out = TableSampleWithId::copy_out_where(client, "1=1")
+            .await
+            .unwrap();
+        assert_eq!(
+            selected
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>(),
+            copied_out
+                .iter()
+                .map(|row| format!("{row:?}"))
+                .collect::<BTreeSet<_>>()
+        );
+    }
+
+    /*
+      Test the streaming selects
+    */
+    {
+        let selected_count = TableSampleWithId::count_where(client, "1=1", &[])
+            .await
+            .unwrap() as usize;
+        let streamed = TableSampleWithId::select_stream_where(client, "1=1", &[])
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(selected_count, streamed.len());
+
+        let mut chunk_resource = get_pool().await.get().await.unwrap();
+        let transaction = chunk_resource.transaction().await.unwrap();
+        let chunks = TableSampleWithId::select_chunks_where(&transaction, "1=1", &[], 3)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 3));
+        assert_eq!(
+            selected_count,
+            chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+        );
+        transaction.commit().await.unwrap();
+    }
+
+    /*
+      Test paging by keyset and by offset
+    */
+    {
+        let selected_count = TableSampleWithId::count_where(client, "1=1", &[])
+            .await
+            .unwrap() as usize;
+        let mut keyset_rows = Vec::new();
+        let mut after = None;
+        loop {
+            let page = TableSampleWithId::select_page_after(client, after.as_ref(), 3)
+                .await
+                .unwrap();
+            assert!(page.rows.len() <= 3);
+            keyset_rows.extend(page.rows);
+            after = page.next;
+            if after.is_none() {
+                break;
+            }
+        }
+        let mut offset_rows = Vec::new();
+        let mut offset = Some(0);
+        while let Some(at) = offset {
+            let page = TableSampleWithId::select_page_at_offset(client, at, 3)
+                .await
+                .unwrap();
+            offset_rows.extend(page.rows);
+            offset = page.next;
+        }
+        assert_eq!(selected_count, keyset_rows.len());
+        assert_eq!(format!("{keyset_rows:?}"), format!("{offset_rows:?}"));
+    }
+
+    /*
+      Test the aggregates
+    */
+    {
+        assert_eq!(
+            samples.len() as i64,
+            TableSampleWithId::count_where(client, "1=1", &[])
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSampleWithId::count_where(client, "1=0", &[])
+                .await
+                .unwrap()
+        );
+        assert!(TableSampleWithId::exists_where(client, "1=1", &[])
+            .await
+            .unwrap());
+        assert!(!TableSampleWithId::exists_where(client, "1=0", &[])
+            .await
+            .unwrap());
+        let min = TableSampleWithId::min_auto_id(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        let max = TableSampleWithId::max_auto_id(client, "1=1", &[])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(min <= max);
+        assert!(
+            TableSampleWithId::count_where(client, r#"auto_id = $1"#, &[&min])
+                .await
+                .unwrap()
+                >= 1
+        );
+        assert_eq!(
+            None,
+            TableSampleWithId::max_auto_id(client, "1=0", &[])
+                .await
+                .unwrap()
+        );
+    }
+
+    /*
+      Test the typed filter
+    */
+    {
+        let value = samples[0].the_name.clone();
+        let expected = samples.iter().filter(|row| row.the_name == value).count();
+        let matched = TableSampleWithId::select_all_filtered(
+            client,
+            &Filter::eq(SampleWithIdColumn::TheName, value),
+        )
+        .await
+        .unwrap();
+        assert_eq!(expected, matched.len());
+        let unmatched = TableSampleWithId::select_all_filtered(
+            client,
+            &Filter::ne(SampleWithIdColumn::TheName, samples[0].the_name.clone())
+                .or(Filter::is_null(SampleWithIdColumn::TheName)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(samples.len(), matched.len() + unmatched.len());
+        assert_eq!(
+            samples.len(),
+            TableSampleWithId::select_all_filtered(client, &Filter::And(vec![]))
+                .await
+                .unwrap()
+                .len()
+        );
+    }
+
+    /*
+      Test the update guarded by the version column
+    */
+    {
+        let key = TableSampleWithId::select_all(client).await.unwrap()[0].pkey();
+        let mut read = TableSampleWithId::select_by_pkey(client, &key)
+            .await
+            .unwrap()
+            .unwrap();
+        let read_version = read.data.the_version;
+        mutate_row_data(&mut read.data);
+        read.data.the_version = read_version;
+        let updated = TableSampleWithId::update_by_pkey_if_version(client, &key, &read.data)
+            .await
+            .unwrap();
+        let VersionedUpdate::Updated { version } = updated else {
+            panic!("Expected update at version {read_version:?}, got {updated:?}");
+        };
+        assert_ne!(read_version, version);
+        let reselected = TableSampleWithId::select_by_pkey(client, &key)
+            .await
+            .unwrap()
+            .unwrap();
+        read.data.the_version = version;
+
+        assert_eq!(format!("{read:?}"), format!("{reselected:?}"));
+
+        read.data.the_version = read_version;
+        assert_eq!(
+            VersionedUpdate::Conflict,
+            TableSampleWithId::update_by_pkey_if_version(client, &key, &read.data)
+                .await
+                .unwrap()
+        );
+        let unchanged = TableSampleWithId::select_by_pkey(client, &key)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{reselected:?}"), format!("{unchanged:?}"));
+    }
+
+    /*
+      Test the primary key functions
+    */
+    {
+        let selected_all = TableSampleWithId::select_all(client).await.unwrap();
+        let keys = selected_all
+            .iter()
+            .map(|row| row.pkey())
+            .collect::<Vec<_>>();
+        for (row, key) in selected_all.iter().zip(keys.iter()) {
+            let selected = TableSampleWithId::select_by_pkey(client, key)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+        assert_eq!(
+            keys.len(),
+            TableSampleWithId::select_by_pkeys(client, &keys)
+                .await
+                .unwrap()
+                .len()
+        );
+
+        let key = &keys[0];
+        let mut updated = TableSampleWithId::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .unwrap();
+        mutate_row_data(&mut updated.data);
+        assert_eq!(
+            1,
+            TableSampleWithId::update_by_pkey(client, key, &updated.data)
+                .await
+                .unwrap()
+        );
+        let reselected = TableSampleWithId::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(format!("{updated:?}"), format!("{reselected:?}"));
+        assert_eq!(
+            1,
+            TableSampleWithId::delete_by_pkey(client, key)
+                .await
+                .unwrap()
+        );
+        assert!(TableSampleWithId::select_by_pkey(client, key)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            0,
+            TableSampleWithId::delete_by_pkey(client, key)
+                .await
+                .unwrap()
+        );
+    }
+
+    /*
+      Test the bulk update and delete by primary key
+    */
+    {
+        let mut bulk_rows = TableSampleWithId::select_all(client).await.unwrap();
+        bulk_rows
+            .iter_mut()
+            .for_each(|row| mutate_row_data(&mut row.data));
+        assert_eq!(
+            bulk_rows.len() as u64,
+            TableSampleWithId::bulk_update(client, &bulk_rows, 4)
+                .await
+                .unwrap()
+        );
+        select_and_compare_assert(
+            client,
+            &bulk_rows
+                .iter()
+                .map(|row| row.data.clone())
+                .collect::<Vec<_>>(),
+            "Blk Update Cmp",
+        )
+        .await;
+        let keys = bulk_rows.iter().map(|row| row.pkey()).collect::<Vec<_>>();
+        assert_eq!(
+            keys.len() as u64,
+            TableSampleWithId::bulk_delete_by_pkeys(client, &keys, 4)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSampleWithId::count_where(client, "1=1", &[])
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            TableSampleWithId::bulk_delete_by_pkeys(client, &keys, 4)
+                .await
+                .unwrap()
+        );
+        TableSampleWithId::bulk_upsert(
+            client,
+            bulk_rows.into_iter().map(|row| row.data).collect(),
+            4,
+        )
+        .await
+        .unwrap();
+    }
+
+    /*
+      Test the unique index `sample_with_id_the_name_the_small_int_unique` functions
+    */
+    {
+        let selected_all = TableSampleWithId::select_all(client).await.unwrap();
+        for row in selected_all.iter() {
+            let key = row.data.sample_with_id_the_name_the_small_int_unique_key();
+            let selected =
+                TableSampleWithId::select_by_sample_with_id_the_name_the_small_int_unique(
+                    client, &key,
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(format!("{row:?}"), format!("{selected:?}"));
+        }
+
+        let mut upsert_rows = selected_all
+            .into_iter()
+            .map(|row| row.data)
+            .collect::<Vec<_>>();
+        upsert_rows.iter_mut().for_each(mutate_row_data);
+        TableSampleWithId::bulk_upsert_by_sample_with_id_the_name_the_small_int_unique(
+            client,
+            upsert_rows.clone(),
+            4,
+        )
+        .await
+        .unwrap();
+        select_and_compare_assert(
+            client,
+            &upsert_rows,
+            "Upsert By sample_with_id_the_name_the_small_int_unique Cmp",
+        )
+        .await;
+
+        let key = upsert_rows[0].sample_with_id_the_name_the_small_int_unique_key();
+        assert_eq!(
+            1,
+            TableSampleWithId::delete_by_sample_with_id_the_name_the_small_int_unique(client, &key)
+                .await
+                .unwrap()
+        );
+        assert!(
+            TableSampleWithId::select_by_sample_with_id_the_name_the_small_int_unique(client, &key)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        TableSampleWithId::bulk_upsert_by_sample_with_id_the_name_the_small_int_unique(
+            client,
+            upsert_rows.clone(),
+            4,
+        )
+        .await
+        .unwrap();
+    }
+
+    /*
+      Test bulk upsert entries of rows inserted and rows updated
+    */
+    {
+        let existing = TableSampleWithId::select_all(client).await.unwrap();
+        let (deleted, kept) = existing.split_at(existing.len() / 2);
+        for entry in deleted.iter() {
+            assert_eq!(
+                1,
+                TableSampleWithId::delete_by_sample_with_id_the_name_the_small_int_unique(
+                    client,
+                    &entry
+                        .data
+                        .sample_with_id_the_name_the_small_int_unique_key()
+                )
+                .await
+                .unwrap()
+            );
+        }
+        let mut upsert_rows = kept
+            .iter()
+            .chain(deleted.iter())
+            .rev()
+            .map(|entry| entry.data.clone())
+            .collect::<Vec<_>>();
+        upsert_rows.iter_mut().for_each(mutate_row_data);
+        let upserted = TableSampleWithId::bulk_upsert(client, upsert_rows.clone(), 4)
+            .await
+            .unwrap();
+        assert_eq!(upsert_rows.len(), upserted.len());
+        for entry in upserted.iter() {
+            let selected =
+                TableSampleWithId::select_by_sample_with_id_the_name_the_small_int_unique(
+                    client,
+                    &entry
+                        .data
+                        .sample_with_id_the_name_the_small_int_unique_key(),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(selected.auto_id, entry.auto_id);
+        }
+        for entry in kept.iter() {
+            let selected =
+                TableSampleWithId::select_by_sample_with_id_the_name_the_small_int_unique(
+                    client,
+                    &entry
+                        .data
+                        .sample_with_id_the_name_the_small_int_unique_key(),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(entry.auto_id, selected.auto_id);
+        }
+        select_and_compare_assert(client, &upsert_rows, "Upsert Entries Cmp").await;
+    }
+
     /*
       Mutate the data, and bulk upsert.
     */
     {
-        samples.iter_mut().for_each(|data| mutate_row_data(data));
+        samples.iter_mut().for_each(mutate_row_data);
         tracing::debug!("Mutated Samples: {samples:?}");
         let upserted = TableSampleWithId::bulk_upsert(client, samples.clone(), 4)
             .await
             .unwrap();
         tracing::debug!("Inserted with `bulk_upsert` -> {upserted:?}");
-        select_and_compare_assert(client, &samples.iter().cloned().collect(), "Blk Upsert Cmp")
-            .await;
+        select_and_compare_assert(client, &samples, "Blk Upsert Cmp").await;
     }
 
     /*
@@ -556,10 +1179,27 @@ async fn test_crud() {
         let deleted = TableSampleWithId::delete_all(client).await.unwrap();
         tracing::info!("Deleted all {deleted} TableSampleWithId entries");
         assert_eq!(deleted as usize, samples.len());
-        let selected = TableSampleWithId::select_all(client).await;
+        let selected = TableSampleWithId::select_all(client).await.unwrap();
         assert_eq!(0, selected.len());
     }
 }
 
+/// Test the schema provisioned from the generated DDL matches that assumed by sample_with_id
+#[tracing_test::traced_test]
+#[serial_test::serial]
+#[tokio::test]
+async fn test_verify_schema() {
+    let resource = get_pool().await.get().await.unwrap();
+    let client = resource.client();
+    TableSampleWithId::create_table(client).await.unwrap();
+    let report = TableSampleWithId::verify_schema(client).await.unwrap();
+    assert!(
+        report.is_consistent(),
+        "Schema drift in `{}`: {:?}",
+        report.table_name,
+        report.drifts
+    );
+}
+
 // α <mod-def test_sample_with_id>
 // ω <mod-def test_sample_with_id>